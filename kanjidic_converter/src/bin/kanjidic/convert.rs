@@ -0,0 +1,52 @@
+use crate::{input, CliError};
+use clap::Args;
+use kanjidic_parser::{export, snapshot, subset::Subset};
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+};
+
+#[derive(Args)]
+pub struct Opts {
+    /// The KANJIDIC2 file to read, optionally gzipped.
+    #[clap(short, long)]
+    input: String,
+    /// Where to write the JSON output.
+    #[clap(short, long)]
+    output: String,
+    /// Write compact rather than pretty-printed JSON.
+    #[clap(short, long)]
+    compact: bool,
+    /// Write one character per line as JSON Lines, omitting the header.
+    #[clap(short, long)]
+    lines: bool,
+    /// Write a binary snapshot for `Kanjidic::load_snapshot` instead of JSON.
+    #[clap(long)]
+    snapshot: bool,
+    /// Keep only the characters in one of these subsets: jouyou, jlpt, or top-N by frequency.
+    #[clap(long)]
+    subset: Vec<Subset>,
+}
+
+pub fn run(opts: Opts) -> Result<(), CliError> {
+    let mut kanjidic = input::kanjidic(&opts.input)?;
+    if !opts.subset.is_empty() {
+        kanjidic = kanjidic.subset(&opts.subset);
+    }
+    if opts.snapshot {
+        snapshot::write(&kanjidic, BufWriter::new(File::create(opts.output)?))?;
+        return Ok(());
+    }
+    if opts.lines {
+        let writer = BufWriter::new(File::create(opts.output)?);
+        export::json_lines(&kanjidic.characters, writer)?;
+        return Ok(());
+    }
+    let json = if opts.compact {
+        serde_json::to_string(&kanjidic)
+    } else {
+        serde_json::to_string_pretty(&kanjidic)
+    }?;
+    fs::write(opts.output, json)?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::CliError;
+use flate2::read::GzDecoder;
+use kanjidic_parser::{diagnostics::ParseDiagnostic, encoding, kanjidic::Kanjidic};
+use std::{
+    fs::{self, File},
+    io::Read,
+};
+
+/// Reads a KANJIDIC2 file, decompressing it first if it is gzipped
+/// and decoding it from whatever encoding it uses.
+pub fn read(path: &str) -> Result<String, CliError> {
+    let bytes = if path.ends_with(".gz") {
+        let mut bytes = vec![];
+        GzDecoder::new(File::open(path)?).read_to_end(&mut bytes)?;
+        bytes
+    } else {
+        fs::read(path)?
+    };
+    Ok(encoding::decode(&bytes)?.into_owned())
+}
+
+/// Reads and parses a KANJIDIC2 file.
+pub fn kanjidic(path: &str) -> Result<Kanjidic, CliError> {
+    let xml = read(path)?;
+    Kanjidic::from_file_contents(&xml)
+        .map_err(|err| CliError::Diagnostic(Box::new(ParseDiagnostic::new(&err, path, &xml))))
+}
@@ -0,0 +1,55 @@
+mod convert;
+mod input;
+mod stats;
+mod validate;
+
+use clap::{Parser, Subcommand};
+use kanjidic_parser::{diagnostics::ParseDiagnostic, encoding, export, kanjidic, snapshot};
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum CliError {
+    #[error("Error reading or writing file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Error parsing file: {0}")]
+    Parse(#[from] kanjidic::Error),
+    #[error("Error decoding file: {0}")]
+    Encoding(#[from] encoding::Error),
+    #[error("Error from json serialization: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Error exporting: {0}")]
+    Export(#[from] export::Error),
+    #[error("Error writing snapshot: {0}")]
+    Snapshot(#[from] snapshot::Error),
+    #[error("{0} characters failed to parse")]
+    Invalid(usize),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Diagnostic(Box<ParseDiagnostic>),
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert a KANJIDIC2 file to JSON.
+    Convert(convert::Opts),
+    /// Summarize the contents of a KANJIDIC2 file.
+    Stats(stats::Opts),
+    /// Report every character in a KANJIDIC2 file that fails to parse.
+    Validate(validate::Opts),
+}
+
+fn main() -> miette::Result<()> {
+    match Cli::parse().command {
+        Command::Convert(opts) => convert::run(opts),
+        Command::Stats(opts) => stats::run(opts),
+        Command::Validate(opts) => validate::run(opts),
+    }?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::{input, CliError};
+use clap::Args;
+use std::{collections::BTreeMap, fmt::Debug};
+
+#[derive(Args)]
+pub struct Opts {
+    /// The KANJIDIC2 file to read, optionally gzipped.
+    #[clap(short, long)]
+    input: String,
+}
+
+pub fn run(opts: Opts) -> Result<(), CliError> {
+    let stats = input::kanjidic(&opts.input)?.stats();
+    println!("Characters: {}", stats.characters);
+    println!(
+        "With frequency data: {}",
+        stats.characters - stats.missing_frequency
+    );
+    println!("Without readings: {}", stats.missing_readings);
+    print_counts("Grade", &stats.by_grade);
+    print_counts("JLPT", &stats.by_jlpt);
+    print_counts("Stroke count", &stats.by_stroke_count);
+    print_counts("Reference coverage", &stats.reference_coverage);
+    Ok(())
+}
+
+fn print_counts<K: Debug>(title: &str, counts: &BTreeMap<K, usize>) {
+    println!();
+    println!("{}:", title);
+    for (key, count) in counts {
+        println!("  {:?}: {}", key, count);
+    }
+}
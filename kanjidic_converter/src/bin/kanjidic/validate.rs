@@ -0,0 +1,104 @@
+use crate::{input, CliError};
+use clap::Args;
+use kanjidic_parser::{
+    character,
+    integrity::{self, Dangling},
+    kanjidic,
+    schema::{self, Unrecognized},
+};
+use roxmltree::Document;
+
+#[derive(Args)]
+pub struct Opts {
+    /// The KANJIDIC2 file to read, optionally gzipped.
+    #[clap(short, long)]
+    input: String,
+    /// Also report elements and attributes that the parser ignores.
+    #[clap(short, long)]
+    strict: bool,
+    /// Also report variants and decomposition components that do not resolve to a character.
+    #[clap(short, long)]
+    references: bool,
+}
+
+pub fn run(opts: Opts) -> Result<(), CliError> {
+    let xml = input::read(&opts.input)?;
+    let start = xml.find("<kanjidic2>").ok_or(kanjidic::Error::MissingRoot)?;
+    let line_offset = xml[..start].matches('\n').count() as u32;
+    let doc = Document::parse(&xml[start..]).map_err(kanjidic::Error::from)?;
+    let mut failures = 0;
+    let mut characters = vec![];
+    for node in doc
+        .root_element()
+        .children()
+        .filter(|child| child.has_tag_name("character"))
+    {
+        match character::from(node) {
+            Ok(character) => characters.push(character),
+            Err(err) => {
+                failures += 1;
+                let position = doc.text_pos_at(node.range().start);
+                let literal = node
+                    .children()
+                    .find(|child| child.has_tag_name("literal"))
+                    .and_then(|literal| literal.text())
+                    .unwrap_or("?");
+                println!(
+                    "{}:{} {}: {}",
+                    position.row + line_offset,
+                    position.col,
+                    literal,
+                    err
+                );
+            }
+        }
+    }
+    if opts.strict {
+        for unrecognized in schema::unrecognized_in(&doc) {
+            failures += 1;
+            let (position, description) = match unrecognized {
+                Unrecognized::Element {
+                    position,
+                    parent,
+                    name,
+                } => (
+                    position,
+                    format!("Unrecognized element <{}> in <{}>", name, parent),
+                ),
+                Unrecognized::Attribute {
+                    position,
+                    element,
+                    name,
+                } => (
+                    position,
+                    format!("Unrecognized attribute {} on <{}>", name, element),
+                ),
+            };
+            println!(
+                "{}:{} {}",
+                position.row + line_offset,
+                position.col,
+                description
+            );
+        }
+    }
+    if opts.references {
+        for dangling in integrity::dangling(&characters) {
+            failures += 1;
+            match dangling {
+                Dangling::Variant { literal, variant } => {
+                    println!("{}: Unresolved variant {:?}", literal, variant)
+                }
+                Dangling::Component { literal, component } => {
+                    println!("{}: Unresolved component {}", literal, component)
+                }
+            }
+        }
+    }
+    if failures > 0 {
+        Err(CliError::Invalid(failures))
+    } else {
+        println!("All characters parsed successfully");
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::kanjidic::{ArchivedKanjidic, Kanjidic};
+use rkyv::{AlignedVec, Deserialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("(Archive) Could not serialize the dictionary: {0}")]
+    Serialize(String),
+    #[error("(Archive) The archive failed validation: {0}")]
+    Validate(String),
+}
+
+/// Archives the dictionary so that it can be accessed in place with [`archived`].
+pub fn to_bytes(kanjidic: &Kanjidic) -> Result<AlignedVec, Error> {
+    rkyv::to_bytes::<_, 4096>(kanjidic).map_err(|err| Error::Serialize(format!("{:?}", err)))
+}
+
+/// Validates and accesses an archived dictionary without deserializing it.
+/// The bytes may come from a memory-mapped file but must be suitably aligned.
+pub fn archived(bytes: &[u8]) -> Result<&ArchivedKanjidic, Error> {
+    rkyv::check_archived_root::<Kanjidic>(bytes)
+        .map_err(|err| Error::Validate(format!("{:?}", err)))
+}
+
+/// Validates an archived dictionary and deserializes it into owned data.
+pub fn from_bytes(bytes: &[u8]) -> Result<Kanjidic, Error> {
+    let archived = archived(bytes)?;
+    // Deserializing with Infallible cannot fail.
+    Ok(archived.deserialize(&mut rkyv::Infallible).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{archived, from_bytes, to_bytes};
+    use crate::test_shared::KANJIDIC;
+
+    #[test]
+    fn round_trip() {
+        let bytes = to_bytes(&KANJIDIC).unwrap();
+        assert_eq!(
+            archived(&bytes).unwrap().characters.len(),
+            KANJIDIC.characters.len()
+        );
+        assert_eq!(from_bytes(&bytes).unwrap(), *KANJIDIC);
+    }
+}
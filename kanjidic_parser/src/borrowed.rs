@@ -0,0 +1,348 @@
+use crate::{
+    character::CharacterError,
+    codepoint, decomposition, grade, kunyomi,
+    parse_options::ParseOptions,
+    pin_yin,
+    pos_error::PosError,
+    query_code, radical, reading, reference,
+    shared::{attr, children, text, text_uint},
+    stroke_count::StrokeCountBuilder,
+    translation, variant,
+};
+use kanjidic_types::{
+    character::BuildError,
+    kunyomi::{self as kunyomi_types, KunyomiKind},
+    Character, Codepoint, Grade, Kunyomi, Language, PinYin, QueryCode, Radical, Reading, Reference,
+    StrokeCount, Variant,
+};
+use roxmltree::{Document, Node};
+use serde::Serialize;
+use std::{borrow::Cow, collections::BTreeMap, sync::atomic::Ordering};
+
+/// A [`Character`] whose text borrows from the parsed document
+/// rather than allocating a string for every reading, meaning, and nanori.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterRef<'a> {
+    pub literal: char,
+    /// The whole literal when it is more than one scalar value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub literal_sequence: Option<&'a str>,
+    pub codepoints: Vec<Codepoint>,
+    pub radicals: Vec<Radical>,
+    pub grade: Option<Grade>,
+    pub stroke_counts: StrokeCount,
+    pub variants: Vec<Variant>,
+    pub frequency: Option<u16>,
+    pub radical_names: Vec<&'a str>,
+    pub jlpt: Option<u8>,
+    pub references: Vec<Reference>,
+    pub query_codes: Vec<QueryCode>,
+    pub readings: Vec<ReadingRef<'a>>,
+    /// Meanings keyed by language code.
+    pub translations: BTreeMap<&'a str, Vec<&'a str>>,
+    pub nanori: Vec<&'a str>,
+    pub decomposition: Vec<char>,
+}
+
+/// A [`Reading`] that borrows from the parsed document.
+/// Pin yin is still owned because its romanization is normalized while parsing,
+/// and Vietnamese readings are owned when they need normalizing to NFC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "tag", content = "content")]
+pub enum ReadingRef<'a> {
+    PinYin(PinYin),
+    KoreanRomanized(&'a str),
+    KoreanHangul(&'a str),
+    /// Borrowed unless normalizing to NFC changed the text.
+    Vietnam(Cow<'a, str>),
+    Onyomi(&'a str),
+    Kunyomi(KunyomiRef<'a>),
+}
+
+/// A [`Kunyomi`] that borrows from the parsed document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct KunyomiRef<'a> {
+    pub reading: &'a str,
+    pub okurigana: Option<&'a str>,
+    pub kind: KunyomiKind,
+}
+
+/// Parses each character in the document without copying its text.
+pub fn characters<'a, 'input: 'a>(
+    doc: &'a Document<'input>,
+) -> Box<dyn Iterator<Item = Result<CharacterRef<'a>, CharacterError>> + 'a> {
+    Box::new(
+        doc.root_element()
+            .children()
+            .filter(|child| child.has_tag_name("character"))
+            .map(from),
+    )
+}
+
+/// Parses each character in the document like [`characters`],
+/// skipping the sections that the options exclude
+/// and ending the iteration early once another thread sets the cancel flag.
+pub fn characters_with<'a, 'input: 'a>(
+    doc: &'a Document<'input>,
+    options: &'a ParseOptions<'a>,
+) -> Box<dyn Iterator<Item = Result<CharacterRef<'a>, CharacterError>> + 'a> {
+    Box::new(
+        doc.root_element()
+            .children()
+            .filter(|child| child.has_tag_name("character"))
+            .take_while(move |_| {
+                !options
+                    .cancel
+                    .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+            })
+            .map(move |node| from_with(node, options)),
+    )
+}
+
+/// Parses a character without copying its text.
+pub fn from<'a>(character_node: Node<'a, '_>) -> Result<CharacterRef<'a>, CharacterError> {
+    from_with(character_node, &ParseOptions::default())
+}
+
+/// Parses a character like [`from`], skipping the sections that the options exclude.
+pub fn from_with<'a>(
+    character_node: Node<'a, '_>,
+    options: &ParseOptions,
+) -> Result<CharacterRef<'a>, CharacterError> {
+    let mut literal = None;
+    let mut literal_sequence = None;
+    let mut codepoints = vec![];
+    let mut radicals = vec![];
+    let mut misc = None;
+    let mut references = vec![];
+    let mut query_codes = vec![];
+    let mut readings = vec![];
+    let mut translations: BTreeMap<&'a str, Vec<&'a str>> = BTreeMap::new();
+    let mut nanori = vec![];
+    for child in character_node.children() {
+        match child.tag_name().name() {
+            "literal" => {
+                let whole = text(&child)?;
+                let mut chars = whole.chars();
+                let first = chars
+                    .next()
+                    .ok_or_else(|| CharacterError::EmptyLiteral(PosError::from(&child)))?;
+                literal = Some(first);
+                literal_sequence = chars.next().map(|_| whole);
+            }
+            "codepoint" if options.codepoints => {
+                codepoints = children(&child, "cp_value", codepoint::from)?
+            }
+            "radical" if options.radicals => {
+                radicals = children(&child, "rad_value", radical::from)?
+            }
+            "misc" => misc = Some(child),
+            "dic_number" if options.references => {
+                references = children(&child, "dic_ref", reference::from)?
+            }
+            "query_code" if options.query_codes => {
+                query_codes = children(&child, "q_code", query_code::from)?
+            }
+            "reading_meaning" => {
+                for group_or_nanori in child.children() {
+                    match group_or_nanori.tag_name().name() {
+                        "rmgroup" => {
+                            for item in group_or_nanori.children() {
+                                match item.tag_name().name() {
+                                    "reading" if options.readings => {
+                                        readings.push(reading_ref(item)?)
+                                    }
+                                    "meaning" => {
+                                        let language = translation::language(&item);
+                                        if options.translations.includes(language) {
+                                            translations
+                                                .entry(language)
+                                                .or_default()
+                                                .push(text(&item)?);
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        "nanori" if options.nanori => nanori.push(
+                            text(&group_or_nanori)
+                                .map_err(|_| CharacterError::NanoriText(PosError::from(&child)))?,
+                        ),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let incomplete =
+        |err| CharacterError::IncompleteCharacter(PosError::from(&character_node), err);
+    let literal = literal.ok_or_else(|| incomplete(BuildError::MissingLiteral))?;
+    let misc = misc.ok_or_else(|| incomplete(BuildError::MissingStrokeCount))?;
+    let mut grade = None;
+    let mut variants = vec![];
+    let mut frequency = None;
+    let mut radical_names = vec![];
+    let mut jlpt = None;
+    let mut stroke_counts = StrokeCountBuilder::default();
+    for child in misc.children() {
+        match child.tag_name().name() {
+            "grade" => grade = Some(grade::from(child)?),
+            "variant" if options.variants => variants.push(variant::from(child)?),
+            "freq" => frequency = Some(text_uint::<u16>(&child)?),
+            "rad_name" if options.radical_names => radical_names.push(text(&child)?),
+            "jlpt" => jlpt = Some(text_uint::<u8>(&child)?),
+            "stroke_count" => stroke_counts.add_from_node(&child)?,
+            _ => {}
+        }
+    }
+
+    Ok(CharacterRef {
+        literal,
+        literal_sequence,
+        codepoints,
+        radicals,
+        grade,
+        stroke_counts: stroke_counts.build(&misc)?,
+        variants,
+        frequency,
+        radical_names,
+        jlpt,
+        references,
+        query_codes,
+        readings,
+        translations,
+        nanori,
+        decomposition: decomposition::components(options.decomposition, literal),
+    })
+}
+
+fn reading_ref<'a>(node: Node<'a, '_>) -> Result<ReadingRef<'a>, reading::Error> {
+    match attr(&node, "r_type")? {
+        "pinyin" => Ok(ReadingRef::PinYin(pin_yin::from(node)?)),
+        "korean_r" => Ok(ReadingRef::KoreanRomanized(text(&node)?)),
+        "korean_h" => Ok(ReadingRef::KoreanHangul(text(&node)?)),
+        "vietnam" => Ok(ReadingRef::Vietnam(reading::nfc(text(&node)?))),
+        "ja_on" => Ok(ReadingRef::Onyomi(text(&node)?)),
+        "ja_kun" => Ok(ReadingRef::Kunyomi(kunyomi_ref(node)?)),
+        _ => Err(reading::Error::UnrecognizedType(PosError::from(&node))),
+    }
+}
+
+fn kunyomi_ref<'a>(node: Node<'a, '_>) -> Result<KunyomiRef<'a>, kunyomi::Error> {
+    let text = text(&node)?;
+    let (kind, reading, okurigana) = kunyomi_types::split(text)
+        .map_err(|err| kunyomi::Error::Parse(PosError::from(&node), err))?;
+    Ok(KunyomiRef {
+        reading,
+        okurigana,
+        kind,
+    })
+}
+
+impl<'a> From<&ReadingRef<'a>> for Reading {
+    fn from(reading: &ReadingRef<'a>) -> Self {
+        match reading {
+            ReadingRef::PinYin(pin_yin) => Reading::PinYin(pin_yin.clone()),
+            ReadingRef::KoreanRomanized(text) => Reading::KoreanRomanized((*text).to_owned()),
+            ReadingRef::KoreanHangul(text) => Reading::KoreanHangul((*text).to_owned()),
+            ReadingRef::Vietnam(text) => Reading::Vietnam(text.as_ref().to_owned()),
+            ReadingRef::Onyomi(text) => Reading::Onyomi((*text).to_owned()),
+            ReadingRef::Kunyomi(kunyomi) => Reading::Kunyomi(Kunyomi {
+                reading: kunyomi.reading.to_owned(),
+                okurigana: kunyomi.okurigana.map(str::to_owned),
+                kind: kunyomi.kind,
+            }),
+        }
+    }
+}
+
+impl<'a> From<&CharacterRef<'a>> for Character {
+    fn from(character: &CharacterRef<'a>) -> Self {
+        let strings =
+            |strings: &[&str]| -> Vec<String> { strings.iter().map(|s| (*s).to_owned()).collect() };
+        Character {
+            literal: character.literal,
+            literal_sequence: character.literal_sequence.map(str::to_owned),
+            codepoints: character.codepoints.clone(),
+            radicals: character.radicals.clone(),
+            grade: character.grade,
+            stroke_counts: character.stroke_counts.clone(),
+            variants: character.variants.clone(),
+            frequency: character.frequency,
+            radical_names: strings(&character.radical_names),
+            jlpt: character.jlpt,
+            references: character.references.clone(),
+            query_codes: character.query_codes.clone(),
+            readings: character.readings.iter().map(Reading::from).collect(),
+            translations: character
+                .translations
+                .iter()
+                .map(|(language, meanings)| (Language::from(*language), strings(meanings)))
+                .collect(),
+            nanori: strings(&character.nanori),
+            decomposition: character.decomposition.clone(),
+            ids: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{characters, characters_with};
+    use crate::{
+        parse_options::{LanguageFilter, ParseOptions},
+        test_shared::{CHARACTERS, DOC},
+    };
+    use kanjidic_types::Character;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn matches_owned() {
+        for (borrowed, owned) in characters(&DOC).zip(CHARACTERS.iter()) {
+            assert_eq!(&Character::from(&borrowed.unwrap()), owned);
+        }
+    }
+
+    #[test]
+    fn filtered_languages() {
+        let options = ParseOptions {
+            translations: LanguageFilter::ENGLISH,
+            ..ParseOptions::default()
+        };
+        let character = characters_with(&DOC, &options).next().unwrap().unwrap();
+        assert_eq!(
+            character.translations.keys().collect::<Vec<_>>(),
+            vec![&"en"]
+        );
+        let options = ParseOptions {
+            translations: LanguageFilter::None,
+            readings: false,
+            ..ParseOptions::default()
+        };
+        let character = characters_with(&DOC, &options).next().unwrap().unwrap();
+        assert!(character.translations.is_empty());
+        assert!(character.readings.is_empty());
+    }
+
+    #[test]
+    fn cancelled() {
+        let cancel = AtomicBool::new(false);
+        let options = ParseOptions {
+            cancel: Some(&cancel),
+            ..ParseOptions::default()
+        };
+        let mut parsed = 0;
+        for character in characters_with(&DOC, &options) {
+            character.unwrap();
+            parsed += 1;
+            if parsed == 10 {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        }
+        assert_eq!(parsed, 10);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{text, SharedError},
 };
@@ -14,6 +15,16 @@ pub enum Error {
     Parse(PosError, busy_people::ParseError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Parse(_, _) => ErrorCode::E0118_INVALID_BUSY_PEOPLE,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<BusyPeople, Error> {
     let text = text(&node)?;
     BusyPeople::try_from(text).map_err(|err| Error::Parse(PosError::from(&node), err))
@@ -23,7 +34,11 @@ pub fn from(node: Node) -> Result<BusyPeople, Error> {
 mod tests {
     use super::from;
     use crate::test_shared::DOC;
-    use kanjidic_types::BusyPeople;
+    use kanjidic_types::{
+        busy_people::{Chapter, Volume},
+        BusyPeople,
+    };
+    use std::convert::TryFrom;
 
     #[test]
     fn busy_people() {
@@ -41,9 +56,25 @@ mod tests {
         assert_eq!(
             busy_people,
             Ok(BusyPeople {
-                volume: 3,
-                chapter: Some(14),
+                volume: Volume::Three,
+                chapter: Chapter::Numbered(14),
             })
         )
     }
+
+    #[test]
+    fn notation() {
+        let appendix = BusyPeople::try_from("1.A").unwrap();
+        assert_eq!(
+            appendix,
+            BusyPeople {
+                volume: Volume::One,
+                chapter: Chapter::Appendix,
+            }
+        );
+        assert_eq!(appendix.to_string(), "1.A");
+        assert_eq!(BusyPeople::try_from("2.15").unwrap().to_string(), "2.15");
+        assert!(BusyPeople::try_from("4.1").is_err());
+        assert!(BusyPeople::try_from("2.15A").is_err());
+    }
 }
@@ -1,14 +1,17 @@
 use crate::{
-    codepoint, grade,
+    codepoint, decomposition,
+    error_code::ErrorCode,
+    grade,
+    parse_options::ParseOptions,
     pos_error::PosError,
     query_code, radical, reading, reference,
     shared::{children, text, text_uint, SharedError},
     stroke_count::{self, StrokeCountBuilder},
-    translation, variant,
+    translation, variant, writer,
 };
 use kanjidic_types::{
-    character::Translations, Character, Codepoint, Grade, QueryCode, Radical, Reading, Reference,
-    StrokeCount, Variant,
+    character::{BuildError, CharacterBuilder, Translations},
+    Character,
 };
 use roxmltree::Node;
 
@@ -36,159 +39,112 @@ pub enum CharacterError {
     DictionaryReference(#[from] reference::Error),
     #[error("(Character) Nanori node missing text: {0}")]
     NanoriText(PosError),
-    #[error("(Character) Expected a single char")]
-    NonCharString,
-    #[error("(Character) Character did not have non-optional fields")]
-    IncompleteCharacter,
+    #[error("(Character) Literal is empty: {0}")]
+    EmptyLiteral(PosError),
+    #[error("(Character) Character did not have non-optional fields: {0}, {1}")]
+    IncompleteCharacter(PosError, BuildError),
 }
 
-struct CharacterBuilder {
-    /// The character itself.
-    pub literal: Option<char>,
-    /// Alternate encodings for the character.
-    pub codepoints: Option<Vec<Codepoint>>,
-    /// Alternate classifications for the character by radical.
-    pub radicals: Option<Vec<Radical>>,
-    /// The kanji grade level.
-    pub grade: Option<Grade>,
-    /// The stroke count of the character.
-    pub stroke_counts: Option<StrokeCount>,
-    /// Cross-references to other characters or alternative indexings.
-    pub variants: Option<Vec<Variant>>,
-    /// A ranking of how often the character appears in newspapers.
-    pub frequency: Option<u16>,
-    /// The kanji's name as a radical if it is one.
-    pub radical_names: Option<Vec<String>>,
-    /// Old JLPT level of the kanji. Based on pre-2010 test levels
-    /// that go up to four, not five.
-    pub jlpt: Option<u8>,
-    /// Indexes into dictionaries and other instructional books
-    pub references: Option<Vec<Reference>>,
-    /// Codes used to identify the kanji
-    pub query_codes: Option<Vec<QueryCode>>,
-    /// Different ways the kanji can be read.
-    pub readings: Option<Vec<Reading>>,
-    /// Translations of the kanji into different languages.
-    pub translations: Option<Translations>,
-    /// Japanese readings associated with names.
-    pub nanori: Option<Vec<String>>,
-    /// The constituent radicals in the kanji
-    pub decomposition: Option<Vec<char>>,
-}
-
-impl CharacterBuilder {
-    pub fn new() -> Self {
-        Self {
-            literal: None,
-            codepoints: None,
-            radicals: None,
-            grade: None,
-            stroke_counts: None,
-            variants: None,
-            frequency: None,
-            radical_names: None,
-            jlpt: None,
-            references: None,
-            query_codes: None,
-            readings: None,
-            translations: None,
-            nanori: None,
-            decomposition: None,
+impl CharacterError {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            CharacterError::Shared(err) => err.code(),
+            CharacterError::Codepoint(err) => err.code(),
+            CharacterError::Radical(err) => err.code(),
+            CharacterError::Grade(err) => err.code(),
+            CharacterError::StrokeCount(err) => err.code(),
+            CharacterError::Variant(err) => err.code(),
+            CharacterError::Translation(err) => err.code(),
+            CharacterError::Reading(err) => err.code(),
+            CharacterError::QueryCode(err) => err.code(),
+            CharacterError::DictionaryReference(err) => err.code(),
+            CharacterError::NanoriText(_) => ErrorCode::E0103_MISSING_NANORI_TEXT,
+            CharacterError::EmptyLiteral(_) => ErrorCode::E0101_INVALID_LITERAL,
+            CharacterError::IncompleteCharacter(_, _) => ErrorCode::E0102_INCOMPLETE_CHARACTER,
         }
     }
+}
 
-    fn build(self) -> Result<Character, CharacterError> {
-        let literal = self.literal.ok_or(CharacterError::IncompleteCharacter)?;
-        let codepoints = self.codepoints.unwrap_or_default();
-        let radicals = self.radicals.unwrap_or_default();
-        let grade = self.grade;
-        let stroke_counts = self
-            .stroke_counts
-            .ok_or(CharacterError::IncompleteCharacter)?;
-        let variants = self.variants.unwrap_or_default();
-        let frequency = self.frequency;
-        let radical_names = self.radical_names.unwrap_or_default();
-        let jlpt = self.jlpt;
-        let references = self.references.unwrap_or_default();
-        let query_codes = self.query_codes.unwrap_or_default();
-        let readings = self.readings.unwrap_or_default();
-        let translations = self.translations.unwrap_or_default();
-        let nanori = self.nanori.unwrap_or_default();
-        let decomposition = self.decomposition.unwrap_or_default();
-
-        Ok(Character {
-            literal,
-            codepoints,
-            radicals,
-            grade,
-            stroke_counts,
-            variants,
-            frequency,
-            radical_names,
-            jlpt,
-            references,
-            query_codes,
-            readings,
-            translations,
-            nanori,
-            decomposition,
-        })
-    }
+/// Splits a literal into its first scalar value and, when there is more than one,
+/// the whole literal, such as `葛` followed by a variation selector.
+pub fn split_literal(s: &str) -> Option<(char, Option<String>)> {
+    let mut chars = s.chars();
+    let first = chars.next()?;
+    let sequence = chars.next().map(|_| s.to_owned());
+    Some((first, sequence))
 }
 
-pub fn string_to_char(s: &str) -> Result<char, CharacterError> {
+/// Gets the only char in the string, if it has exactly one.
+pub fn string_to_char(s: &str) -> Option<char> {
     let mut chars = s.chars();
-    let radical = chars.next().ok_or(CharacterError::NonCharString);
+    let radical = chars.next();
     match chars.next() {
-        Some(_) => Err(CharacterError::NonCharString),
+        Some(_) => None,
         None => radical,
     }
 }
 
 pub fn from(character_node: Node) -> Result<Character, CharacterError> {
-    let mut builder = CharacterBuilder::new();
+    from_with(character_node, &ParseOptions::default())
+}
+
+/// Parses a character, skipping the sections the options exclude.
+pub fn from_with(
+    character_node: Node,
+    options: &ParseOptions,
+) -> Result<Character, CharacterError> {
+    let mut builder = Character::builder();
     for child in character_node.children() {
         match child.tag_name().name() {
             "literal" => {
-                let literal = string_to_char(text(&child)?)?;
-                builder.literal = Some(literal);
-                builder.decomposition = Some(decomposition(literal));
+                let (literal, sequence) = split_literal(text(&child)?)
+                    .ok_or_else(|| CharacterError::EmptyLiteral(PosError::from(&child)))?;
+                builder = builder
+                    .literal(literal)
+                    .decomposition(decomposition::components(options.decomposition, literal));
+                if let Some(sequence) = sequence {
+                    builder = builder.literal_sequence(sequence);
+                }
             }
-            "codepoint" => {
-                builder.codepoints = Some(children(&child, "cp_value", codepoint::from)?);
+            "codepoint" if options.codepoints => {
+                builder = builder.codepoints(children(&child, "cp_value", codepoint::from)?);
             }
-            "radical" => {
-                builder.radicals = Some(children(&child, "rad_value", radical::from)?);
+            "radical" if options.radicals => {
+                builder = builder.radicals(children(&child, "rad_value", radical::from)?);
             }
             "misc" => {
-                unpack_misc(&child, &mut builder)?;
+                builder = unpack_misc(&child, builder, options)?;
             }
-            "dic_number" => {
-                builder.references = Some(children(&child, "dic_ref", reference::from)?);
+            "dic_number" if options.references => {
+                builder = builder.references(children(&child, "dic_ref", reference::from)?);
             }
-            "query_code" => {
-                builder.query_codes = Some(children(&child, "q_code", query_code::from)?);
+            "query_code" if options.query_codes => {
+                builder = builder.query_codes(children(&child, "q_code", query_code::from)?);
             }
             "reading_meaning" => {
-                unpack_reading_meaning(&child, &mut builder)?;
+                builder = unpack_reading_meaning(&child, builder, options)?;
             }
             _ => {}
         }
     }
-    builder.build()
+    builder
+        .build()
+        .map_err(|err| CharacterError::IncompleteCharacter(PosError::from(&character_node), err))
 }
 
 fn unpack_reading_meaning(
     reading_meaning: &Node,
-    builder: &mut CharacterBuilder,
-) -> Result<(), CharacterError> {
+    mut builder: CharacterBuilder,
+    options: &ParseOptions,
+) -> Result<CharacterBuilder, CharacterError> {
     let mut nanori = vec![];
     for child in reading_meaning.children() {
         match child.tag_name().name() {
             "rmgroup" => {
-                unpack_rmgroup(&child, builder)?;
+                builder = unpack_rmgroup(&child, builder, options)?;
             }
-            "nanori" => {
+            "nanori" if options.nanori => {
                 nanori.push(
                     text(&child)
                         .map(|s: &str| s.to_owned())
@@ -198,47 +154,52 @@ fn unpack_reading_meaning(
             _ => {}
         }
     }
-    builder.nanori = Some(nanori);
-    Ok(())
+    Ok(builder.nanori(nanori))
 }
 
-fn unpack_rmgroup(rmgroup: &Node, builder: &mut CharacterBuilder) -> Result<(), CharacterError> {
+fn unpack_rmgroup(
+    rmgroup: &Node,
+    builder: CharacterBuilder,
+    options: &ParseOptions,
+) -> Result<CharacterBuilder, CharacterError> {
     let mut readings = vec![];
     let mut translations = Translations::default();
     for child in rmgroup.children() {
         match child.tag_name().name() {
-            "reading" => {
+            "reading" if options.readings => {
                 readings.push(reading::from(child)?);
             }
-            "meaning" => {
+            "meaning" if options.translations.includes(translation::language(&child)) => {
                 translation::add_meaning(&mut translations, &child)?;
             }
             _ => {}
         }
     }
-    builder.readings = Some(readings);
-    builder.translations = Some(translations);
-    Ok(())
+    Ok(builder.readings(readings).translations(translations))
 }
 
-fn unpack_misc(misc: &Node, builder: &mut CharacterBuilder) -> Result<(), CharacterError> {
+fn unpack_misc(
+    misc: &Node,
+    mut builder: CharacterBuilder,
+    options: &ParseOptions,
+) -> Result<CharacterBuilder, CharacterError> {
     let mut variants = vec![];
     let mut radical_names = vec![];
     let mut stroke_counts = StrokeCountBuilder::default();
     for child in misc.children() {
         match child.tag_name().name() {
             "grade" => {
-                builder.grade = Some(grade::from(child)?);
+                builder = builder.grade(grade::from(child)?);
             }
-            "variant" => variants.push(variant::from(child)?),
+            "variant" if options.variants => variants.push(variant::from(child)?),
             "freq" => {
-                builder.frequency = Some(text_uint::<u16>(&child)?);
+                builder = builder.frequency(text_uint::<u16>(&child)?);
             }
-            "rad_name" => {
+            "rad_name" if options.radical_names => {
                 radical_names.push(text(&child)?.to_owned());
             }
             "jlpt" => {
-                builder.jlpt = Some(text_uint::<u8>(&child)?);
+                builder = builder.jlpt(text_uint::<u8>(&child)?);
             }
             "stroke_count" => {
                 stroke_counts.add_from_node(&child)?;
@@ -246,27 +207,28 @@ fn unpack_misc(misc: &Node, builder: &mut CharacterBuilder) -> Result<(), Charac
             _ => {}
         }
     }
-    builder.variants = Some(variants);
-    builder.radical_names = Some(radical_names);
-    builder.stroke_counts = Some(stroke_counts.build()?);
-    Ok(())
+    Ok(builder
+        .variants(variants)
+        .radical_names(radical_names)
+        .stroke_counts(stroke_counts.build(misc)?))
 }
 
-fn decomposition(literal: char) -> Vec<char> {
-    for decomposition in kradical_static::DECOMPOSITIONS {
-        if decomposition.kanji == literal {
-            let out: Vec<char> = decomposition.radicals.to_vec();
-            return out;
-        }
-    }
-    vec![]
+/// Serializes the character as a KANJIDIC2 `<character>` element.
+pub fn to_xml(character: &Character) -> String {
+    let mut out = vec![];
+    writer::character(character, &mut out).expect("Writing to a Vec should not fail");
+    String::from_utf8(out).expect("Written XML should be UTF-8")
 }
 
 #[cfg(test)]
 mod tests {
-    use super::from;
-    use crate::test_shared::DOC;
+    use super::{from, from_with, to_xml, CharacterError};
+    use crate::{
+        parse_options::{LanguageFilter, ParseOptions},
+        test_shared::DOC,
+    };
     use kanjidic_types::{
+        character::{BuildError, Translations},
         de_roo::{ExtremeBottom, ExtremeTop},
         four_corner::Stroke,
         kunyomi::KunyomiKind,
@@ -275,9 +237,9 @@ mod tests {
         pin_yin::Tone,
         radical::RadicalKind,
         skip::{SkipSolid, SolidSubpattern},
-        Character, Codepoint, DeRoo, FourCorner, Grade, KangXi, Kunyomi, Kuten, Moro, Oneill,
-        PinYin, QueryCode, Radical, Reading, Reference, ShDesc, ShRadical, Skip, StrokeCount,
-        Variant,
+        Character, Codepoint, DeRoo, FourCorner, Grade, KangXi, Kunyomi, Kuten, Language, Moro,
+        Oneill, PinYin, QueryCode, Radical, Reading, Reference, ShDesc, ShRadical, Skip,
+        StrokeCount, Variant,
     };
     use std::{collections::HashMap, iter::FromIterator};
 
@@ -287,12 +249,20 @@ mod tests {
             .descendants()
             .find(|node| node.has_tag_name("character"))
             .unwrap();
-        let character = from(node);
+        let decompositions: HashMap<_, _> =
+            vec![('亜', vec!['｜', '一', '口'])].into_iter().collect();
+        let options = ParseOptions {
+            decomposition: Some(&decompositions),
+            ..ParseOptions::default()
+        };
+        let character = from_with(node, &options);
         assert_eq!(
             character,
             Ok(Character {
                 literal: '亜',
+                literal_sequence: None,
                 decomposition: vec!['｜', '一', '口'],
+                ids: None,
                 codepoints: vec![
                     Codepoint::Unicode(20124),
                     Codepoint::Jis208(Kuten {
@@ -394,9 +364,9 @@ mod tests {
                         okurigana: Some("ぐ".into()),
                     })
                 ],
-                translations: HashMap::from_iter([
+                translations: Translations::from_iter([
                     (
-                        "en".to_owned(),
+                        Language::En,
                         vec![
                             "Asia".to_owned(),
                             "rank next".to_owned(),
@@ -405,7 +375,7 @@ mod tests {
                         ]
                     ),
                     (
-                        "fr".to_owned(),
+                        Language::Fr,
                         vec![
                             "Asie".to_owned(),
                             "suivant".to_owned(),
@@ -414,7 +384,7 @@ mod tests {
                         ]
                     ),
                     (
-                        "pt".to_owned(),
+                        Language::Pt,
                         vec![
                             "Ásia".to_owned(),
                             "próxima".to_owned(),
@@ -423,7 +393,7 @@ mod tests {
                         ]
                     ),
                     (
-                        "es".to_owned(),
+                        Language::Es,
                         vec![
                             "pref. para indicar".to_owned(),
                             "venir después de".to_owned(),
@@ -434,4 +404,164 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn selected_sections() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("character"))
+            .unwrap();
+        let options = ParseOptions {
+            references: false,
+            query_codes: false,
+            translations: LanguageFilter::Only(&["en"]),
+            decomposition: None,
+            ..ParseOptions::default()
+        };
+        let character = from_with(node, &options).unwrap();
+        assert!(character.references.is_empty());
+        assert!(character.query_codes.is_empty());
+        assert!(!character.readings.is_empty());
+        assert_eq!(character.translations.len(), 1);
+        assert_eq!(character.translations["en"][0], "Asia");
+        assert!(character.decomposition.is_empty());
+    }
+
+    #[test]
+    fn xml_fragment() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("character"))
+            .unwrap();
+        let character = from(node).unwrap();
+        let xml = to_xml(&character);
+        assert!(xml.starts_with("<character>\n\t<literal>亜</literal>\n"));
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        assert_eq!(from(doc.root_element()), Ok(character));
+    }
+
+    #[test]
+    fn variation_sequence() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("character"))
+            .unwrap();
+        let xml = to_xml(&from(node).unwrap())
+            .replace("<literal>亜</literal>", "<literal>亜\u{E0100}</literal>");
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        let character = from(doc.root_element()).unwrap();
+        assert_eq!(character.literal, '亜');
+        assert_eq!(character.literal_sequence.as_deref(), Some("亜\u{E0100}"));
+        assert_eq!(character.literal_str(), "亜\u{E0100}");
+        assert!(to_xml(&character).contains("<literal>亜\u{E0100}</literal>"));
+    }
+
+    #[test]
+    fn incomplete() {
+        let doc =
+            roxmltree::Document::parse("<character><literal>亜</literal></character>").unwrap();
+        let error = from(doc.root_element()).unwrap_err();
+        assert!(matches!(
+            error,
+            CharacterError::IncompleteCharacter(_, BuildError::MissingStrokeCount)
+        ));
+    }
+
+    #[test]
+    fn accessors() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("character"))
+            .unwrap();
+        let character = from(node).unwrap();
+        assert_eq!(character.onyomi().collect::<Vec<_>>(), vec!["ア"]);
+        assert_eq!(
+            character.kunyomi().collect::<Vec<_>>(),
+            vec![&Kunyomi {
+                kind: KunyomiKind::Normal,
+                reading: "つ".into(),
+                okurigana: Some("ぐ".into()),
+            }]
+        );
+        assert_eq!(
+            character.pinyin().collect::<Vec<_>>(),
+            vec![&PinYin {
+                romanization: "ya".into(),
+                tone: Tone::Falling,
+            }]
+        );
+        assert_eq!(character.primary_meaning(), Some("Asia"));
+        assert_eq!(character.meanings("fr").map(<[String]>::len), Some(4));
+        assert_eq!(character.meanings("de"), None);
+    }
+
+    #[test]
+    fn summary() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("character"))
+            .unwrap();
+        let character = from(node).unwrap();
+        assert_eq!(
+            character.to_string(),
+            "亜 (7 strokes, Jouyou, frequency 1509, JLPT 1)\n\
+             On: ア\n\
+             Kun: つ.ぐ\n\
+             Nanori: や, つぎ, つぐ\n\
+             Pinyin: yà\n\
+             Korean: a, 아\n\
+             Vietnamese: A, Á\n\
+             Meanings: Asia, rank next, come after, -ous"
+        );
+    }
+
+    #[test]
+    fn builder() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("character"))
+            .unwrap();
+        let parsed = from(node).unwrap();
+        let built = Character::builder()
+            .literal('亜')
+            .stroke_counts(StrokeCount {
+                accepted: 7,
+                miscounts: vec![],
+            })
+            .grade(Grade::Jouyou)
+            .reading(Reading::Onyomi("ア".into()))
+            .meaning("en", "Asia")
+            .meaning(Language::En, "rank next")
+            .build()
+            .unwrap();
+        assert_eq!(built.literal, parsed.literal);
+        assert_eq!(built.stroke_counts, parsed.stroke_counts);
+        assert_eq!(built.onyomi().collect::<Vec<_>>(), vec!["ア"]);
+        assert_eq!(
+            &parsed.meanings("en").unwrap()[..2],
+            built.meanings("en").unwrap()
+        );
+        assert!(built.codepoints.is_empty());
+
+        let strokes = StrokeCount {
+            accepted: 1,
+            miscounts: vec![],
+        };
+        assert_eq!(
+            Character::builder().stroke_counts(strokes.clone()).build(),
+            Err(BuildError::MissingLiteral)
+        );
+        assert_eq!(
+            Character::builder().literal('亜').build(),
+            Err(BuildError::MissingStrokeCount)
+        );
+        assert_eq!(
+            Character::builder()
+                .literal('亜')
+                .literal_sequence("葛\u{E0100}")
+                .stroke_counts(strokes)
+                .build(),
+            Err(BuildError::LiteralSequence("葛\u{E0100}".into()))
+        );
+    }
 }
@@ -0,0 +1,53 @@
+use crate::{ids::IdsDatabase, kanjidic::Kanjidic};
+use std::{fs, io, path::Path};
+
+/// Reads every `IDS-*.txt` file in a directory of CHISE IDS data,
+/// such as a checkout of <https://gitlab.chise.org/CHISE/ids>.
+pub fn load_dir<P: AsRef<Path>>(dir: P) -> io::Result<IdsDatabase> {
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_ids = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("IDS-") && name.ends_with(".txt"));
+        if is_ids {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    let mut database = IdsDatabase::default();
+    for path in paths {
+        database.merge(IdsDatabase::from_ids_txt(&fs::read_to_string(path)?));
+    }
+    Ok(database)
+}
+
+impl Kanjidic {
+    /// Fills in the Ideographic Description Sequence of each character
+    /// from a directory of CHISE IDS data.
+    pub fn merge_chise_ids<P: AsRef<Path>>(&mut self, dir: P) -> io::Result<()> {
+        load_dir(dir)?.apply(&mut self.characters);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load_dir;
+    use crate::test_shared::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn directory() {
+        let dir = temp_dir("chise_directory");
+        fs::write(dir.join("IDS-UCS-Basic.txt"), "U+4E9C\t亜\t⿱一⿻口丨\n").unwrap();
+        fs::write(dir.join("IDS-UCS-Ext-A.txt"), "U+3400\t㐀\t⿱一卜\n").unwrap();
+        fs::write(dir.join("README.txt"), "U+5516\t唖\t⿰口亜\n").unwrap();
+        let ids = load_dir(&dir).unwrap();
+        assert_eq!(ids.get('亜'), Some("⿱一⿻口丨"));
+        assert_eq!(ids.get('㐀'), Some("⿱一卜"));
+        assert_eq!(ids.get('唖'), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
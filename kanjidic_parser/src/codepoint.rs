@@ -1,4 +1,8 @@
-use crate::shared::{self, attr, text_hex, SharedError};
+use crate::{
+    error_code::ErrorCode,
+    pos_error::PosError,
+    shared::{self, attr, text_hex, SharedError},
+};
 use kanjidic_types::{kuten, Codepoint, Kuten};
 use roxmltree::Node;
 use std::convert::TryFrom;
@@ -7,21 +11,33 @@ use std::convert::TryFrom;
 pub enum Error {
     #[error("(Codepoint) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Codepoint) Unrecognized encoding")]
-    Encoding,
-    #[error("(Codepoint) Kuten: {0}")]
-    Kuten(#[from] kuten::ParseError),
+    #[error("(Codepoint) Unrecognized encoding: {0}")]
+    Encoding(PosError),
+    #[error("(Codepoint) Kuten: {0}, {1}")]
+    Kuten(PosError, kuten::ParseError),
+}
+
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Encoding(_) => ErrorCode::E0104_UNKNOWN_CODEPOINT,
+            Error::Kuten(_, _) => ErrorCode::E0105_INVALID_KUTEN,
+        }
+    }
 }
 
 pub fn from(node: Node) -> Result<Codepoint, Error> {
     let text = shared::text(&node)?;
     let encoding = attr(&node, "cp_type")?;
+    let kuten = || Kuten::try_from(text).map_err(|err| Error::Kuten(PosError::from(&node), err));
     match encoding {
-        "jis208" => Ok(Codepoint::Jis208(Kuten::try_from(text)?)),
-        "jis212" => Ok(Codepoint::Jis212(Kuten::try_from(text)?)),
-        "jis213" => Ok(Codepoint::Jis213(Kuten::try_from(text)?)),
+        "jis208" => Ok(Codepoint::Jis208(kuten()?)),
+        "jis212" => Ok(Codepoint::Jis212(kuten()?)),
+        "jis213" => Ok(Codepoint::Jis213(kuten()?)),
         "ucs" => Ok(Codepoint::Unicode(text_hex(&node)?)),
-        _ => Err(Error::Encoding),
+        _ => Err(Error::Encoding(PosError::from(&node))),
     }
 }
 
@@ -1,21 +1,49 @@
-use crate::shared::{self, SharedError};
-use kanjidic_types::{take_uint, IResult, NomErr, NomErrorReason};
+use crate::{
+    error_code::ErrorCode,
+    pos_error::PosError,
+    shared::{self, SharedError},
+};
+use kanjidic_types::{take_uint, IResult, NomErrorReason};
 use nom::{character::complete::char, combinator::map_res, sequence::tuple};
 use roxmltree::Node;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
 
 /// Error while parsing the database version
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
     #[error("(Database version) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Database version) Format: {0}")]
-    Format(NomErrorReason),
+    #[error("(Database version) Format: {0}, {1}")]
+    Format(PosError, NomErrorReason),
+}
+
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Format(_, _) => ErrorCode::E0202_INVALID_DATABASE_VERSION,
+        }
+    }
+
+    /// Where in the document the failure happened.
+    pub fn position(&self) -> &PosError {
+        match self {
+            Error::Shared(err) => err.position(),
+            Error::Format(pos, _) => pos,
+        }
+    }
 }
 
 /// The version of the file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "snapshot", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DatabaseVersion {
     /// The year of release.
     pub year: u16,
@@ -24,19 +52,11 @@ pub struct DatabaseVersion {
     pub version: u16,
 }
 
-impl<'a> From<NomErr<'a>> for Error {
-    fn from(err: NomErr<'a>) -> Self {
-        Self::Format(err.into())
-    }
-}
-
-impl<'a, 'input> TryFrom<Node<'a, 'input>> for DatabaseVersion {
-    type Error = Error;
-
-    fn try_from(node: Node) -> Result<DatabaseVersion, Error> {
-        let text = shared::text(&node)?;
-        Ok(map_res(take_db_version, map_db_version)(text).map(|(_, s)| s)?)
-    }
+pub fn from(node: Node) -> Result<DatabaseVersion, Error> {
+    let text = shared::text(&node)?;
+    map_res(take_db_version, map_db_version)(text)
+        .map(|(_, s)| s)
+        .map_err(|err| Error::Format(PosError::from(&node), err.into()))
 }
 
 type DbVersionParts<'a> = (u16, char, u16);
@@ -52,8 +72,8 @@ fn map_db_version(parts: DbVersionParts) -> Result<DatabaseVersion, Error> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{database_version::DatabaseVersion, test_shared::DOC};
-    use std::convert::TryFrom;
+    use super::{from, DatabaseVersion};
+    use crate::test_shared::DOC;
 
     #[test]
     fn gets_db_version() {
@@ -61,7 +81,7 @@ mod tests {
             .descendants()
             .find(|node| node.has_tag_name("database_version"))
             .unwrap();
-        let version = DatabaseVersion::try_from(node);
+        let version = from(node);
         assert_eq!(
             version,
             Ok(DatabaseVersion {
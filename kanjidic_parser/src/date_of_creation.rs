@@ -1,12 +1,22 @@
-use crate::shared::{self, SharedError};
-use kanjidic_types::{take_uint, IResult, NomErr, NomErrorReason};
+use crate::{
+    error_code::ErrorCode,
+    pos_error::PosError,
+    shared::{self, SharedError},
+};
+use kanjidic_types::{take_uint, IResult, NomErrorReason};
 use nom::{character::complete::char, combinator::map_res, sequence::tuple};
 use roxmltree::Node;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
 
 /// The date the file was created
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "snapshot", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DateOfCreation {
     /// Year of creation
     pub year: u16,
@@ -21,25 +31,35 @@ pub struct DateOfCreation {
 pub enum Error {
     #[error("(Date of creation) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Date of creation) Format: {0}")]
-    Format(NomErrorReason),
+    #[error("(Date of creation) Format: {0}, {1}")]
+    Format(PosError, NomErrorReason),
 }
 
-impl<'a> From<NomErr<'a>> for Error {
-    fn from(err: NomErr<'a>) -> Self {
-        Self::Format(err.into())
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Format(_, _) => ErrorCode::E0203_INVALID_DATE_OF_CREATION,
+        }
     }
-}
-
-impl<'a, 'input> TryFrom<Node<'a, 'input>> for DateOfCreation {
-    type Error = Error;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
-        let text = shared::text(&node)?;
-        Ok(map_res(take_db_version, map_db_version)(text).map(|(_, s)| s)?)
+    /// Where in the document the failure happened.
+    pub fn position(&self) -> &PosError {
+        match self {
+            Error::Shared(err) => err.position(),
+            Error::Format(pos, _) => pos,
+        }
     }
 }
 
+pub fn from(node: Node) -> Result<DateOfCreation, Error> {
+    let text = shared::text(&node)?;
+    map_res(take_db_version, map_db_version)(text)
+        .map(|(_, s)| s)
+        .map_err(|err| Error::Format(PosError::from(&node), err.into()))
+}
+
 type DateOfCreationParts<'a> = (u16, char, u8, char, u8);
 
 fn take_db_version(s: &str) -> IResult<DateOfCreationParts> {
@@ -57,8 +77,8 @@ fn map_db_version(parts: DateOfCreationParts) -> Result<DateOfCreation, Error> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{date_of_creation::DateOfCreation, test_shared::DOC};
-    use std::convert::TryFrom;
+    use super::{from, DateOfCreation};
+    use crate::test_shared::DOC;
 
     #[test]
     fn gets_date_of_creation() {
@@ -66,7 +86,7 @@ mod tests {
             .descendants()
             .find(|node| node.has_tag_name("date_of_creation"))
             .unwrap();
-        let version = DateOfCreation::try_from(node);
+        let version = from(node);
         assert_eq!(
             version,
             Ok(DateOfCreation {
@@ -0,0 +1,143 @@
+use super::KanjidicDb;
+use crate::decomposition::normalize_component;
+use kanjidic_types::Character;
+use std::{collections::HashMap, ops::RangeBounds};
+
+/// The characters containing each normalized component, in dictionary order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct ComponentIndex {
+    kanji: HashMap<char, Vec<usize>>,
+}
+
+impl ComponentIndex {
+    pub(super) fn new(characters: &[Character]) -> Self {
+        let mut kanji: HashMap<char, Vec<usize>> = HashMap::new();
+        for (i, character) in characters.iter().enumerate() {
+            for &component in &character.decomposition {
+                let containing = kanji.entry(normalize_component(component)).or_default();
+                if containing.last() != Some(&i) {
+                    containing.push(i);
+                }
+            }
+        }
+        Self { kanji }
+    }
+
+    pub(super) fn containing(&self, component: char) -> &[usize] {
+        self.kanji
+            .get(&normalize_component(component))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+impl KanjidicDb {
+    /// Finds the characters whose decomposition contains the given component.
+    /// Variant forms of a component, such as 氵 and 水, are treated as the same component.
+    pub fn kanji_containing(&self, component: char) -> Vec<&Character> {
+        self.components
+            .containing(component)
+            .iter()
+            .map(|&i| &self.characters[i])
+            .collect()
+    }
+
+    /// Iterates over every component that appears in a decomposition,
+    /// with variant forms normalized by [`normalize_component`].
+    pub fn components(&self) -> impl Iterator<Item = char> + '_ {
+        self.components.kanji.keys().copied()
+    }
+
+    /// Finds the characters whose decomposition contains every one of the given components.
+    /// Variant forms of a component, such as 氵 and 水, are treated as the same component.
+    pub fn containing_components(&self, components: &[char]) -> Vec<&Character> {
+        self.containing_components_with_strokes(components, ..)
+    }
+
+    /// Finds the characters whose decomposition contains every one of the given components
+    /// and whose accepted stroke count is in the given range.
+    pub fn containing_components_with_strokes<R: RangeBounds<u8>>(
+        &self,
+        components: &[char],
+        strokes: R,
+    ) -> Vec<&Character> {
+        let rarest = components
+            .iter()
+            .map(|&component| self.components.containing(component))
+            .min_by_key(|containing| containing.len());
+        let rarest = match rarest {
+            Some(rarest) => rarest,
+            None => return vec![],
+        };
+        rarest
+            .iter()
+            .map(|&i| &self.characters[i])
+            .filter(|character| strokes.contains(&character.stroke_counts.accepted))
+            .filter(|character| {
+                components
+                    .iter()
+                    .all(|&component| contains_component(character, component))
+            })
+            .collect()
+    }
+}
+
+/// Whether the decomposition contains the component, comparing normalized forms.
+pub(super) fn contains_component(character: &Character, component: char) -> bool {
+    let component = normalize_component(component);
+    character
+        .decomposition
+        .iter()
+        .any(|&other| normalize_component(other) == component)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::KanjidicDb,
+        test_shared::{decomposed, CHARACTERS},
+    };
+
+    #[test]
+    fn all_components() {
+        let db = KanjidicDb::new(decomposed());
+        let found = db.containing_components(&['口', '一']);
+        assert!(found.iter().any(|character| character.literal == '亜'));
+        assert!(found.iter().all(|character| {
+            character.decomposition.contains(&'口') && character.decomposition.contains(&'一')
+        }));
+        assert!(db.containing_components(&[]).is_empty());
+    }
+
+    #[test]
+    fn reverse_index() {
+        let db = KanjidicDb::new(decomposed());
+        let found = db.kanji_containing('口');
+        assert!(found.iter().any(|character| character.literal == '亜'));
+        assert!(found
+            .iter()
+            .all(|character| character.decomposition.contains(&'口')));
+        assert!(db.components().any(|component| component == '口'));
+        assert!(db.kanji_containing('a').is_empty());
+    }
+
+    #[test]
+    fn filtered_by_strokes() {
+        let db = KanjidicDb::new(decomposed());
+        let found = db.containing_components_with_strokes(&['口', '一'], 7..=7);
+        assert!(found.iter().any(|character| character.literal == '亜'));
+        let found = db.containing_components_with_strokes(&['口', '一'], 8..);
+        assert!(!found.iter().any(|character| character.literal == '亜'));
+    }
+
+    #[test]
+    fn variant_forms() {
+        let mut characters = CHARACTERS.clone();
+        characters[0].decomposition = vec!['氵', '口'];
+        let db = KanjidicDb::new(characters);
+        for component in ['水', '氵', '⺡'].iter() {
+            let found = db.containing_components(&[*component, '口']);
+            assert_eq!(found[0].literal, '亜');
+            assert_eq!(db.kanji_containing(*component)[0].literal, '亜');
+        }
+    }
+}
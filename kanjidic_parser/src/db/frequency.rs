@@ -0,0 +1,84 @@
+use super::KanjidicDb;
+use kanjidic_types::Character;
+
+/// The characters that have a frequency ranking, from most to least frequent.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct FrequencyIndex {
+    ranked: Vec<usize>,
+}
+
+impl FrequencyIndex {
+    pub(super) fn new(characters: &[Character]) -> Self {
+        let mut ranked: Vec<usize> = characters
+            .iter()
+            .enumerate()
+            .filter(|(_, character)| character.frequency.is_some())
+            .map(|(i, _)| i)
+            .collect();
+        ranked.sort_by_key(|&i| characters[i].frequency);
+        Self { ranked }
+    }
+}
+
+impl KanjidicDb {
+    /// The percentage of ranked characters that are no more frequent than the given one,
+    /// so the most frequent character is at 100 and the least frequent is close to 0.
+    /// Characters without a frequency ranking have no percentile.
+    pub fn frequency_percentile(&self, literal: char) -> Option<f64> {
+        let rank = self.get(literal)?.frequency?;
+        let ranked = &self.frequencies.ranked;
+        let more_frequent =
+            ranked.partition_point(|&i| self.characters[i].frequency.unwrap() < rank);
+        Some(100.0 * (ranked.len() - more_frequent) as f64 / ranked.len() as f64)
+    }
+
+    /// The most frequent characters, from most to least frequent.
+    pub fn top_n_by_frequency(&self, n: usize) -> Vec<&Character> {
+        self.by_frequency().take(n).collect()
+    }
+
+    /// Iterates over the characters with a frequency ranking,
+    /// from most to least frequent.
+    pub fn by_frequency(&self) -> impl Iterator<Item = &Character> {
+        self.frequencies
+            .ranked
+            .iter()
+            .map(move |&i| &self.characters[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+
+    #[test]
+    fn top_n() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let top = db.top_n_by_frequency(10);
+        assert_eq!(top.len(), 10);
+        assert!(top
+            .windows(2)
+            .all(|pair| pair[0].frequency.unwrap() <= pair[1].frequency.unwrap()));
+        let best = CHARACTERS
+            .iter()
+            .filter_map(|character| character.frequency)
+            .min();
+        assert_eq!(top[0].frequency, best);
+    }
+
+    #[test]
+    fn percentiles() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let top = db.top_n_by_frequency(1)[0].literal;
+        assert_eq!(db.frequency_percentile(top), Some(100.0));
+        let last = db.by_frequency().last().unwrap().literal;
+        let lowest = db.frequency_percentile(last).unwrap();
+        assert!(lowest > 0.0 && lowest < 10.0);
+        let unranked = CHARACTERS
+            .iter()
+            .find(|character| character.frequency.is_none())
+            .unwrap();
+        assert_eq!(db.frequency_percentile(unranked.literal), None);
+        assert_eq!(db.frequency_percentile('a'), None);
+    }
+}
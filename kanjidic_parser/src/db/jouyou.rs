@@ -0,0 +1,22 @@
+use super::KanjidicDb;
+use crate::jouyou;
+
+impl KanjidicDb {
+    /// The year the character entered the official kanji lists,
+    /// as described in [`jouyou::since`].
+    pub fn jouyou_since(&self, literal: char) -> Option<u16> {
+        self.get(literal).and_then(jouyou::since)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+
+    #[test]
+    fn adoption_year() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        assert_eq!(db.jouyou_since('亜'), Some(1946));
+        assert_eq!(db.jouyou_since('a'), None);
+    }
+}
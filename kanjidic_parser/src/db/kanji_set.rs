@@ -0,0 +1,234 @@
+use super::KanjidicDb;
+use kanjidic_types::Character;
+use std::{
+    iter::FromIterator,
+    ops::{BitAnd, BitOr, Sub},
+};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of characters from a [`KanjidicDb`], stored as one bit per position in the database
+/// so that sets combine cheaply with union, intersection, and difference.
+/// Sets only make sense alongside the database whose positions they hold.
+#[derive(Debug, Clone, Default)]
+pub struct KanjiSet {
+    words: Vec<u64>,
+}
+
+impl KanjiSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the character at the given position,
+    /// returning whether it was not already in the set.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, bit) = split(index);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let added = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        added
+    }
+
+    /// Removes the character at the given position,
+    /// returning whether it was in the set.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, bit) = split(index);
+        match self.words.get_mut(word) {
+            Some(word) => {
+                let removed = *word & bit != 0;
+                *word &= !bit;
+                removed
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the set has the character at the given position.
+    pub fn contains(&self, index: usize) -> bool {
+        let (word, bit) = split(index);
+        self.words.get(word).is_some_and(|word| word & bit != 0)
+    }
+
+    /// The number of characters in the set.
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Whether the set has no characters.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// The characters in either set.
+    pub fn union(&self, other: &KanjiSet) -> KanjiSet {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = longer.words.clone();
+        for (word, other) in words.iter_mut().zip(&shorter.words) {
+            *word |= other;
+        }
+        KanjiSet { words }
+    }
+
+    /// The characters in both sets.
+    pub fn intersection(&self, other: &KanjiSet) -> KanjiSet {
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(word, other)| word & other)
+            .collect();
+        KanjiSet { words }
+    }
+
+    /// The characters in this set but not the other.
+    pub fn difference(&self, other: &KanjiSet) -> KanjiSet {
+        let mut words = self.words.clone();
+        for (word, other) in words.iter_mut().zip(&other.words) {
+            *word &= !other;
+        }
+        KanjiSet { words }
+    }
+
+    /// Iterates over the positions in the set in ascending order.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            (0..BITS)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| i * BITS + bit)
+        })
+    }
+}
+
+fn split(index: usize) -> (usize, u64) {
+    (index / BITS, 1 << (index % BITS))
+}
+
+impl PartialEq for KanjiSet {
+    fn eq(&self, other: &Self) -> bool {
+        let (longer, shorter) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        longer.words[..shorter.words.len()] == shorter.words[..]
+            && longer.words[shorter.words.len()..]
+                .iter()
+                .all(|&word| word == 0)
+    }
+}
+
+impl Eq for KanjiSet {}
+
+impl FromIterator<usize> for KanjiSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = KanjiSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for KanjiSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for index in iter {
+            self.insert(index);
+        }
+    }
+}
+
+impl BitOr for &KanjiSet {
+    type Output = KanjiSet;
+
+    fn bitor(self, other: &KanjiSet) -> KanjiSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for &KanjiSet {
+    type Output = KanjiSet;
+
+    fn bitand(self, other: &KanjiSet) -> KanjiSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for &KanjiSet {
+    type Output = KanjiSet;
+
+    fn sub(self, other: &KanjiSet) -> KanjiSet {
+        self.difference(other)
+    }
+}
+
+impl KanjidicDb {
+    /// The set of every character in the database.
+    pub fn all_kanji(&self) -> KanjiSet {
+        (0..self.len()).collect()
+    }
+
+    /// The set of the given characters, skipping those not in the database.
+    pub fn kanji_set<I: IntoIterator<Item = char>>(&self, literals: I) -> KanjiSet {
+        literals
+            .into_iter()
+            .filter_map(|literal| self.index_of(literal))
+            .collect()
+    }
+
+    /// Iterates over the characters in the set in dictionary order.
+    pub fn characters_in<'a>(&'a self, set: &'a KanjiSet) -> impl Iterator<Item = &'a Character> {
+        set.indices().filter_map(move |i| self.characters.get(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KanjiSet;
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+    use kanjidic_types::Grade;
+
+    #[test]
+    fn set_algebra() {
+        let a: KanjiSet = vec![1, 3, 64, 130].into_iter().collect();
+        let b: KanjiSet = vec![3, 4, 130].into_iter().collect();
+        assert_eq!(
+            (&a | &b).indices().collect::<Vec<_>>(),
+            vec![1, 3, 4, 64, 130]
+        );
+        assert_eq!((&a & &b).indices().collect::<Vec<_>>(), vec![3, 130]);
+        assert_eq!((&a - &b).indices().collect::<Vec<_>>(), vec![1, 64]);
+        assert_eq!((&b - &a).len(), 1);
+        assert!((&b - &b).is_empty());
+        assert_eq!(&(&a - &b) | &(&a & &b), a);
+        assert_eq!(KanjiSet::new(), &b - &b);
+    }
+
+    #[test]
+    fn from_queries() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let jouyou = db.query().grade(Grade::Jouyou).to_set();
+        let common = db.query().frequency_below(1000).to_set();
+        let learned = db.kanji_set("依為".chars());
+        let next = &(&jouyou & &common) - &learned;
+        let expected: Vec<_> = db
+            .query()
+            .grade(Grade::Jouyou)
+            .frequency_below(1000)
+            .filter(|character| !"依為".contains(character.literal))
+            .collect();
+        assert_eq!(db.characters_in(&next).collect::<Vec<_>>(), expected);
+        assert!(!next.is_empty());
+        assert!(jouyou.contains(db.index_of('亜').unwrap()));
+        assert!(!next.contains(db.index_of('依').unwrap()));
+        assert_eq!(db.all_kanji().len(), db.len());
+    }
+}
@@ -0,0 +1,89 @@
+use super::KanjidicDb;
+use crate::{kanken::KankenLevel, rtk::Edition};
+use kanjidic_types::{Character, Reference};
+use std::collections::HashMap;
+
+impl KanjidicDb {
+    /// Attaches WaniKani levels, such as those read by [`crate::wanikani::parse`],
+    /// replacing any earlier levels for the same characters.
+    pub fn attach_wanikani_levels(&mut self, levels: HashMap<char, u8>) {
+        self.wanikani_levels.extend(levels);
+    }
+
+    /// The WaniKani level that teaches the character, if attached.
+    pub fn wanikani_level(&self, literal: char) -> Option<u8> {
+        self.wanikani_levels.get(&literal).copied()
+    }
+
+    /// The characters taught at the given WaniKani level, in dictionary order.
+    pub fn by_wanikani_level(&self, level: u8) -> Vec<&Character> {
+        self.characters
+            .iter()
+            .filter(|character| self.wanikani_level(character.literal) == Some(level))
+            .collect()
+    }
+
+    /// Attaches Heisig keywords, such as those read by [`crate::rtk::parse`],
+    /// to the characters whose Heisig reference for the edition has the keyword's frame number.
+    /// Keywords for frames that no character references are dropped.
+    pub fn attach_rtk_keywords(&mut self, edition: Edition, keywords: &HashMap<u16, String>) {
+        for character in self.characters.iter() {
+            let keyword = character.references.iter().find_map(|reference| {
+                let frame = match (edition, reference) {
+                    (Edition::Classic, Reference::Heisig(frame)) => frame,
+                    (Edition::Sixth, Reference::Heisig6(frame)) => frame,
+                    _ => return None,
+                };
+                keywords.get(frame)
+            });
+            if let Some(keyword) = keyword {
+                self.rtk_keywords.insert(character.literal, keyword.clone());
+            }
+        }
+    }
+
+    /// The Remembering the Kanji keyword of the character, if attached.
+    pub fn rtk_keyword(&self, literal: char) -> Option<&str> {
+        self.rtk_keywords.get(&literal).map(String::as_str)
+    }
+
+    /// Attaches Kanken levels, such as those read by [`crate::kanken::parse`],
+    /// replacing any earlier levels for the same characters.
+    pub fn attach_kanken_levels(&mut self, levels: HashMap<char, KankenLevel>) {
+        self.kanken_levels.extend(levels);
+    }
+
+    /// The Kanji Kentei level that first tests the character, if attached.
+    pub fn kanken_level(&self, literal: char) -> Option<KankenLevel> {
+        self.kanken_levels.get(&literal).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::KanjidicDb,
+        kanken::{self, KankenLevel},
+        rtk::{self, Edition},
+        test_shared::CHARACTERS,
+        wanikani,
+    };
+
+    #[test]
+    fn attached() {
+        let mut db = KanjidicDb::new(CHARACTERS.clone());
+        assert_eq!(db.wanikani_level('亜'), None);
+        assert_eq!(db.rtk_keyword('亜'), None);
+        assert_eq!(db.kanken_level('亜'), None);
+
+        db.attach_wanikani_levels(wanikani::parse("亜,53\n").unwrap());
+        assert_eq!(db.wanikani_level('亜'), Some(53));
+        assert_eq!(db.by_wanikani_level(53), vec![&CHARACTERS[0]]);
+
+        db.attach_rtk_keywords(Edition::Classic, &rtk::parse("1809,Asia\n").unwrap());
+        assert_eq!(db.rtk_keyword('亜'), Some("Asia"));
+
+        db.attach_kanken_levels(kanken::parse("亜,準2\n").unwrap());
+        assert_eq!(db.kanken_level('亜'), Some(KankenLevel::PreTwo));
+    }
+}
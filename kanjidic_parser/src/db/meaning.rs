@@ -0,0 +1,118 @@
+use super::KanjidicDb;
+use kanjidic_types::{sort, Character, Language};
+use std::cmp::Ordering;
+
+/// How closely a meaning matched a search, from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchKind {
+    /// The whole meaning is the query.
+    Exact,
+    /// Every word in the query is a word in the meaning.
+    Word,
+    /// Every word in the query appears somewhere in the meaning.
+    Substring,
+}
+
+/// A character whose meaning matched a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeaningMatch<'a> {
+    /// The matched character.
+    pub character: &'a Character,
+    /// The meaning that matched best.
+    pub meaning: &'a str,
+    /// The position of that meaning among the character's meanings.
+    pub position: usize,
+    /// How closely the meaning matched.
+    pub kind: MatchKind,
+}
+
+impl KanjidicDb {
+    /// Searches the meanings in the given language, ignoring case.
+    /// Results are ranked by how closely they matched, then by whether the match
+    /// was one of the character's first meanings, then by frequency.
+    pub fn search_meanings(&self, query: &str, language: &Language) -> Vec<MeaningMatch<'_>> {
+        let query = query.to_lowercase();
+        let words = tokenize(&query);
+        if words.is_empty() {
+            return vec![];
+        }
+        let mut matches: Vec<MeaningMatch> = self
+            .characters
+            .iter()
+            .filter_map(|character| {
+                character
+                    .translations
+                    .get(language)?
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(position, meaning)| {
+                        let kind = match_kind(&query, &words, meaning)?;
+                        Some(MeaningMatch {
+                            character,
+                            meaning,
+                            position,
+                            kind,
+                        })
+                    })
+                    .min_by_key(|m| (m.kind, m.position))
+            })
+            .collect();
+        matches.sort_by(compare);
+        matches
+    }
+}
+
+fn match_kind(query: &str, words: &[&str], meaning: &str) -> Option<MatchKind> {
+    let meaning = meaning.to_lowercase();
+    if meaning == query {
+        return Some(MatchKind::Exact);
+    }
+    let meaning_words = tokenize(&meaning);
+    if words.iter().all(|word| meaning_words.contains(word)) {
+        Some(MatchKind::Word)
+    } else if words.iter().all(|word| meaning.contains(word)) {
+        Some(MatchKind::Substring)
+    } else {
+        None
+    }
+}
+
+fn tokenize(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn compare(a: &MeaningMatch, b: &MeaningMatch) -> Ordering {
+    a.kind
+        .cmp(&b.kind)
+        .then(a.position.cmp(&b.position))
+        .then_with(|| sort::by_frequency(a.character, b.character))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MatchKind;
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+    use kanjidic_types::Language;
+
+    #[test]
+    fn ranks_exact_matches_first() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let matches = db.search_meanings("Asia", &Language::En);
+        let first = &matches[0];
+        assert_eq!(first.character.literal, '亜');
+        assert_eq!(first.kind, MatchKind::Exact);
+        assert_eq!(first.position, 0);
+    }
+
+    #[test]
+    fn matches_words() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let matches = db.search_meanings("after", &Language::En);
+        assert!(matches
+            .iter()
+            .any(|m| m.character.literal == '亜' && m.kind == MatchKind::Word));
+        assert!(!db.search_meanings("suivant", &Language::Fr).is_empty());
+    }
+}
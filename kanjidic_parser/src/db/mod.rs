@@ -0,0 +1,199 @@
+mod component;
+
+mod frequency;
+
+mod jouyou;
+
+mod kanji_set;
+pub use kanji_set::KanjiSet;
+
+mod lists;
+
+mod meaning;
+pub use meaning::{MatchKind, MeaningMatch};
+
+mod query;
+pub use query::{Query, QueryFilter};
+
+mod radical;
+
+mod reading;
+pub use reading::ReadingOptions;
+
+mod reference;
+
+mod similarity;
+pub use similarity::Similar;
+
+mod source;
+pub use source::KanjiSource;
+
+mod stroke;
+
+#[cfg(feature = "kanjivg")]
+mod stroke_data;
+
+#[cfg(feature = "unihan")]
+mod unihan;
+
+use crate::kanjidic::Kanjidic;
+use component::ComponentIndex;
+use frequency::FrequencyIndex;
+use kanjidic_types::Character;
+use reading::ReadingIndex;
+use reference::ReferenceIndex;
+use std::collections::HashMap;
+use stroke::StrokeIndex;
+
+/// The dictionary's characters, indexed for lookup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KanjidicDb {
+    characters: Vec<Character>,
+    literals: HashMap<String, usize>,
+    readings: ReadingIndex,
+    strokes: StrokeIndex,
+    frequencies: FrequencyIndex,
+    components: ComponentIndex,
+    references: ReferenceIndex,
+    wanikani_levels: HashMap<char, u8>,
+    rtk_keywords: HashMap<char, String>,
+    kanken_levels: HashMap<char, crate::kanken::KankenLevel>,
+    #[cfg(feature = "kanjivg")]
+    stroke_data: HashMap<char, crate::kanjivg::StrokeData>,
+    #[cfg(feature = "unihan")]
+    unihan: HashMap<char, crate::unihan::UnihanEntry>,
+}
+
+impl KanjidicDb {
+    /// Creates a database from the given characters.
+    pub fn new(characters: Vec<Character>) -> Self {
+        let literals = characters
+            .iter()
+            .enumerate()
+            .map(|(i, character)| (character.literal_str().into_owned(), i))
+            .collect();
+        let readings = ReadingIndex::new(&characters);
+        let strokes = StrokeIndex::new(&characters);
+        let frequencies = FrequencyIndex::new(&characters);
+        let components = ComponentIndex::new(&characters);
+        let references = ReferenceIndex::new(&characters);
+        Self {
+            characters,
+            literals,
+            readings,
+            strokes,
+            frequencies,
+            components,
+            references,
+            wanikani_levels: HashMap::new(),
+            rtk_keywords: HashMap::new(),
+            kanken_levels: HashMap::new(),
+            #[cfg(feature = "kanjivg")]
+            stroke_data: HashMap::new(),
+            #[cfg(feature = "unihan")]
+            unihan: HashMap::new(),
+        }
+    }
+
+    /// Gets the character with the given literal.
+    pub fn get(&self, literal: char) -> Option<&Character> {
+        self.get_literal(literal.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Gets the character with the given literal,
+    /// which may be more than one scalar value.
+    pub fn get_literal(&self, literal: &str) -> Option<&Character> {
+        self.index_of_literal(literal).map(|i| &self.characters[i])
+    }
+
+    /// Gets the position of the character with the given literal.
+    pub fn index_of(&self, literal: char) -> Option<usize> {
+        self.index_of_literal(literal.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Gets the position of the character with the given literal,
+    /// which may be more than one scalar value.
+    pub fn index_of_literal(&self, literal: &str) -> Option<usize> {
+        self.literals.get(literal).copied()
+    }
+
+    /// Whether the database has a character with the given literal.
+    pub fn contains(&self, literal: char) -> bool {
+        self.index_of(literal).is_some()
+    }
+
+    /// The characters in dictionary order.
+    pub fn characters(&self) -> &[Character] {
+        &self.characters
+    }
+
+    /// Iterates over the characters in dictionary order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Character> {
+        self.characters.iter()
+    }
+
+    /// The number of characters.
+    pub fn len(&self) -> usize {
+        self.characters.len()
+    }
+
+    /// Whether there are no characters.
+    pub fn is_empty(&self) -> bool {
+        self.characters.is_empty()
+    }
+
+    /// Takes back ownership of the characters.
+    pub fn into_characters(self) -> Vec<Character> {
+        self.characters
+    }
+}
+
+impl From<Vec<Character>> for KanjidicDb {
+    fn from(characters: Vec<Character>) -> Self {
+        Self::new(characters)
+    }
+}
+
+impl From<Kanjidic> for KanjidicDb {
+    fn from(kanjidic: Kanjidic) -> Self {
+        Self::new(kanjidic.characters)
+    }
+}
+
+impl<'a> IntoIterator for &'a KanjidicDb {
+    type Item = &'a Character;
+    type IntoIter = std::slice::Iter<'a, Character>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KanjidicDb;
+    use crate::test_shared::CHARACTERS;
+
+    #[test]
+    fn lookup() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        assert_eq!(db.len(), CHARACTERS.len());
+        assert_eq!(db.get('亜'), Some(&CHARACTERS[0]));
+        assert_eq!(db.index_of('亜'), Some(0));
+        assert_eq!(db.get('a'), None);
+        assert_eq!(db.get_literal("亜"), Some(&CHARACTERS[0]));
+        assert_eq!(db.get_literal("亜\u{FE00}"), None);
+        assert_eq!(db.iter().count(), CHARACTERS.len());
+    }
+
+    #[test]
+    fn variation_sequences() {
+        let mut characters = CHARACTERS[..1].to_vec();
+        let mut sequence = characters[0].clone();
+        sequence.literal_sequence = Some("亜\u{FE00}".into());
+        characters.push(sequence);
+        let db = KanjidicDb::new(characters);
+        assert_eq!(db.index_of('亜'), Some(0));
+        assert_eq!(db.index_of_literal("亜\u{FE00}"), Some(1));
+    }
+}
@@ -0,0 +1,215 @@
+use super::{component::contains_component, KanjiSet, KanjidicDb};
+use kanjidic_types::{Character, Grade};
+use serde::{Deserialize, Serialize};
+
+type Filter<'a> = Box<dyn Fn(&Character) -> bool + 'a>;
+
+/// A set of conditions that characters must all satisfy,
+/// created with [`KanjidicDb::query`].
+pub struct Query<'a> {
+    db: &'a KanjidicDb,
+    filters: Vec<Filter<'a>>,
+}
+
+/// Plain query conditions, for building a [`Query`] from outside of Rust,
+/// such as from JSON or a foreign-language binding. Unset conditions match everything.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct QueryFilter {
+    /// The grade, using the numbering from KANJIDIC2.
+    pub grade: Option<u8>,
+    /// The old JLPT level.
+    pub jlpt: Option<u8>,
+    /// The least accepted stroke count.
+    pub min_strokes: Option<u8>,
+    /// The greatest accepted stroke count.
+    pub max_strokes: Option<u8>,
+    /// A rank that the newspaper frequency ranking must be below.
+    pub frequency_below: Option<u16>,
+    /// A component that the decomposition must contain.
+    pub component: Option<char>,
+}
+
+impl KanjidicDb {
+    /// Starts a query over all the characters.
+    pub fn query(&self) -> Query<'_> {
+        Query {
+            db: self,
+            filters: vec![],
+        }
+    }
+
+    /// Starts a query with the given conditions.
+    /// A grade number that KANJIDIC2 does not use matches nothing.
+    pub fn query_with(&self, filter: &QueryFilter) -> Query<'_> {
+        let mut query = self.query();
+        if let Some(number) = filter.grade {
+            query = match Grade::from_raw(number) {
+                Some(grade) => query.grade(grade),
+                None => query.filter(|_| false),
+            };
+        }
+        if let Some(level) = filter.jlpt {
+            query = query.jlpt(level);
+        }
+        if let Some(strokes) = filter.min_strokes {
+            query = query.min_strokes(strokes);
+        }
+        if let Some(strokes) = filter.max_strokes {
+            query = query.max_strokes(strokes);
+        }
+        if let Some(rank) = filter.frequency_below {
+            query = query.frequency_below(rank);
+        }
+        if let Some(component) = filter.component {
+            query = query.component(component);
+        }
+        query
+    }
+}
+
+impl<'a> Query<'a> {
+    /// Keeps characters that satisfy the given condition.
+    pub fn filter<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Character) -> bool + 'a,
+    {
+        self.filters.push(Box::new(f));
+        self
+    }
+
+    /// Keeps characters in the given grade.
+    pub fn grade(self, grade: Grade) -> Self {
+        self.filter(move |character| character.grade == Some(grade))
+    }
+
+    /// Keeps characters in the given old JLPT level.
+    pub fn jlpt(self, level: u8) -> Self {
+        self.filter(move |character| character.jlpt == Some(level))
+    }
+
+    /// Keeps characters with an old JLPT level of at most the given level.
+    pub fn jlpt_at_most(self, level: u8) -> Self {
+        self.filter(move |character| matches!(character.jlpt, Some(jlpt) if jlpt <= level))
+    }
+
+    /// Keeps characters with an old JLPT level of at least the given level.
+    pub fn jlpt_at_least(self, level: u8) -> Self {
+        self.filter(move |character| matches!(character.jlpt, Some(jlpt) if jlpt >= level))
+    }
+
+    /// Keeps characters ranked more frequent than the given rank.
+    pub fn frequency_below(self, rank: u16) -> Self {
+        self.filter(move |character| matches!(character.frequency, Some(f) if f < rank))
+    }
+
+    /// Keeps characters with a frequency ranking.
+    pub fn has_frequency(self) -> Self {
+        self.filter(|character| character.frequency.is_some())
+    }
+
+    /// Keeps characters with the given accepted stroke count.
+    pub fn strokes(self, strokes: u8) -> Self {
+        self.filter(move |character| character.stroke_counts.accepted == strokes)
+    }
+
+    /// Keeps characters with at least the given accepted stroke count.
+    pub fn min_strokes(self, strokes: u8) -> Self {
+        self.filter(move |character| character.stroke_counts.accepted >= strokes)
+    }
+
+    /// Keeps characters with at most the given accepted stroke count.
+    pub fn max_strokes(self, strokes: u8) -> Self {
+        self.filter(move |character| character.stroke_counts.accepted <= strokes)
+    }
+
+    /// Keeps characters whose decomposition contains the given component
+    /// or one of its variant forms.
+    pub fn component(self, component: char) -> Self {
+        self.filter(move |character| contains_component(character, component))
+    }
+
+    /// Iterates over the matching characters in dictionary order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a Character> + '_ {
+        self.db
+            .characters
+            .iter()
+            .filter(move |character| self.matches(character))
+    }
+
+    /// Collects the positions of the matching characters into a set.
+    pub fn to_set(&self) -> KanjiSet {
+        self.db
+            .characters
+            .iter()
+            .enumerate()
+            .filter(|(_, character)| self.matches(character))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Collects the matching characters in dictionary order.
+    pub fn collect(&self) -> Vec<&'a Character> {
+        self.iter().collect()
+    }
+
+    /// Counts the matching characters.
+    pub fn count(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn matches(&self, character: &Character) -> bool {
+        self.filters.iter().all(|filter| filter(character))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::{KanjidicDb, QueryFilter},
+        test_shared::{decomposed, CHARACTERS},
+    };
+    use kanjidic_types::Grade;
+
+    #[test]
+    fn combined_filters() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let found = db
+            .query()
+            .grade(Grade::Jouyou)
+            .jlpt_at_most(2)
+            .frequency_below(2000)
+            .min_strokes(5)
+            .collect();
+        assert!(found.iter().any(|character| character.literal == '亜'));
+        assert!(found.iter().all(|character| {
+            character.grade == Some(Grade::Jouyou)
+                && character.jlpt.unwrap() <= 2
+                && character.frequency.unwrap() < 2000
+                && character.stroke_counts.accepted >= 5
+        }));
+    }
+
+    #[test]
+    fn filter() {
+        let db = KanjidicDb::new(decomposed());
+        let filter: QueryFilter =
+            serde_json::from_str(r#"{"grade": 8, "jlpt": 1, "maxStrokes": 7, "component": "口"}"#)
+                .unwrap();
+        let found = db.query_with(&filter).collect();
+        assert!(found.iter().any(|character| character.literal == '亜'));
+        assert_eq!(db.query_with(&QueryFilter::default()).count(), db.len());
+        let unused = QueryFilter {
+            grade: Some(7),
+            ..QueryFilter::default()
+        };
+        assert_eq!(db.query_with(&unused).count(), 0);
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        assert_eq!(db.query().count(), db.len());
+        assert_eq!(db.query().strokes(7).max_strokes(6).count(), 0);
+    }
+}
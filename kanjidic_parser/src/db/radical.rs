@@ -0,0 +1,45 @@
+use super::KanjidicDb;
+use kanjidic_types::{radical::RadicalDiscrepancy, Character};
+
+impl KanjidicDb {
+    /// Finds the characters whose Nelson radical differs from the classical one,
+    /// in dictionary order.
+    pub fn radical_discrepancies(&self) -> Vec<(&Character, RadicalDiscrepancy)> {
+        self.characters
+            .iter()
+            .filter_map(|character| {
+                character
+                    .radical_discrepancy()
+                    .map(|discrepancy| (character, discrepancy))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+    use kanjidic_types::{radical::RadicalDiscrepancy, KangXi};
+
+    #[test]
+    fn discrepancies() {
+        assert_eq!(
+            CHARACTERS[0].radical_discrepancy(),
+            Some(RadicalDiscrepancy {
+                classical: KangXi::Two,
+                nelson: KangXi::One,
+            })
+        );
+        assert_eq!(CHARACTERS[1].radical_discrepancy(), None);
+
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let discrepancies = db.radical_discrepancies();
+        assert_eq!(discrepancies[0].0.literal, '亜');
+        assert!(discrepancies
+            .iter()
+            .all(|(character, _)| character.radical_discrepancy().is_some()));
+        assert!(!discrepancies
+            .iter()
+            .any(|(character, _)| character.literal == '唖'));
+    }
+}
@@ -0,0 +1,158 @@
+use super::KanjidicDb;
+use kanjidic_types::{kana, Character, Reading};
+use std::collections::HashMap;
+
+/// How readings are compared in [`KanjidicDb::by_reading_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReadingOptions {
+    /// Also match kunyomi against their stem without the okurigana.
+    pub ignore_okurigana: bool,
+    /// Treat hiragana and katakana as equivalent,
+    /// and a long vowel mark as the kana it stands for.
+    pub fold_kana: bool,
+}
+
+/// Maps Japanese readings to the characters that have them.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct ReadingIndex {
+    /// Keyed by the reading as folded by [`kana::fold`].
+    entries: HashMap<String, Vec<Entry>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    character: usize,
+    /// The reading as written in the dictionary.
+    text: String,
+    /// Whether this is a kunyomi stem with the okurigana removed.
+    stem: bool,
+}
+
+impl ReadingIndex {
+    pub(super) fn new(characters: &[Character]) -> Self {
+        let mut index = Self::default();
+        for (i, character) in characters.iter().enumerate() {
+            for reading in character.readings.iter() {
+                match reading {
+                    Reading::Onyomi(onyomi) => index.insert(i, onyomi.clone(), false),
+                    Reading::Kunyomi(kunyomi) => match &kunyomi.okurigana {
+                        Some(okurigana) => {
+                            let full = format!("{}{}", kunyomi.reading, okurigana);
+                            index.insert(i, full, false);
+                            index.insert(i, kunyomi.reading.clone(), true);
+                        }
+                        None => index.insert(i, kunyomi.reading.clone(), false),
+                    },
+                    _ => {}
+                }
+            }
+        }
+        index
+    }
+
+    fn insert(&mut self, character: usize, text: String, stem: bool) {
+        self.entries
+            .entry(kana::fold(&text))
+            .or_default()
+            .push(Entry {
+                character,
+                text,
+                stem,
+            });
+    }
+
+    fn find(&self, reading: &str, options: ReadingOptions) -> Vec<usize> {
+        let reading: String = reading.chars().filter(|c| *c != '.' && *c != '-').collect();
+        let mut out: Vec<usize> = vec![];
+        let entries = match self.entries.get(&kana::fold(&reading)) {
+            Some(entries) => entries,
+            None => return out,
+        };
+        for entry in entries {
+            let okurigana_matches = options.ignore_okurigana || !entry.stem;
+            let kana_matches = options.fold_kana || entry.text == reading;
+            if okurigana_matches && kana_matches && !out.contains(&entry.character) {
+                out.push(entry.character);
+            }
+        }
+        out
+    }
+}
+
+impl KanjidicDb {
+    /// Finds the characters with the given onyomi or kunyomi, matched exactly.
+    /// Romaji such as `kou` is converted to kana and matches both hiragana and katakana.
+    pub fn by_reading(&self, reading: &str) -> Vec<&Character> {
+        self.by_reading_with(reading, ReadingOptions::default())
+    }
+
+    /// Finds the characters with the given onyomi or kunyomi.
+    /// Okurigana dots and prefix or suffix dashes in the query are ignored.
+    /// Romaji is converted with [`kana::romaji_to_hiragana`], matching any of its spellings,
+    /// and always has its kana folded,
+    /// since it does not say whether it stands for hiragana or katakana.
+    pub fn by_reading_with(&self, reading: &str, options: ReadingOptions) -> Vec<&Character> {
+        let (readings, options) = if kana::is_romaji(reading) {
+            let options = ReadingOptions {
+                fold_kana: true,
+                ..options
+            };
+            (kana::romaji_to_hiragana(reading), options)
+        } else {
+            (vec![reading.to_owned()], options)
+        };
+        let mut found: Vec<usize> = vec![];
+        for reading in readings {
+            for i in self.readings.find(&reading, options) {
+                if !found.contains(&i) {
+                    found.push(i);
+                }
+            }
+        }
+        found.into_iter().map(|i| &self.characters[i]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReadingOptions;
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+
+    fn literals(db: &KanjidicDb, reading: &str, options: ReadingOptions) -> Vec<char> {
+        db.by_reading_with(reading, options)
+            .into_iter()
+            .map(|character| character.literal)
+            .collect()
+    }
+
+    #[test]
+    fn exact_reading() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        assert!(literals(&db, "ア", ReadingOptions::default()).contains(&'亜'));
+        assert!(literals(&db, "つ.ぐ", ReadingOptions::default()).contains(&'亜'));
+        assert!(!literals(&db, "あ", ReadingOptions::default()).contains(&'亜'));
+        assert!(!literals(&db, "つ", ReadingOptions::default()).contains(&'亜'));
+    }
+
+    #[test]
+    fn folded_reading() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let options = ReadingOptions {
+            ignore_okurigana: true,
+            fold_kana: true,
+        };
+        assert!(literals(&db, "あ", options).contains(&'亜'));
+        assert!(literals(&db, "ツ", options).contains(&'亜'));
+        assert!(literals(&db, "ツ.グ", options).contains(&'亜'));
+    }
+
+    #[test]
+    fn romaji_reading() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        assert!(literals(&db, "a", ReadingOptions::default()).contains(&'亜'));
+        assert!(literals(&db, "tsu.gu", ReadingOptions::default()).contains(&'亜'));
+        assert!(!literals(&db, "tsu", ReadingOptions::default()).contains(&'亜'));
+        assert!(literals(&db, "ōu", ReadingOptions::default()).contains(&'奄'));
+        assert!(db.by_reading("xq").is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use super::KanjidicDb;
+use kanjidic_types::{Character, Reference};
+use std::collections::HashMap;
+
+/// Maps each dictionary reference to the characters listed under it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct ReferenceIndex {
+    references: HashMap<Reference, Vec<usize>>,
+}
+
+impl ReferenceIndex {
+    pub(super) fn new(characters: &[Character]) -> Self {
+        let mut references: HashMap<Reference, Vec<usize>> = HashMap::new();
+        for (i, character) in characters.iter().enumerate() {
+            for &reference in character.references.iter() {
+                let entry = references.entry(reference).or_default();
+                if entry.last() != Some(&i) {
+                    entry.push(i);
+                }
+            }
+        }
+        Self { references }
+    }
+}
+
+impl KanjidicDb {
+    /// Finds the characters listed under the given dictionary reference,
+    /// such as `Reference::Heisig(1809)`.
+    /// Structured references like Morohashi entries must match in full.
+    pub fn by_reference(&self, reference: Reference) -> Vec<&Character> {
+        self.references
+            .references
+            .get(&reference)
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.characters[i])
+            .collect()
+    }
+
+    /// Maps each reference in the dictionary to the positions of the characters listed under it,
+    /// in dictionary order. The index is built with the database,
+    /// so many references can be looked up without scanning the characters each time.
+    /// Morohashi and O'Neill references keep their suffixes, so `1234P` and `1234` differ.
+    pub fn reference_index(&self) -> &HashMap<Reference, Vec<usize>> {
+        &self.references.references
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+    use kanjidic_types::{moro::MoroSuffix, Moro, Reference};
+
+    #[test]
+    fn heisig() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let found = db.by_reference(Reference::Heisig(1809));
+        assert_eq!(found, vec![&CHARACTERS[0]]);
+        assert!(db.by_reference(Reference::Heisig(0)).is_empty());
+    }
+
+    #[test]
+    fn index() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let index = db.reference_index();
+        assert_eq!(index.get(&Reference::Heisig(1809)), Some(&vec![0]));
+        assert_eq!(index.get(&Reference::NelsonClassic(43)), Some(&vec![0]));
+        assert_eq!(index.get(&Reference::Heisig(0)), None);
+    }
+
+    #[test]
+    fn suffixes() {
+        let moro = |suffix| {
+            Reference::Moro(Moro {
+                volume: None,
+                page: None,
+                index: 1,
+                suffix,
+            })
+        };
+        let mut characters = CHARACTERS[..2].to_vec();
+        characters[0].references = vec![moro(MoroSuffix::None)];
+        characters[1].references = vec![moro(MoroSuffix::P)];
+        let db = KanjidicDb::new(characters);
+        let index = db.reference_index();
+        assert_eq!(index.get(&moro(MoroSuffix::None)), Some(&vec![0]));
+        assert_eq!(index.get(&moro(MoroSuffix::P)), Some(&vec![1]));
+        assert_eq!(index.get(&moro(MoroSuffix::X)), None);
+    }
+}
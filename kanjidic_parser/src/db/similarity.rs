@@ -0,0 +1,78 @@
+use super::KanjidicDb;
+use kanjidic_types::Character;
+use std::collections::BTreeSet;
+
+/// How much shared components count toward similarity, with stroke counts making up the rest.
+const COMPONENT_WEIGHT: f64 = 0.8;
+
+/// A character that looks like another one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Similar<'a> {
+    pub character: &'a Character,
+    /// From 0 for nothing in common to 1 for the same components and stroke count.
+    pub score: f64,
+}
+
+impl KanjidicDb {
+    /// Finds the characters most easily confused with the given one,
+    /// ranked by how many components they share and how close their stroke counts are.
+    /// Only characters sharing at least one component are considered.
+    pub fn similar_to(&self, literal: char, limit: usize) -> Vec<Similar<'_>> {
+        let target = match self.get(literal) {
+            Some(target) => target,
+            None => return vec![],
+        };
+        let candidates: BTreeSet<usize> = target
+            .decomposition
+            .iter()
+            .flat_map(|&component| self.components.containing(component).iter().copied())
+            .collect();
+        let mut similar: Vec<_> = candidates
+            .into_iter()
+            .map(|i| &self.characters[i])
+            .filter(|character| character.literal != literal)
+            .map(|character| Similar {
+                character,
+                score: score(target, character),
+            })
+            .collect();
+        similar.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        similar.truncate(limit);
+        similar
+    }
+}
+
+fn score(a: &Character, b: &Character) -> f64 {
+    let a_components: BTreeSet<char> = a.decomposition.iter().copied().collect();
+    let b_components: BTreeSet<char> = b.decomposition.iter().copied().collect();
+    let shared = a_components.intersection(&b_components).count();
+    let total = a_components.union(&b_components).count();
+    let components = shared as f64 / total as f64;
+    let a_strokes = a.stroke_counts.accepted as f64;
+    let b_strokes = b.stroke_counts.accepted as f64;
+    let strokes = 1.0 - (a_strokes - b_strokes).abs() / a_strokes.max(b_strokes).max(1.0);
+    COMPONENT_WEIGHT * components + (1.0 - COMPONENT_WEIGHT) * strokes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{db::KanjidicDb, test_shared::decomposed};
+
+    #[test]
+    fn ranked() {
+        let db = KanjidicDb::new(decomposed());
+        let similar = db.similar_to('亜', 5);
+        assert!(!similar.is_empty());
+        assert!(similar.len() <= 5);
+        assert!(similar
+            .iter()
+            .all(|similar| similar.character.literal != '亜'));
+        assert!(similar
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+        assert!(similar
+            .iter()
+            .all(|similar| similar.score > 0.0 && similar.score <= 1.0));
+        assert!(db.similar_to('a', 5).is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+use super::{KanjidicDb, ReadingOptions};
+use kanjidic_types::{Character, Language};
+
+/// A collection of kanji that other dictionaries can look characters up in,
+/// such as a JMdict wrapper annotating the kanji in its headwords.
+pub trait KanjiSource {
+    /// Gets the character with the given literal.
+    fn kanji(&self, literal: char) -> Option<&Character>;
+
+    /// Finds the characters with the given onyomi or kunyomi,
+    /// treating hiragana and katakana as equivalent and ignoring okurigana.
+    fn kanji_by_reading(&self, reading: &str) -> Vec<&Character>;
+
+    /// Finds the characters with the given meaning in the given language, best matches first.
+    fn kanji_by_meaning(&self, meaning: &str, language: &Language) -> Vec<&Character>;
+
+    /// The characters of a word that are in the collection, in the order they appear.
+    /// Kana and other characters the collection lacks are skipped.
+    fn kanji_in(&self, word: &str) -> Vec<&Character> {
+        word.chars().filter_map(|c| self.kanji(c)).collect()
+    }
+}
+
+impl KanjiSource for KanjidicDb {
+    fn kanji(&self, literal: char) -> Option<&Character> {
+        self.get(literal)
+    }
+
+    fn kanji_by_reading(&self, reading: &str) -> Vec<&Character> {
+        let options = ReadingOptions {
+            ignore_okurigana: true,
+            fold_kana: true,
+        };
+        self.by_reading_with(reading, options)
+    }
+
+    fn kanji_by_meaning(&self, meaning: &str, language: &Language) -> Vec<&Character> {
+        self.search_meanings(meaning, language)
+            .into_iter()
+            .map(|found| found.character)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KanjiSource;
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+    use kanjidic_types::Language;
+
+    fn literals<'a>(
+        characters: impl IntoIterator<Item = &'a kanjidic_types::Character>,
+    ) -> Vec<char> {
+        characters
+            .into_iter()
+            .map(|character| character.literal)
+            .collect()
+    }
+
+    #[test]
+    fn lookup() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let source: &dyn KanjiSource = &db;
+        assert_eq!(source.kanji('亜').map(|c| c.literal), Some('亜'));
+        assert!(literals(source.kanji_by_reading("あ")).contains(&'亜'));
+        assert_eq!(
+            literals(source.kanji_by_meaning("Asia", &Language::En))[0],
+            '亜'
+        );
+        assert_eq!(literals(source.kanji_in("亜いA")), vec!['亜']);
+    }
+}
@@ -0,0 +1,94 @@
+use super::KanjidicDb;
+use kanjidic_types::Character;
+use std::collections::BTreeMap;
+
+/// Maps stroke counts, including common miscounts, to the characters that have them.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct StrokeIndex {
+    counts: BTreeMap<u8, Vec<usize>>,
+}
+
+impl StrokeIndex {
+    pub(super) fn new(characters: &[Character]) -> Self {
+        let mut counts: BTreeMap<u8, Vec<usize>> = BTreeMap::new();
+        for (i, character) in characters.iter().enumerate() {
+            for count in character.stroke_counts.all_counts() {
+                let entry = counts.entry(count).or_default();
+                if entry.last() != Some(&i) {
+                    entry.push(i);
+                }
+            }
+        }
+        Self { counts }
+    }
+
+    fn find(&self, min: u8, max: u8) -> Vec<usize> {
+        let mut out: Vec<usize> = self
+            .counts
+            .range(min..=max)
+            .flat_map(|(_, characters)| characters.iter().copied())
+            .collect();
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+}
+
+impl KanjidicDb {
+    /// Finds the characters with the given stroke count,
+    /// either as the accepted count or as a common miscount.
+    pub fn by_stroke_count(&self, strokes: u8) -> Vec<&Character> {
+        self.by_stroke_count_fuzzy(strokes, 0)
+    }
+
+    /// Finds the characters with a stroke count within the tolerance of the given count,
+    /// either as the accepted count or as a common miscount.
+    pub fn by_stroke_count_fuzzy(&self, strokes: u8, tolerance: u8) -> Vec<&Character> {
+        self.strokes
+            .find(
+                strokes.saturating_sub(tolerance),
+                strokes.saturating_add(tolerance),
+            )
+            .into_iter()
+            .map(|i| &self.characters[i])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+
+    #[test]
+    fn exact_count() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let found = db.by_stroke_count(7);
+        assert!(found.iter().any(|character| character.literal == '亜'));
+        assert!(!db
+            .by_stroke_count(8)
+            .iter()
+            .any(|character| character.literal == '亜'));
+    }
+
+    #[test]
+    fn with_tolerance() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let found = db.by_stroke_count_fuzzy(8, 1);
+        assert!(found.iter().any(|character| character.literal == '亜'));
+        assert!(found.len() > db.by_stroke_count(8).len());
+    }
+
+    #[test]
+    fn includes_miscounts() {
+        let db = KanjidicDb::new(CHARACTERS.clone());
+        let has_stroke_count = |count| {
+            db.by_stroke_count(count)
+                .iter()
+                .any(|character| character.literal == '逢')
+        };
+        assert!(has_stroke_count(10));
+        assert!(has_stroke_count(9));
+        assert!(has_stroke_count(11));
+        assert!(!has_stroke_count(12));
+    }
+}
@@ -0,0 +1,32 @@
+use super::KanjidicDb;
+use crate::kanjivg::StrokeData;
+use std::collections::HashMap;
+
+impl KanjidicDb {
+    /// Attaches KanjiVG stroke data, such as that read by [`crate::kanjivg::from_xml`],
+    /// replacing any earlier data for the same characters.
+    pub fn attach_stroke_data(&mut self, data: HashMap<char, StrokeData>) {
+        self.stroke_data.extend(data);
+    }
+
+    /// The stroke order and component groups of the character, if attached.
+    pub fn stroke_data(&self, literal: char) -> Option<&StrokeData> {
+        self.stroke_data.get(&literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{db::KanjidicDb, kanjivg, test_shared::CHARACTERS};
+
+    #[test]
+    fn attached() {
+        let mut db = KanjidicDb::new(CHARACTERS.clone());
+        assert_eq!(db.stroke_data('亜'), None);
+        let xml = r#"<kanjivg xmlns:kvg="http://kanjivg.tagaini.net">
+            <g id="kvg:04e9c" kvg:element="亜"><path d="M18,21h73"/></g>
+            </kanjivg>"#;
+        db.attach_stroke_data(kanjivg::from_xml(xml).unwrap());
+        assert_eq!(db.stroke_data('亜').unwrap().strokes.len(), 1);
+    }
+}
@@ -0,0 +1,57 @@
+use super::KanjidicDb;
+use crate::unihan::{self, UnihanEntry};
+use std::{io, path::Path};
+
+impl KanjidicDb {
+    /// Loads Unihan data from a text file or a directory of them,
+    /// filling in English meanings for characters that have none
+    /// and keeping the rest of the Unihan fields for [`KanjidicDb::unihan`].
+    pub fn enrich_with_unihan<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let entries = unihan::load(path)?;
+        unihan::apply(&entries, &mut self.characters);
+        self.unihan.extend(entries);
+        Ok(())
+    }
+
+    /// The Unihan definitions, stroke counts, and readings of the character, if loaded.
+    pub fn unihan(&self, literal: char) -> Option<&UnihanEntry> {
+        self.unihan.get(&literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::KanjidicDb,
+        test_shared::{temp_dir, CHARACTERS},
+    };
+    use kanjidic_types::Language;
+    use std::fs;
+
+    #[test]
+    fn enriched() {
+        let mut characters = CHARACTERS.clone();
+        characters[0].translations.clear();
+        let mut db = KanjidicDb::new(characters);
+        let dir = temp_dir("unihan_enriched");
+        let path = dir.join("Unihan.txt");
+        fs::write(
+            &path,
+            "U+4E9C\tkDefinition\tAsia; second\nU+4E9C\tkTotalStrokes\t7\n",
+        )
+        .unwrap();
+        db.enrich_with_unihan(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            db.get('亜').unwrap().translations["en"],
+            vec!["Asia", "second"]
+        );
+        assert_eq!(db.unihan('亜').unwrap().total_strokes, vec![7]);
+        assert_eq!(
+            db.search_meanings("asia", &Language::En)[0]
+                .character
+                .literal,
+            '亜'
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{text, SharedError},
 };
@@ -14,6 +15,16 @@ pub enum Error {
     Str(PosError, de_roo::ParseError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Str(_, _) => ErrorCode::E0123_INVALID_DE_ROO,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<DeRoo, Error> {
     DeRoo::try_from(text(&node)?).map_err(|err| Error::Str(PosError::from(&node), err))
 }
@@ -26,6 +37,7 @@ mod tests {
         de_roo::{ExtremeBottom, ExtremeTop},
         DeRoo,
     };
+    use std::convert::TryFrom;
 
     #[test]
     fn de_roo() {
@@ -48,4 +60,23 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn code() {
+        let de_roo = DeRoo {
+            top: ExtremeTop::Bald,
+            bottom: ExtremeBottom::StandingBottom,
+        };
+        assert_eq!(de_roo.code(), 3273);
+        assert_eq!(de_roo.to_string(), "3273");
+        assert_eq!(DeRoo::try_from(3273), Ok(de_roo));
+        let dot = DeRoo {
+            top: ExtremeTop::Dot,
+            bottom: ExtremeBottom::FourDots,
+        };
+        assert_eq!(dot.to_string(), "340");
+        assert_eq!(DeRoo::try_from(dot.code()), Ok(dot));
+        assert!(DeRoo::try_from(3281).is_err());
+        assert!(DeRoo::try_from(240).is_err());
+    }
 }
@@ -0,0 +1,99 @@
+use std::{collections::HashMap, fmt};
+
+/// A source of the components that make up each character.
+pub trait DecompositionProvider {
+    /// The components of the character, if it is known.
+    fn components(&self, literal: char) -> Option<&[char]>;
+}
+
+impl fmt::Debug for dyn DecompositionProvider + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DecompositionProvider")
+    }
+}
+
+/// The EDRDG radical decompositions embedded by `kradical_static`.
+#[cfg(feature = "decomposition")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Kradical;
+
+#[cfg(feature = "decomposition")]
+impl DecompositionProvider for Kradical {
+    fn components(&self, literal: char) -> Option<&[char]> {
+        kradical_static::DECOMPOSITIONS
+            .iter()
+            .find(|decomposition| decomposition.kanji == literal)
+            .map(|decomposition| decomposition.radicals)
+    }
+}
+
+impl DecompositionProvider for HashMap<char, Vec<char>> {
+    fn components(&self, literal: char) -> Option<&[char]> {
+        self.get(&literal).map(Vec::as_slice)
+    }
+}
+
+/// The unified ideographs for the Kangxi Radicals block, U+2F00 to U+2FD5.
+const KANGXI_RADICALS: &str = "一丨丶丿乙亅二亠人儿入八冂冖冫几凵刀力勹匕匚匸十卜卩厂厶又口囗土士夂夊夕大女子宀寸小尢尸屮山巛工己巾干幺广廴廾弋弓彐彡彳心戈戶手支攴文斗斤方无日曰月木欠止歹殳毋比毛氏气水火爪父爻爿片牙牛犬玄玉瓜瓦甘生用田疋疒癶白皮皿目矛矢石示禸禾穴立竹米糸缶网羊羽老而耒耳聿肉臣自至臼舌舛舟艮色艸虍虫血行衣襾見角言谷豆豕豸貝赤走足身車辛辰辵邑酉釆里金長門阜隶隹雨靑非面革韋韭音頁風飛食首香馬骨高髟鬥鬯鬲鬼魚鳥鹵鹿麥麻黃黍黑黹黽鼎鼓鼠鼻齊齒龍龜龠";
+
+/// Maps the variant forms of a component, such as 氵 or ⺌,
+/// to the standalone character it derives from, such as 水 or 小.
+/// Characters from the Kangxi Radicals block map to the matching ideograph.
+/// Any other character is returned unchanged.
+pub fn normalize_component(component: char) -> char {
+    match component {
+        '亻' | '⺅' | '𠆢' => '人',
+        '氵' | '⺡' | '氺' | '⺢' => '水',
+        '扌' | '⺘' => '手',
+        '忄' | '⺖' | '⺗' => '心',
+        '犭' | '⺨' => '犬',
+        '礻' | '⺭' => '示',
+        '衤' | '⻂' => '衣',
+        '刂' | '⺉' => '刀',
+        '灬' | '⺣' => '火',
+        '⺌' | '⺍' => '小',
+        '艹' | '⺾' | '⺿' | '⻀' => '艸',
+        '辶' | '⻌' | '⻍' | '⻎' => '辵',
+        '罒' | '⺲' => '网',
+        '⺶' => '羊',
+        '⺮' => '竹',
+        '飠' | '⻞' => '食',
+        '糹' | '⺯' => '糸',
+        '訁' => '言',
+        '釒' => '金',
+        '耂' | '⺹' => '老',
+        '牜' | '⺧' => '牛',
+        '⺩' => '玉',
+        '爫' | '⺤' => '爪',
+        '⻖' => '阜',
+        '⻏' => '邑',
+        '\u{2F00}'..='\u{2FD5}' => KANGXI_RADICALS
+            .chars()
+            .nth(component as usize - 0x2F00)
+            .unwrap_or(component),
+        component => component,
+    }
+}
+
+/// Looks up the components with the provider, if there is one.
+pub(crate) fn components(provider: Option<&dyn DecompositionProvider>, literal: char) -> Vec<char> {
+    provider
+        .and_then(|provider| provider.components(literal))
+        .map(<[char]>::to_vec)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_component;
+
+    #[test]
+    fn normalized() {
+        assert_eq!(normalize_component('氵'), '水');
+        assert_eq!(normalize_component('亻'), '人');
+        assert_eq!(normalize_component('⺌'), '小');
+        assert_eq!(normalize_component('\u{2F08}'), '人');
+        assert_eq!(normalize_component('\u{2FD5}'), '龠');
+        assert_eq!(normalize_component('口'), '口');
+    }
+}
@@ -0,0 +1,117 @@
+use crate::kanjidic::Error;
+use miette::{Diagnostic, NamedSource, SourceSpan};
+use roxmltree::TextPos;
+
+/// A parse error with the source text it refers to, for rendering with miette.
+#[derive(Debug, thiserror::Error, Diagnostic)]
+#[error("{message}")]
+#[diagnostic(code(kanjidic::parse))]
+pub struct ParseDiagnostic {
+    message: String,
+    #[source_code]
+    source_code: NamedSource,
+    #[label("{label}")]
+    span: Option<SourceSpan>,
+    label: String,
+    #[help]
+    help: Option<String>,
+}
+
+impl ParseDiagnostic {
+    /// Pairs an error from [`Kanjidic::from_file_contents`](crate::kanjidic::Kanjidic::from_file_contents)
+    /// with the file contents that were parsed. The name is shown alongside the source, usually a path.
+    pub fn new(error: &Error, name: &str, file_contents: &str) -> Self {
+        // Parsing skips everything before the root element, so positions are relative to it.
+        let start = file_contents.find("<kanjidic2>").unwrap_or(0);
+        let parsed = &file_contents[start..];
+        let (message, span, label, help) = match error {
+            Error::Character(position, literal, err) => (
+                format!("Could not parse the character {}", literal),
+                Some((start + position.offset(), "<character>".len()).into()),
+                err.to_string(),
+                None,
+            ),
+            Error::Xml(err) => (
+                "The file is not well-formed XML".to_owned(),
+                offset_of(parsed, err.pos()).map(|offset| (start + offset, 0).into()),
+                err.to_string(),
+                None,
+            ),
+            Error::Header(err) => (
+                "Could not parse the header".to_owned(),
+                Some((start + err.position().offset(), 0).into()),
+                err.to_string(),
+                None,
+            ),
+            Error::Cancelled => (error.to_string(), None, String::new(), None),
+            Error::MissingHeader | Error::MissingRoot => (
+                error.to_string(),
+                None,
+                String::new(),
+                Some("Check that the file is a KANJIDIC2 release".to_owned()),
+            ),
+        };
+        Self {
+            message,
+            source_code: NamedSource::new(name, file_contents.to_owned()),
+            span,
+            label,
+            help,
+        }
+    }
+}
+
+/// Converts a one-based row and column into a byte offset.
+fn offset_of(text: &str, position: TextPos) -> Option<usize> {
+    let mut offset = 0;
+    for (row, line) in text.split_inclusive('\n').enumerate() {
+        if row + 1 == position.row as usize {
+            let column: usize = line
+                .chars()
+                .take(position.col.saturating_sub(1) as usize)
+                .map(char::len_utf8)
+                .sum();
+            return Some(offset + column);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseDiagnostic;
+    use crate::kanjidic::Kanjidic;
+    use miette::{Diagnostic, SourceSpan};
+
+    #[test]
+    fn labels_character() {
+        let xml = "<!DOCTYPE kanjidic2>\n<kanjidic2>\n\
+            <header><file_version>4</file_version>\
+            <database_version>2021-176</database_version>\
+            <date_of_creation>2021-06-25</date_of_creation></header>\n\
+            <character><literal>亜</literal><misc><grade>7</grade></misc></character>\n\
+            </kanjidic2>";
+        let error = Kanjidic::from_file_contents(xml).unwrap_err();
+        let diagnostic = ParseDiagnostic::new(&error, "kanjidic2.xml", xml);
+        let labels: Vec<_> = diagnostic.labels().unwrap().collect();
+        let start = xml.find("<character>").unwrap();
+        assert_eq!(labels[0].inner(), &SourceSpan::from((start, 11)));
+        assert_eq!(diagnostic.to_string(), "Could not parse the character 亜");
+    }
+
+    #[test]
+    fn labels_header() {
+        let xml = "<!DOCTYPE kanjidic2>\n<kanjidic2>\n\
+            <header><file_version>4</file_version>\
+            <database_version>2021-176</database_version>\
+            <date_of_creation>June</date_of_creation></header>\n\
+            </kanjidic2>";
+        let error = Kanjidic::from_file_contents(xml).unwrap_err();
+        let diagnostic = ParseDiagnostic::new(&error, "kanjidic2.xml", xml);
+        let labels: Vec<_> = diagnostic.labels().unwrap().collect();
+        let start = xml.find("<date_of_creation>").unwrap();
+        assert_eq!(labels[0].inner(), &SourceSpan::from((start, 0)));
+        assert_eq!(diagnostic.to_string(), "Could not parse the header");
+    }
+}
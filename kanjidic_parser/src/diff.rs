@@ -0,0 +1,254 @@
+use crate::kanjidic::Kanjidic;
+use kanjidic_types::{
+    Character, Codepoint, Grade, Language, QueryCode, Radical, Reading, Reference, StrokeCount,
+    Variant,
+};
+use serde::Serialize;
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashMap},
+};
+
+/// The differences between two releases of the dictionary.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct KanjidicDiff<'a> {
+    /// Characters only in the new release, in dictionary order.
+    pub added: Vec<&'a Character>,
+    /// Characters only in the old release, in dictionary order.
+    pub removed: Vec<&'a Character>,
+    /// Characters in both releases whose entries differ.
+    pub changed: Vec<CharacterDiff<'a>>,
+}
+
+impl<'a> KanjidicDiff<'a> {
+    /// Whether the releases contain the same characters.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The changes to a single character between releases.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CharacterDiff<'a> {
+    /// The character itself, including any scalar values after the first.
+    pub literal: Cow<'a, str>,
+    /// Each field that changed.
+    pub changes: Vec<Change<'a>>,
+}
+
+/// A field of a character that changed between releases.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "tag", content = "content")]
+pub enum Change<'a> {
+    Codepoints(ListChange<'a, Codepoint>),
+    Radicals(ListChange<'a, Radical>),
+    Grade(ValueChange<Option<Grade>>),
+    StrokeCounts(ValueChange<&'a StrokeCount>),
+    Variants(ListChange<'a, Variant>),
+    Frequency(ValueChange<Option<u16>>),
+    RadicalNames(ListChange<'a, String>),
+    Jlpt(ValueChange<Option<u8>>),
+    References(ListChange<'a, Reference>),
+    QueryCodes(ListChange<'a, QueryCode>),
+    Readings(ListChange<'a, Reading>),
+    /// Meanings in the given language.
+    Meanings {
+        language: &'a str,
+        #[serde(flatten)]
+        change: ListChange<'a, String>,
+    },
+    Nanori(ListChange<'a, String>),
+    Decomposition(ListChange<'a, char>),
+    Ids(ValueChange<Option<&'a str>>),
+}
+
+/// A single value that changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ValueChange<T> {
+    pub old: T,
+    pub new: T,
+}
+
+/// Items that were added to or removed from a list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListChange<'a, T> {
+    pub added: Vec<&'a T>,
+    pub removed: Vec<&'a T>,
+}
+
+/// Compares two releases of the dictionary.
+pub fn compare<'a>(old: &'a Kanjidic, new: &'a Kanjidic) -> KanjidicDiff<'a> {
+    compare_characters(&old.characters, &new.characters)
+}
+
+/// Compares two sets of characters, matching them up by literal.
+pub fn compare_characters<'a>(old: &'a [Character], new: &'a [Character]) -> KanjidicDiff<'a> {
+    let old_by_literal: HashMap<Cow<str>, &Character> = old
+        .iter()
+        .map(|character| (character.literal_str(), character))
+        .collect();
+    let new_by_literal: HashMap<Cow<str>, &Character> = new
+        .iter()
+        .map(|character| (character.literal_str(), character))
+        .collect();
+    let mut diff = KanjidicDiff::default();
+    for character in new {
+        match old_by_literal.get(&character.literal_str()) {
+            Some(previous) => {
+                let changes = compare_character(previous, character);
+                if !changes.is_empty() {
+                    diff.changed.push(CharacterDiff {
+                        literal: character.literal_str(),
+                        changes,
+                    });
+                }
+            }
+            None => diff.added.push(character),
+        }
+    }
+    diff.removed = old
+        .iter()
+        .filter(|character| !new_by_literal.contains_key(&character.literal_str()))
+        .collect();
+    diff
+}
+
+/// Lists the fields that differ between two versions of a character.
+pub fn compare_character<'a>(old: &'a Character, new: &'a Character) -> Vec<Change<'a>> {
+    let mut changes = vec![];
+    if let Some(change) = list(&old.codepoints, &new.codepoints) {
+        changes.push(Change::Codepoints(change));
+    }
+    if let Some(change) = list(&old.radicals, &new.radicals) {
+        changes.push(Change::Radicals(change));
+    }
+    if let Some(change) = value(old.grade, new.grade) {
+        changes.push(Change::Grade(change));
+    }
+    if let Some(change) = value(&old.stroke_counts, &new.stroke_counts) {
+        changes.push(Change::StrokeCounts(change));
+    }
+    if let Some(change) = list(&old.variants, &new.variants) {
+        changes.push(Change::Variants(change));
+    }
+    if let Some(change) = value(old.frequency, new.frequency) {
+        changes.push(Change::Frequency(change));
+    }
+    if let Some(change) = list(&old.radical_names, &new.radical_names) {
+        changes.push(Change::RadicalNames(change));
+    }
+    if let Some(change) = value(old.jlpt, new.jlpt) {
+        changes.push(Change::Jlpt(change));
+    }
+    if let Some(change) = list(&old.references, &new.references) {
+        changes.push(Change::References(change));
+    }
+    if let Some(change) = list(&old.query_codes, &new.query_codes) {
+        changes.push(Change::QueryCodes(change));
+    }
+    if let Some(change) = list(&old.readings, &new.readings) {
+        changes.push(Change::Readings(change));
+    }
+    let languages: BTreeSet<&Language> = old
+        .translations
+        .keys()
+        .chain(new.translations.keys())
+        .collect();
+    for language in languages {
+        let old_meanings = old
+            .translations
+            .get(language)
+            .map_or(&[][..], Vec::as_slice);
+        let new_meanings = new
+            .translations
+            .get(language)
+            .map_or(&[][..], Vec::as_slice);
+        if let Some(change) = list(old_meanings, new_meanings) {
+            changes.push(Change::Meanings {
+                language: language.code(),
+                change,
+            });
+        }
+    }
+    if let Some(change) = list(&old.nanori, &new.nanori) {
+        changes.push(Change::Nanori(change));
+    }
+    if let Some(change) = list(&old.decomposition, &new.decomposition) {
+        changes.push(Change::Decomposition(change));
+    }
+    if let Some(change) = value(old.ids.as_deref(), new.ids.as_deref()) {
+        changes.push(Change::Ids(change));
+    }
+    changes
+}
+
+fn value<T: PartialEq>(old: T, new: T) -> Option<ValueChange<T>> {
+    if old == new {
+        None
+    } else {
+        Some(ValueChange { old, new })
+    }
+}
+
+fn list<'a, T: PartialEq>(old: &'a [T], new: &'a [T]) -> Option<ListChange<'a, T>> {
+    let added: Vec<&T> = new.iter().filter(|item| !old.contains(item)).collect();
+    let removed: Vec<&T> = old.iter().filter(|item| !new.contains(item)).collect();
+    if added.is_empty() && removed.is_empty() {
+        None
+    } else {
+        Some(ListChange { added, removed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, compare_characters, Change, ListChange, ValueChange};
+    use crate::test_shared::{CHARACTERS, KANJIDIC};
+
+    #[test]
+    fn identical() {
+        assert!(compare(&KANJIDIC, &KANJIDIC).is_empty());
+    }
+
+    #[test]
+    fn added_and_removed() {
+        let old = &CHARACTERS[..10];
+        let new = &CHARACTERS[5..15];
+        let diff = compare_characters(old, new);
+        assert_eq!(diff.added, CHARACTERS[10..15].iter().collect::<Vec<_>>());
+        assert_eq!(diff.removed, CHARACTERS[..5].iter().collect::<Vec<_>>());
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn field_changes() {
+        let old = CHARACTERS[..1].to_vec();
+        let mut new = old.clone();
+        new[0].stroke_counts.accepted += 1;
+        new[0]
+            .translations
+            .get_mut("en")
+            .unwrap()
+            .push("continent".to_owned());
+        let diff = compare_characters(&old, &new);
+        assert_eq!(diff.changed.len(), 1);
+        let changes = &diff.changed[0].changes;
+        assert_eq!(
+            changes[0],
+            Change::StrokeCounts(ValueChange {
+                old: &old[0].stroke_counts,
+                new: &new[0].stroke_counts,
+            })
+        );
+        assert_eq!(
+            changes[1],
+            Change::Meanings {
+                language: "en",
+                change: ListChange {
+                    added: vec![&"continent".to_owned()],
+                    removed: vec![],
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{kanjidic::Kanjidic, snapshot};
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// The file name of the generated Rust source.
+pub const SOURCE_FILE: &str = "kanjidic.rs";
+
+/// The file name of the snapshot the generated source embeds.
+pub const SNAPSHOT_FILE: &str = "kanjidic.snapshot";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("(Embed) Io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("(Embed) Parsing: {0}")]
+    Parse(#[from] crate::kanjidic::Error),
+    #[error("(Embed) Snapshot: {0}")]
+    Snapshot(#[from] snapshot::Error),
+}
+
+/// Parses a KANJIDIC2 file from a build script and writes the embedded dataset to `out_dir`,
+/// usually `OUT_DIR`, telling Cargo to rebuild when the file changes.
+/// Include the result with
+/// `include!(concat!(env!("OUT_DIR"), "/kanjidic.rs"));`
+/// and call the generated `embedded_kanjidic()` to get the dictionary
+/// without parsing XML at runtime.
+pub fn embed_xml<P: AsRef<Path>, Q: AsRef<Path>>(xml: P, out_dir: Q) -> Result<PathBuf, Error> {
+    let xml = xml.as_ref();
+    println!("cargo:rerun-if-changed={}", xml.display());
+    let kanjidic = Kanjidic::from_file_contents(&fs::read_to_string(xml)?)?;
+    embed(&kanjidic, out_dir)
+}
+
+/// Writes an already parsed dictionary to `out_dir` as an embedded dataset,
+/// as described in [`embed_xml`], returning the path of the generated source.
+pub fn embed<Q: AsRef<Path>>(kanjidic: &Kanjidic, out_dir: Q) -> Result<PathBuf, Error> {
+    let out_dir = out_dir.as_ref();
+    let snapshot_path = out_dir.join(SNAPSHOT_FILE);
+    snapshot::write(kanjidic, BufWriter::new(File::create(&snapshot_path)?))?;
+    let source_path = out_dir.join(SOURCE_FILE);
+    let mut source = BufWriter::new(File::create(&source_path)?);
+    write!(
+        source,
+        r#"/// The dictionary snapshot generated at build time.
+pub static KANJIDIC_SNAPSHOT: &[u8] = include_bytes!({:?});
+
+/// Decodes the dictionary embedded at build time.
+pub fn embedded_kanjidic() -> ::kanjidic_parser::kanjidic::Kanjidic {{
+    ::kanjidic_parser::snapshot::read(KANJIDIC_SNAPSHOT)
+        .expect("the embedded snapshot was written by the same version of kanjidic_parser")
+}}
+"#,
+        snapshot_path.display().to_string()
+    )?;
+    source.flush()?;
+    Ok(source_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{embed, SNAPSHOT_FILE};
+    use crate::{
+        snapshot,
+        test_shared::{temp_dir, KANJIDIC},
+    };
+    use std::fs;
+
+    #[test]
+    fn generated_files() {
+        let dir = temp_dir("embed_generated_files");
+        let source_path = embed(&KANJIDIC, &dir).unwrap();
+        let source = fs::read_to_string(&source_path).unwrap();
+        assert!(source.contains("pub fn embedded_kanjidic()"));
+        assert!(source.contains(SNAPSHOT_FILE));
+        let snapshot = fs::read(dir.join(SNAPSHOT_FILE)).unwrap();
+        assert_eq!(snapshot::read(snapshot.as_slice()).unwrap(), *KANJIDIC);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
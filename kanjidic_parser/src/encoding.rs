@@ -0,0 +1,91 @@
+use crate::kanjidic::{self, Kanjidic};
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+use std::borrow::Cow;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("(Encoding) Input looks like {0} but could not be decoded as it")]
+    Decode(&'static str),
+    #[error("(Encoding) Parsing: {0}")]
+    Parse(#[from] kanjidic::Error),
+}
+
+/// Works out the encoding of an XML file from its byte order mark,
+/// the layout of its first bytes, or its XML declaration, defaulting to UTF-8.
+pub fn detect(bytes: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+    match bytes {
+        [b'<', 0, ..] => return UTF_16LE,
+        [0, b'<', ..] => return UTF_16BE,
+        _ => {}
+    }
+    declared(bytes).unwrap_or(UTF_8)
+}
+
+/// The encoding named in the XML declaration, such as `<?xml version="1.0" encoding="EUC-JP"?>`.
+fn declared(bytes: &[u8]) -> Option<&'static Encoding> {
+    let end = bytes.iter().position(|&b| b == b'>')?;
+    let declaration = std::str::from_utf8(&bytes[..end]).ok()?;
+    let rest = &declaration[declaration.find("encoding")? + "encoding".len()..];
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let quote = rest.chars().next().filter(|&c| c == '"' || c == '\'')?;
+    let rest = &rest[quote.len_utf8()..];
+    let label = &rest[..rest.find(quote)?];
+    Encoding::for_label(label.as_bytes())
+}
+
+/// Decodes the bytes of an XML file in whatever encoding it uses.
+pub fn decode(bytes: &[u8]) -> Result<Cow<'_, str>, Error> {
+    let encoding = detect(bytes);
+    let (encoding, bom_length) = Encoding::for_bom(bytes).unwrap_or((encoding, 0));
+    encoding
+        .decode_without_bom_handling_and_without_replacement(&bytes[bom_length..])
+        .ok_or_else(|| Error::Decode(encoding.name()))
+}
+
+impl Kanjidic {
+    /// Parses a complete KANJIDIC2 file in UTF-8, UTF-16, EUC-JP, or any other encoding
+    /// that it declares.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self::from_file_contents(&decode(bytes)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, detect, Error};
+    use encoding_rs::{EUC_JP, UTF_16LE, UTF_8};
+
+    #[test]
+    fn detection() {
+        assert_eq!(detect(b"<kanjidic2/>"), UTF_8);
+        assert_eq!(detect(b"<\0k\0"), UTF_16LE);
+        assert_eq!(
+            detect(b"<?xml version=\"1.0\" encoding=\"EUC-JP\"?><kanjidic2/>"),
+            EUC_JP
+        );
+        assert_eq!(
+            detect("<?xml version=\"1.0\" encoding=«EUC-JP»?><kanjidic2/>".as_bytes()),
+            UTF_8
+        );
+    }
+
+    #[test]
+    fn decoding() {
+        let mut utf_16 = vec![0xff, 0xfe];
+        utf_16.extend(
+            "<literal>亜</literal>"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes),
+        );
+        assert_eq!(decode(&utf_16).unwrap(), "<literal>亜</literal>");
+
+        let mut euc_jp = b"<?xml version='1.0' encoding='EUC-JP'?><literal>".to_vec();
+        euc_jp.extend([0xb0, 0xa1]);
+        assert!(decode(&euc_jp).unwrap().ends_with("<literal>亜"));
+
+        assert_eq!(decode(&[b'<', 0xff]), Err(Error::Decode("UTF-8")));
+    }
+}
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A stable identifier for each kind of parse failure.
+/// Codes are never renumbered or reused, so they can be compared across versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    // Document structure
+    E0001_INVALID_XML,
+    E0002_MISSING_ROOT,
+    E0003_MISSING_HEADER,
+    E0004_CANCELLED,
+
+    // Shared node access
+    E0010_MISSING_CHILD,
+    E0011_MISSING_TEXT,
+    E0012_INVALID_TEXT_UINT,
+    E0013_INVALID_ATTRIBUTE_UINT,
+    E0014_MISSING_ATTRIBUTE,
+    E0015_INVALID_HEX,
+
+    // Characters
+    E0101_INVALID_LITERAL,
+    E0102_INCOMPLETE_CHARACTER,
+    E0103_MISSING_NANORI_TEXT,
+    E0104_UNKNOWN_CODEPOINT,
+    E0105_INVALID_KUTEN,
+    E0106_UNKNOWN_RADICAL,
+    E0107_UNKNOWN_DIC_REF,
+    E0108_RADICAL_OUT_OF_RANGE,
+    E0109_UNKNOWN_GRADE,
+    E0110_MISSING_STROKE_COUNT,
+    E0111_UNKNOWN_VARIANT,
+    E0112_UNKNOWN_READING,
+    E0113_UNKNOWN_QUERY_CODE,
+    E0114_UNKNOWN_MISCLASSIFICATION,
+    E0115_INVALID_PIN_YIN,
+    E0116_INVALID_KUNYOMI,
+    E0117_INVALID_MORO,
+    E0118_INVALID_BUSY_PEOPLE,
+    E0119_INVALID_ONEILL,
+    E0120_INVALID_SKIP,
+    E0121_INVALID_SPAHN_HADAMITZKY,
+    E0122_INVALID_FOUR_CORNER,
+    E0123_INVALID_DE_ROO,
+
+    // Header
+    E0201_INCOMPLETE_HEADER,
+    E0202_INVALID_DATABASE_VERSION,
+    E0203_INVALID_DATE_OF_CREATION,
+}
+
+impl ErrorCode {
+    /// The code as shown to users, such as `E0107`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::E0001_INVALID_XML => "E0001",
+            ErrorCode::E0002_MISSING_ROOT => "E0002",
+            ErrorCode::E0003_MISSING_HEADER => "E0003",
+            ErrorCode::E0004_CANCELLED => "E0004",
+            ErrorCode::E0010_MISSING_CHILD => "E0010",
+            ErrorCode::E0011_MISSING_TEXT => "E0011",
+            ErrorCode::E0012_INVALID_TEXT_UINT => "E0012",
+            ErrorCode::E0013_INVALID_ATTRIBUTE_UINT => "E0013",
+            ErrorCode::E0014_MISSING_ATTRIBUTE => "E0014",
+            ErrorCode::E0015_INVALID_HEX => "E0015",
+            ErrorCode::E0101_INVALID_LITERAL => "E0101",
+            ErrorCode::E0102_INCOMPLETE_CHARACTER => "E0102",
+            ErrorCode::E0103_MISSING_NANORI_TEXT => "E0103",
+            ErrorCode::E0104_UNKNOWN_CODEPOINT => "E0104",
+            ErrorCode::E0105_INVALID_KUTEN => "E0105",
+            ErrorCode::E0106_UNKNOWN_RADICAL => "E0106",
+            ErrorCode::E0107_UNKNOWN_DIC_REF => "E0107",
+            ErrorCode::E0108_RADICAL_OUT_OF_RANGE => "E0108",
+            ErrorCode::E0109_UNKNOWN_GRADE => "E0109",
+            ErrorCode::E0110_MISSING_STROKE_COUNT => "E0110",
+            ErrorCode::E0111_UNKNOWN_VARIANT => "E0111",
+            ErrorCode::E0112_UNKNOWN_READING => "E0112",
+            ErrorCode::E0113_UNKNOWN_QUERY_CODE => "E0113",
+            ErrorCode::E0114_UNKNOWN_MISCLASSIFICATION => "E0114",
+            ErrorCode::E0115_INVALID_PIN_YIN => "E0115",
+            ErrorCode::E0116_INVALID_KUNYOMI => "E0116",
+            ErrorCode::E0117_INVALID_MORO => "E0117",
+            ErrorCode::E0118_INVALID_BUSY_PEOPLE => "E0118",
+            ErrorCode::E0119_INVALID_ONEILL => "E0119",
+            ErrorCode::E0120_INVALID_SKIP => "E0120",
+            ErrorCode::E0121_INVALID_SPAHN_HADAMITZKY => "E0121",
+            ErrorCode::E0122_INVALID_FOUR_CORNER => "E0122",
+            ErrorCode::E0123_INVALID_DE_ROO => "E0123",
+            ErrorCode::E0201_INCOMPLETE_HEADER => "E0201",
+            ErrorCode::E0202_INVALID_DATABASE_VERSION => "E0202",
+            ErrorCode::E0203_INVALID_DATE_OF_CREATION => "E0203",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
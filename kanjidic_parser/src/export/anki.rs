@@ -0,0 +1,115 @@
+use super::{Column, Error};
+use kanjidic_types::Character;
+use std::io::Write;
+
+/// How to lay out the notes of an Anki import file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnkiOptions {
+    /// The note fields in order. Anki matches existing notes by the first field,
+    /// so it should usually be [`Column::Literal`].
+    pub fields: Vec<Column>,
+    /// The deck to import into, or the one chosen in the import dialog if unset.
+    pub deck: Option<String>,
+    /// The note type to import as, or the one chosen in the import dialog if unset.
+    pub notetype: Option<String>,
+    /// Whether to tag each note with its grade and JLPT level, such as `kanjidic::grade-8`.
+    pub tags: bool,
+}
+
+impl Default for AnkiOptions {
+    fn default() -> Self {
+        Self {
+            fields: vec![
+                Column::Literal,
+                Column::Meanings,
+                Column::Onyomi,
+                Column::Kunyomi,
+                Column::StrokeCount,
+            ],
+            deck: None,
+            notetype: None,
+            tags: true,
+        }
+    }
+}
+
+/// Writes the characters as a tab-separated text file that Anki can import,
+/// with file headers naming the columns and, if given, the deck and note type.
+/// Notes carry no media, so there is nothing to copy into Anki's media folder.
+pub fn anki<'a, W: Write>(
+    characters: impl IntoIterator<Item = &'a Character>,
+    options: &AnkiOptions,
+    mut writer: W,
+) -> Result<(), Error> {
+    let mut columns: Vec<&str> = options.fields.iter().map(Column::name).collect();
+    if options.tags {
+        columns.push("tags");
+    }
+    writeln!(writer, "#separator:tab")?;
+    writeln!(writer, "#html:false")?;
+    writeln!(writer, "#columns:{}", columns.join("\t"))?;
+    if let Some(deck) = &options.deck {
+        writeln!(writer, "#deck:{}", deck)?;
+    }
+    if let Some(notetype) = &options.notetype {
+        writeln!(writer, "#notetype:{}", notetype)?;
+    }
+    if options.tags {
+        writeln!(writer, "#tags column:{}", columns.len())?;
+    }
+    for character in characters {
+        let mut cells: Vec<String> = options
+            .fields
+            .iter()
+            .map(|column| column.value(character))
+            .collect();
+        if options.tags {
+            cells.push(tags(character));
+        }
+        let escaped: Vec<String> = cells.iter().map(|cell| escape(cell)).collect();
+        writeln!(writer, "{}", escaped.join("\t"))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn tags(character: &Character) -> String {
+    let mut tags = vec![];
+    if let Some(grade) = character.grade {
+        tags.push(format!("kanjidic::grade-{}", grade.to_raw()));
+    }
+    if let Some(jlpt) = character.jlpt {
+        tags.push(format!("kanjidic::jlpt-{}", jlpt));
+    }
+    tags.join(" ")
+}
+
+fn escape(cell: &str) -> String {
+    if cell.contains(['\t', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{anki, AnkiOptions};
+    use crate::{export::Column, test_shared::CHARACTERS};
+
+    #[test]
+    fn notes() {
+        let mut out = vec![];
+        let options = AnkiOptions {
+            fields: vec![Column::Literal, Column::FirstMeaning, Column::Onyomi],
+            deck: Some("Kanji".to_owned()),
+            ..AnkiOptions::default()
+        };
+        anki(&CHARACTERS[..1], &options, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "#separator:tab\n#html:false\n#columns:literal\tfirst_meaning\tonyomi\ttags\n\
+             #deck:Kanji\n#tags column:4\n亜\tAsia\tア\tkanjidic::grade-8 kanjidic::jlpt-1\n"
+        );
+    }
+}
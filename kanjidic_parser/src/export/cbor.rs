@@ -0,0 +1,29 @@
+use super::Error;
+use crate::kanjidic::Kanjidic;
+use std::io::{Read, Write};
+
+/// Writes the dictionary as CBOR.
+pub fn cbor<W: Write>(kanjidic: &Kanjidic, mut writer: W) -> Result<(), Error> {
+    ciborium::ser::into_writer(kanjidic, &mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads a dictionary written by [`cbor`].
+pub fn from_cbor<R: Read>(reader: R) -> Result<Kanjidic, Error> {
+    Ok(ciborium::de::from_reader(reader)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cbor, from_cbor};
+    use crate::test_shared::KANJIDIC;
+
+    #[test]
+    fn round_trip() {
+        let mut blob = vec![];
+        cbor(&KANJIDIC, &mut blob).unwrap();
+        let kanjidic = from_cbor(blob.as_slice()).unwrap();
+        assert_eq!(kanjidic, *KANJIDIC);
+    }
+}
@@ -0,0 +1,132 @@
+use super::Error;
+use crate::kunyomi;
+use kanjidic_types::{Character, Grade};
+use std::io::Write;
+
+/// A column that can be included in CSV output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// The character itself.
+    Literal,
+    /// The grade level, using the numbering from KANJIDIC2.
+    Grade,
+    /// The accepted stroke count.
+    StrokeCount,
+    /// The newspaper frequency ranking.
+    Frequency,
+    /// The old JLPT level.
+    Jlpt,
+    /// The first English meaning.
+    FirstMeaning,
+    /// All English meanings.
+    Meanings,
+    /// The onyomi readings.
+    Onyomi,
+    /// The kunyomi readings, in KANJIDIC2 notation.
+    Kunyomi,
+    /// The name readings.
+    Nanori,
+}
+
+impl Column {
+    /// The header text for the column.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Column::Literal => "literal",
+            Column::Grade => "grade",
+            Column::StrokeCount => "stroke_count",
+            Column::Frequency => "frequency",
+            Column::Jlpt => "jlpt",
+            Column::FirstMeaning => "first_meaning",
+            Column::Meanings => "meanings",
+            Column::Onyomi => "onyomi",
+            Column::Kunyomi => "kunyomi",
+            Column::Nanori => "nanori",
+        }
+    }
+
+    pub(super) fn value(&self, character: &Character) -> String {
+        match self {
+            Column::Literal => character.literal_str().into_owned(),
+            Column::Grade => optional(character.grade.map(Grade::to_raw)),
+            Column::StrokeCount => character.stroke_counts.accepted.to_string(),
+            Column::Frequency => optional(character.frequency),
+            Column::Jlpt => optional(character.jlpt),
+            Column::FirstMeaning => character.primary_meaning().unwrap_or_default().to_owned(),
+            Column::Meanings => character.meanings("en").unwrap_or_default().join(SEPARATOR),
+            Column::Onyomi => join(character.onyomi().map(str::to_owned)),
+            Column::Kunyomi => join(character.kunyomi().map(kunyomi::text)),
+            Column::Nanori => character.nanori.join(SEPARATOR),
+        }
+    }
+}
+
+/// Separates multiple values within a single cell.
+const SEPARATOR: &str = "; ";
+
+/// Writes the characters as CSV with a header row, including only the given columns.
+pub fn csv<W: Write>(
+    characters: &[Character],
+    columns: &[Column],
+    mut writer: W,
+) -> Result<(), Error> {
+    let header: Vec<String> = columns
+        .iter()
+        .map(|column| column.name().to_owned())
+        .collect();
+    write_row(&mut writer, &header)?;
+    for character in characters {
+        let row: Vec<String> = columns
+            .iter()
+            .map(|column| column.value(character))
+            .collect();
+        write_row(&mut writer, &row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_row<W: Write>(writer: &mut W, cells: &[String]) -> Result<(), Error> {
+    let escaped: Vec<String> = cells.iter().map(|cell| escape(cell)).collect();
+    writeln!(writer, "{}", escaped.join(","))?;
+    Ok(())
+}
+
+fn escape(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_owned()
+    }
+}
+
+fn join(values: impl Iterator<Item = String>) -> String {
+    values.collect::<Vec<_>>().join(SEPARATOR)
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv, Column};
+    use crate::test_shared::CHARACTERS;
+
+    #[test]
+    fn selected_columns() {
+        let mut out = vec![];
+        let columns = [
+            Column::Literal,
+            Column::Grade,
+            Column::FirstMeaning,
+            Column::Onyomi,
+            Column::Kunyomi,
+        ];
+        csv(&CHARACTERS[..1], &columns, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "literal,grade,first_meaning,onyomi,kunyomi\n亜,8,Asia,ア,つ.ぐ\n"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use super::Error;
+use crate::db::KanjidicDb;
+use kanjidic_types::{Codepoint, Variant};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io::Write,
+};
+
+/// Writes a GraphViz DOT graph of the given characters and everything reachable from them.
+/// Solid edges point from a character to the components of its decomposition,
+/// and dashed edges join a character to its variants that are in the database.
+pub fn dot<W: Write>(db: &KanjidicDb, roots: &[char], mut writer: W) -> Result<(), Error> {
+    let codepoints: HashMap<Codepoint, char> = db
+        .iter()
+        .flat_map(|character| {
+            character
+                .codepoints
+                .iter()
+                .map(move |&codepoint| (codepoint, character.literal))
+        })
+        .collect();
+    let mut visited: HashSet<char> = roots.iter().copied().collect();
+    let mut queue: VecDeque<char> = roots.iter().copied().collect();
+    let mut variant_edges: HashSet<(char, char)> = HashSet::new();
+    writeln!(writer, "digraph kanji {{")?;
+    for root in roots {
+        writeln!(writer, "    \"{}\" [shape=box];", root)?;
+    }
+    while let Some(literal) = queue.pop_front() {
+        let character = match db.get(literal) {
+            Some(character) => character,
+            None => continue,
+        };
+        let mut next = vec![];
+        for &component in &character.decomposition {
+            if component != literal {
+                writeln!(writer, "    \"{}\" -> \"{}\";", literal, component)?;
+                next.push(component);
+            }
+        }
+        for variant in &character.variants {
+            let other = match codepoint(variant).and_then(|cp| codepoints.get(&cp)) {
+                Some(&other) if other != literal => other,
+                _ => continue,
+            };
+            let edge = (literal.min(other), literal.max(other));
+            if variant_edges.insert(edge) {
+                writeln!(
+                    writer,
+                    "    \"{}\" -> \"{}\" [style=dashed, dir=none];",
+                    edge.0, edge.1
+                )?;
+            }
+            next.push(other);
+        }
+        for c in next {
+            if visited.insert(c) {
+                queue.push_back(c);
+            }
+        }
+    }
+    writeln!(writer, "}}")?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn codepoint(variant: &Variant) -> Option<Codepoint> {
+    match *variant {
+        Variant::Jis208(kuten) => Some(Codepoint::Jis208(kuten)),
+        Variant::Jis212(kuten) => Some(Codepoint::Jis212(kuten)),
+        Variant::Jis213(kuten) => Some(Codepoint::Jis213(kuten)),
+        Variant::Unicode(unicode) => Some(Codepoint::Unicode(unicode)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dot;
+    use crate::{db::KanjidicDb, test_shared::decomposed};
+    use kanjidic_types::Variant;
+
+    #[test]
+    fn graph() {
+        let mut characters = decomposed()[..2].to_vec();
+        let variant = Variant::Unicode(characters[1].literal as u32);
+        characters[0].variants = vec![variant];
+        let second = characters[1].literal;
+        let db = KanjidicDb::new(characters);
+        let mut out = vec![];
+        dot(&db, &['亜'], &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("digraph kanji {\n    \"亜\" [shape=box];\n"));
+        assert!(out.contains("    \"亜\" -> \"口\";\n"));
+        let (low, high) = ('亜'.min(second), '亜'.max(second));
+        assert!(out.contains(&format!(
+            "    \"{}\" -> \"{}\" [style=dashed, dir=none];\n",
+            low, high
+        )));
+        assert!(out.ends_with("}\n"));
+    }
+}
@@ -0,0 +1,32 @@
+use super::Error;
+use kanjidic_types::Character;
+use std::io::Write;
+
+/// Writes each character as a JSON object on its own line (NDJSON).
+pub fn json_lines<W: Write>(characters: &[Character], mut writer: W) -> Result<(), Error> {
+    for character in characters {
+        serde_json::to_writer(&mut writer, character)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::json_lines;
+    use crate::test_shared::CHARACTERS;
+    use kanjidic_types::Character;
+
+    #[test]
+    fn one_character_per_line() {
+        let mut out = vec![];
+        json_lines(&CHARACTERS, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let parsed: Vec<Character> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, *CHARACTERS);
+    }
+}
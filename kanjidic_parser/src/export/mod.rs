@@ -0,0 +1,62 @@
+mod csv;
+pub use self::csv::{csv, Column};
+
+#[cfg(feature = "anki")]
+mod anki;
+#[cfg(feature = "anki")]
+pub use anki::{anki, AnkiOptions};
+
+mod dot;
+pub use dot::dot;
+
+mod json_lines;
+pub use json_lines::json_lines;
+
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "msgpack")]
+pub use msgpack::{from_msgpack, msgpack};
+
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "cbor")]
+pub use cbor::{cbor, from_cbor};
+
+#[cfg(feature = "parquet")]
+mod parquet;
+#[cfg(feature = "parquet")]
+pub use self::parquet::{parquet, record_batch};
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(feature = "sqlite")]
+pub use sqlite::{sqlite, sqlite_connection};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("(Export) Io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("(Export) Json: {0}")]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "msgpack")]
+    #[error("(Export) MessagePack encoding: {0}")]
+    MsgpackEncode(#[from] rmp_serde::encode::Error),
+    #[cfg(feature = "msgpack")]
+    #[error("(Export) MessagePack decoding: {0}")]
+    MsgpackDecode(#[from] rmp_serde::decode::Error),
+    #[cfg(feature = "cbor")]
+    #[error("(Export) CBOR encoding: {0}")]
+    CborEncode(#[from] ciborium::ser::Error<std::io::Error>),
+    #[cfg(feature = "cbor")]
+    #[error("(Export) CBOR decoding: {0}")]
+    CborDecode(#[from] ciborium::de::Error<std::io::Error>),
+    #[cfg(feature = "parquet")]
+    #[error("(Export) Arrow: {0}")]
+    Arrow(#[from] arrow::error::ArrowError),
+    #[cfg(feature = "parquet")]
+    #[error("(Export) Parquet: {0}")]
+    Parquet(#[from] ::parquet::errors::ParquetError),
+    #[cfg(feature = "sqlite")]
+    #[error("(Export) Sqlite: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+}
@@ -0,0 +1,30 @@
+use super::Error;
+use crate::kanjidic::Kanjidic;
+use std::io::{Read, Write};
+
+/// Writes the dictionary as MessagePack.
+/// Fields are written by name since several types skip empty fields when serializing.
+pub fn msgpack<W: Write>(kanjidic: &Kanjidic, mut writer: W) -> Result<(), Error> {
+    rmp_serde::encode::write_named(&mut writer, kanjidic)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads a dictionary written by [`msgpack`].
+pub fn from_msgpack<R: Read>(reader: R) -> Result<Kanjidic, Error> {
+    Ok(rmp_serde::from_read(reader)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_msgpack, msgpack};
+    use crate::test_shared::KANJIDIC;
+
+    #[test]
+    fn round_trip() {
+        let mut blob = vec![];
+        msgpack(&KANJIDIC, &mut blob).unwrap();
+        let kanjidic = from_msgpack(blob.as_slice()).unwrap();
+        assert_eq!(kanjidic, *KANJIDIC);
+    }
+}
@@ -0,0 +1,133 @@
+use super::Error;
+use crate::kunyomi;
+use ::parquet::arrow::ArrowWriter;
+use arrow::{
+    array::{ArrayRef, ListBuilder, StringArray, StringBuilder, UInt16Array, UInt8Array},
+    record_batch::RecordBatch,
+};
+use kanjidic_types::{Character, Grade, Language};
+use std::{collections::BTreeSet, io::Write, sync::Arc};
+
+/// Flattens the characters into an Arrow record batch with one row per character.
+/// Readings and nanori become list columns, and meanings get a list column
+/// for each language, such as `meanings_en`.
+pub fn record_batch(characters: &[Character]) -> Result<RecordBatch, Error> {
+    let literal: StringArray = characters
+        .iter()
+        .map(|character| Some(character.literal.to_string()))
+        .collect();
+    let grade: UInt8Array = characters
+        .iter()
+        .map(|character| character.grade.map(Grade::to_raw))
+        .collect();
+    let stroke_count: UInt8Array = characters
+        .iter()
+        .map(|character| Some(character.stroke_counts.accepted))
+        .collect();
+    let frequency: UInt16Array = characters
+        .iter()
+        .map(|character| character.frequency)
+        .collect();
+    let jlpt: UInt8Array = characters.iter().map(|character| character.jlpt).collect();
+    let mut columns: Vec<(String, ArrayRef)> = vec![
+        ("literal".into(), Arc::new(literal) as ArrayRef),
+        ("grade".into(), Arc::new(grade) as ArrayRef),
+        ("stroke_count".into(), Arc::new(stroke_count) as ArrayRef),
+        ("frequency".into(), Arc::new(frequency) as ArrayRef),
+        ("jlpt".into(), Arc::new(jlpt) as ArrayRef),
+        (
+            "pinyin".into(),
+            list(characters, |character| {
+                character
+                    .pinyin()
+                    .map(|pin_yin| format!("{}{}", pin_yin.romanization, pin_yin.tone as u8))
+                    .collect()
+            }),
+        ),
+        (
+            "onyomi".into(),
+            list(characters, |character| {
+                character.onyomi().map(str::to_owned).collect()
+            }),
+        ),
+        (
+            "kunyomi".into(),
+            list(characters, |character| {
+                character.kunyomi().map(kunyomi::text).collect()
+            }),
+        ),
+        (
+            "nanori".into(),
+            list(characters, |character| character.nanori.clone()),
+        ),
+    ];
+    let languages: BTreeSet<&Language> = characters
+        .iter()
+        .flat_map(|character| character.translations.keys())
+        .collect();
+    for language in languages {
+        let meanings = list(characters, |character| {
+            character
+                .translations
+                .get(language)
+                .cloned()
+                .unwrap_or_default()
+        });
+        columns.push((format!("meanings_{}", language), meanings));
+    }
+    Ok(RecordBatch::try_from_iter(columns)?)
+}
+
+/// Writes the characters as a Parquet file using the columns from [`record_batch`].
+pub fn parquet<W: Write + Send>(characters: &[Character], writer: W) -> Result<(), Error> {
+    let batch = record_batch(characters)?;
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+fn list<F>(characters: &[Character], values: F) -> ArrayRef
+where
+    F: Fn(&Character) -> Vec<String>,
+{
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for character in characters {
+        for value in values(character) {
+            builder.values().append_value(value);
+        }
+        builder.append(true);
+    }
+    Arc::new(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parquet, record_batch};
+    use crate::test_shared::CHARACTERS;
+    use arrow::array::{Array, ListArray, StringArray};
+
+    #[test]
+    fn flattens_characters() {
+        let batch = record_batch(&CHARACTERS).unwrap();
+        assert_eq!(batch.num_rows(), CHARACTERS.len());
+        let onyomi = batch
+            .column_by_name("onyomi")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap()
+            .value(0);
+        let onyomi = onyomi.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(onyomi.len(), 1);
+        assert_eq!(onyomi.value(0), "ア");
+        assert!(batch.column_by_name("meanings_en").is_some());
+    }
+
+    #[test]
+    fn writes_parquet() {
+        let mut out = vec![];
+        parquet(&CHARACTERS, &mut out).unwrap();
+        assert!(out.starts_with(b"PAR1"));
+    }
+}
@@ -0,0 +1,185 @@
+use super::Error;
+use crate::kunyomi;
+use crate::reference;
+use kanjidic_types::{Character, Codepoint, Grade, Reading};
+use rusqlite::{params, Connection};
+use std::path::Path;
+
+const SCHEMA: &str = "
+CREATE TABLE characters (
+    literal TEXT PRIMARY KEY NOT NULL,
+    grade INTEGER,
+    stroke_count INTEGER NOT NULL,
+    frequency INTEGER,
+    jlpt INTEGER
+);
+CREATE TABLE readings (
+    literal TEXT NOT NULL REFERENCES characters(literal),
+    kind TEXT NOT NULL,
+    reading TEXT NOT NULL
+);
+CREATE TABLE meanings (
+    literal TEXT NOT NULL REFERENCES characters(literal),
+    language TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    meaning TEXT NOT NULL
+);
+CREATE TABLE dictionary_references (
+    literal TEXT NOT NULL REFERENCES characters(literal),
+    kind TEXT NOT NULL,
+    value TEXT NOT NULL
+);
+CREATE TABLE codepoints (
+    literal TEXT NOT NULL REFERENCES characters(literal),
+    kind TEXT NOT NULL,
+    value TEXT NOT NULL
+);
+CREATE INDEX characters_frequency ON characters(frequency);
+CREATE INDEX readings_literal ON readings(literal);
+CREATE INDEX readings_reading ON readings(reading);
+CREATE INDEX meanings_literal ON meanings(literal);
+CREATE INDEX dictionary_references_literal ON dictionary_references(literal);
+CREATE INDEX codepoints_literal ON codepoints(literal);
+";
+
+/// Creates a SQLite database at the given path containing the characters.
+pub fn sqlite<P: AsRef<Path>>(characters: &[Character], path: P) -> Result<(), Error> {
+    let mut connection = Connection::open(path)?;
+    sqlite_connection(characters, &mut connection)
+}
+
+/// Creates the dictionary tables in an open SQLite database and fills them with the characters.
+pub fn sqlite_connection(
+    characters: &[Character],
+    connection: &mut Connection,
+) -> Result<(), Error> {
+    connection.execute_batch(SCHEMA)?;
+    let transaction = connection.transaction()?;
+    {
+        let mut insert_character = transaction.prepare(
+            "INSERT INTO characters (literal, grade, stroke_count, frequency, jlpt)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+        )?;
+        let mut insert_reading = transaction
+            .prepare("INSERT INTO readings (literal, kind, reading) VALUES (?1, ?2, ?3)")?;
+        let mut insert_meaning = transaction.prepare(
+            "INSERT INTO meanings (literal, language, position, meaning)
+            VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        let mut insert_reference = transaction.prepare(
+            "INSERT INTO dictionary_references (literal, kind, value) VALUES (?1, ?2, ?3)",
+        )?;
+        let mut insert_codepoint = transaction
+            .prepare("INSERT INTO codepoints (literal, kind, value) VALUES (?1, ?2, ?3)")?;
+        for character in characters {
+            let literal = character.literal_str();
+            insert_character.execute(params![
+                literal,
+                character.grade.map(Grade::to_raw),
+                character.stroke_counts.accepted,
+                character.frequency,
+                character.jlpt,
+            ])?;
+            for reading in &character.readings {
+                let (kind, text) = reading_row(reading);
+                insert_reading.execute(params![literal, kind, text])?;
+            }
+            for (language, meanings) in &character.translations {
+                for (position, meaning) in meanings.iter().enumerate() {
+                    insert_meaning.execute(params![
+                        literal,
+                        language.code(),
+                        position as i64,
+                        meaning
+                    ])?;
+                }
+            }
+            for reference in &character.references {
+                insert_reference.execute(params![
+                    literal,
+                    reference::dr_type(reference),
+                    reference::text(reference),
+                ])?;
+            }
+            for codepoint in &character.codepoints {
+                let (kind, value) = codepoint_row(codepoint);
+                insert_codepoint.execute(params![literal, kind, value])?;
+            }
+        }
+    }
+    transaction.commit()?;
+    Ok(())
+}
+
+fn reading_row(reading: &Reading) -> (&'static str, String) {
+    match reading {
+        Reading::PinYin(pin_yin) => (
+            "pinyin",
+            format!("{}{}", pin_yin.romanization, pin_yin.tone as u8),
+        ),
+        Reading::KoreanRomanized(text) => ("korean_r", text.clone()),
+        Reading::KoreanHangul(text) => ("korean_h", text.clone()),
+        Reading::Vietnam(text) => ("vietnam", text.clone()),
+        Reading::Onyomi(text) => ("ja_on", text.clone()),
+        Reading::Kunyomi(kunyomi) => ("ja_kun", kunyomi::text(kunyomi)),
+    }
+}
+
+fn codepoint_row(codepoint: &Codepoint) -> (&'static str, String) {
+    match codepoint {
+        Codepoint::Jis208(kuten) => ("jis208", kuten.to_string()),
+        Codepoint::Jis212(kuten) => ("jis212", kuten.to_string()),
+        Codepoint::Jis213(kuten) => ("jis213", kuten.to_string()),
+        Codepoint::Unicode(code) => ("ucs", format!("{:x}", code)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sqlite_connection;
+    use crate::test_shared::CHARACTERS;
+    use rusqlite::Connection;
+
+    #[test]
+    fn writes_tables() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        sqlite_connection(&CHARACTERS, &mut connection).unwrap();
+        let count: i64 = connection
+            .query_row("SELECT COUNT(*) FROM characters", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count as usize, CHARACTERS.len());
+        let literal: String = connection
+            .query_row(
+                "SELECT literal FROM dictionary_references WHERE kind = 'heisig' AND value = '1809'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(literal, "亜");
+        let jis208: String = connection
+            .query_row(
+                "SELECT value FROM codepoints WHERE literal = '亜' AND kind = 'jis208'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(jis208, "1-16-01");
+    }
+
+    #[test]
+    fn variation_sequences() {
+        let mut sequence = CHARACTERS[0].clone();
+        sequence.literal_sequence = Some("亜\u{FE00}".into());
+        let characters = vec![CHARACTERS[0].clone(), sequence];
+        let mut connection = Connection::open_in_memory().unwrap();
+        sqlite_connection(&characters, &mut connection).unwrap();
+        let literals: Vec<String> = connection
+            .prepare("SELECT literal FROM characters ORDER BY literal")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(literals, vec!["亜", "亜\u{FE00}"]);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::kanjidic::{self, Kanjidic};
+use flate2::read::GzDecoder;
+use std::io::Read;
+
+/// Where EDRDG publishes the latest KANJIDIC2 release.
+pub const LATEST_URL: &str = "https://www.edrdg.org/kanjidic/kanjidic2.xml.gz";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("(Fetch) Request failed: {0}")]
+    Request(#[from] Box<ureq::Error>),
+    #[error("(Fetch) Could not decompress the download: {0}")]
+    Decompress(#[from] std::io::Error),
+    #[error("(Fetch) Parsing: {0}")]
+    Parse(#[from] kanjidic::Error),
+}
+
+impl Kanjidic {
+    /// Downloads and parses the latest KANJIDIC2 release from EDRDG.
+    pub fn download_latest() -> Result<Self, Error> {
+        download(LATEST_URL)
+    }
+}
+
+/// Downloads a gzipped KANJIDIC2 file from the given URL and parses it.
+pub fn download(url: &str) -> Result<Kanjidic, Error> {
+    let response = ureq::get(url).call().map_err(Box::new)?;
+    let mut xml = String::new();
+    GzDecoder::new(response.into_reader()).read_to_string(&mut xml)?;
+    Ok(Kanjidic::from_file_contents(&xml)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kanjidic::Kanjidic;
+
+    #[test]
+    #[ignore]
+    fn download_latest() {
+        let kanjidic = Kanjidic::download_latest().unwrap();
+        assert!(!kanjidic.characters.is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{
+    db::{KanjidicDb, QueryFilter},
+    kanjidic::Kanjidic,
+};
+use kanjidic_types::Language;
+use serde::Serialize;
+use std::{
+    ffi::{CStr, CString},
+    fs,
+    os::raw::c_char,
+    ptr,
+};
+
+/// Parses the KANJIDIC2 XML file at the given path.
+///
+/// # Safety
+///
+/// `path` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_db_open(path: *const c_char) -> *mut KanjidicDb {
+    match to_str(path).and_then(|path| fs::read_to_string(path).ok()) {
+        Some(xml) => parse(&xml),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Parses KANJIDIC2 XML from memory.
+///
+/// # Safety
+///
+/// `xml` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_db_parse(xml: *const c_char) -> *mut KanjidicDb {
+    match to_str(xml) {
+        Some(xml) => parse(xml),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Releases a database.
+///
+/// # Safety
+///
+/// `db` must be null or a pointer returned by this module that has not yet been freed.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_db_free(db: *mut KanjidicDb) {
+    if !db.is_null() {
+        drop(Box::from_raw(db));
+    }
+}
+
+/// The number of characters in the database.
+///
+/// # Safety
+///
+/// `db` must be null or a valid database pointer.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_db_len(db: *const KanjidicDb) -> usize {
+    db.as_ref().map_or(0, KanjidicDb::len)
+}
+
+/// Gets the character with the given Unicode scalar value as a JSON object,
+/// or null if there is no such character.
+///
+/// # Safety
+///
+/// `db` must be null or a valid database pointer.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_lookup(db: *const KanjidicDb, literal: u32) -> *mut c_char {
+    let character = db
+        .as_ref()
+        .zip(char::from_u32(literal))
+        .and_then(|(db, literal)| db.get(literal));
+    match character {
+        Some(character) => to_json(character),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Gets the characters with the given on'yomi or kun'yomi as a JSON array.
+///
+/// # Safety
+///
+/// `db` must be null or a valid database pointer,
+/// and `reading` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_by_reading(
+    db: *const KanjidicDb,
+    reading: *const c_char,
+) -> *mut c_char {
+    match (db.as_ref(), to_str(reading)) {
+        (Some(db), Some(reading)) => to_json(&db.by_reading(reading)),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Gets the characters with a meaning matching the query as a JSON array, best matches first.
+///
+/// # Safety
+///
+/// `db` must be null or a valid database pointer,
+/// and `query` and `language` must be null or valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_search_meanings(
+    db: *const KanjidicDb,
+    query: *const c_char,
+    language: *const c_char,
+) -> *mut c_char {
+    match (db.as_ref(), to_str(query), to_str(language)) {
+        (Some(db), Some(query), Some(language)) => {
+            let characters: Vec<_> = db
+                .search_meanings(query, &Language::from(language))
+                .into_iter()
+                .map(|found| found.character)
+                .collect();
+            to_json(&characters)
+        }
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Gets the characters matching a JSON filter such as
+/// `{"grade": 1, "jlpt": 4, "minStrokes": 2, "maxStrokes": 8, "component": "口"}`
+/// as a JSON array. A null filter matches every character.
+///
+/// # Safety
+///
+/// `db` must be null or a valid database pointer,
+/// and `filter` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_query(
+    db: *const KanjidicDb,
+    filter: *const c_char,
+) -> *mut c_char {
+    let db = match db.as_ref() {
+        Some(db) => db,
+        None => return ptr::null_mut(),
+    };
+    let filter = if filter.is_null() {
+        QueryFilter::default()
+    } else {
+        match to_str(filter).and_then(|filter| serde_json::from_str(filter).ok()) {
+            Some(filter) => filter,
+            None => return ptr::null_mut(),
+        }
+    };
+    to_json(&db.query_with(&filter).collect())
+}
+
+/// Releases a string returned by this module.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by this module that has not yet been freed.
+#[no_mangle]
+pub unsafe extern "C" fn kanjidic_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+fn parse(xml: &str) -> *mut KanjidicDb {
+    match Kanjidic::from_file_contents(xml) {
+        Ok(kanjidic) => Box::into_raw(Box::new(KanjidicDb::new(kanjidic.characters))),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        None
+    } else {
+        CStr::from_ptr(s).to_str().ok()
+    }
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> *mut c_char {
+    serde_json::to_string(value)
+        .ok()
+        .and_then(|json| CString::new(json).ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        kanjidic_by_reading, kanjidic_db_free, kanjidic_db_len, kanjidic_lookup, kanjidic_query,
+        kanjidic_string_free,
+    };
+    use crate::{db::KanjidicDb, test_shared::decomposed};
+    use kanjidic_types::Character;
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn round_trip() {
+        let characters = decomposed();
+        let db = Box::into_raw(Box::new(KanjidicDb::new(characters.clone())));
+        unsafe {
+            assert_eq!(kanjidic_db_len(db), characters.len());
+
+            let json = kanjidic_lookup(db, '亜' as u32);
+            let character: Character =
+                serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+            assert_eq!(character, characters[0]);
+            kanjidic_string_free(json);
+            assert!(kanjidic_lookup(db, 'a' as u32).is_null());
+
+            let reading = CString::new("ア").unwrap();
+            let json = kanjidic_by_reading(db, reading.as_ptr());
+            let characters: Vec<Character> =
+                serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+            assert!(characters.iter().any(|character| character.literal == '亜'));
+            kanjidic_string_free(json);
+
+            let filter = CString::new(r#"{"maxStrokes": 7, "component": "口"}"#).unwrap();
+            let json = kanjidic_query(db, filter.as_ptr());
+            let characters: Vec<Character> =
+                serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+            assert!(characters.iter().any(|character| character.literal == '亜'));
+            kanjidic_string_free(json);
+
+            let filter = CString::new("not json").unwrap();
+            assert!(kanjidic_query(db, filter.as_ptr()).is_null());
+
+            kanjidic_db_free(db);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{self, SharedError},
 };
@@ -14,6 +15,16 @@ pub enum Error {
     Str(PosError, four_corner::ParseError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Str(_, _) => ErrorCode::E0122_INVALID_FOUR_CORNER,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<FourCorner, Error> {
     let text = shared::text(&node)?;
     FourCorner::try_from(text).map_err(|err| Error::Str(PosError::from(&node), err))
@@ -49,4 +60,20 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn notation() {
+        let four_corner: FourCorner = "1060.7".parse().unwrap();
+        assert_eq!(four_corner.top_left, Stroke::LineHorizontal);
+        assert_eq!(four_corner.fifth_corner, Some(Stroke::Angle));
+        assert_eq!(four_corner.to_string(), "1060.7");
+
+        let four_corner: FourCorner = "1060".parse().unwrap();
+        assert_eq!(four_corner.fifth_corner, None);
+        assert_eq!(four_corner.to_string(), "1060");
+
+        assert!("106".parse::<FourCorner>().is_err());
+        assert!("1060-7".parse::<FourCorner>().is_err());
+        assert!("1060.71".parse::<FourCorner>().is_err());
+    }
 }
@@ -0,0 +1,229 @@
+use crate::{
+    busy_people,
+    character::{self, CharacterError},
+    codepoint,
+    database_version::{self, DatabaseVersion},
+    date_of_creation::{self, DateOfCreation},
+    de_roo, four_corner, grade,
+    header::{self, Header},
+    kunyomi, kuten, moro, oneill, pin_yin, query_code, radical, reading, reference,
+    skip::{self, SkipError},
+    spahn_hadamitzky::{self, ShError},
+    variant,
+};
+use kanjidic_types::{
+    BusyPeople, Character, Codepoint, DeRoo, FourCorner, Grade, Kunyomi, Kuten, Moro, Oneill,
+    PinYin, QueryCode, Radical, Reading, Reference, ShDesc, Skip, Variant,
+};
+use roxmltree::Node;
+
+/// A KANJIDIC2 element that can be parsed on its own from its XML node,
+/// so that downstream code can parse any element generically.
+/// Each implementation defers to the parsing function of its module,
+/// such as [`kunyomi::from`] for a `<reading r_type="ja_kun">` node.
+pub trait FromKanjidicNode: Sized {
+    /// The error for a node that is not a valid element.
+    type Error: std::error::Error;
+
+    /// Parses the element from its node.
+    fn from_node(node: Node) -> Result<Self, Self::Error>;
+}
+
+impl FromKanjidicNode for BusyPeople {
+    type Error = busy_people::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        busy_people::from(node)
+    }
+}
+
+impl FromKanjidicNode for Character {
+    type Error = CharacterError;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        character::from(node)
+    }
+}
+
+impl FromKanjidicNode for Codepoint {
+    type Error = codepoint::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        codepoint::from(node)
+    }
+}
+
+impl FromKanjidicNode for DeRoo {
+    type Error = de_roo::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        de_roo::from(node)
+    }
+}
+
+impl FromKanjidicNode for FourCorner {
+    type Error = four_corner::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        four_corner::from(node)
+    }
+}
+
+impl FromKanjidicNode for Grade {
+    type Error = grade::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        grade::from(node)
+    }
+}
+
+impl FromKanjidicNode for Kunyomi {
+    type Error = kunyomi::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        kunyomi::from(node)
+    }
+}
+
+impl FromKanjidicNode for Kuten {
+    type Error = kuten::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        kuten::from(node)
+    }
+}
+
+impl FromKanjidicNode for Moro {
+    type Error = moro::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        moro::from(node)
+    }
+}
+
+impl FromKanjidicNode for Oneill {
+    type Error = oneill::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        oneill::from(node)
+    }
+}
+
+impl FromKanjidicNode for PinYin {
+    type Error = pin_yin::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        pin_yin::from(node)
+    }
+}
+
+impl FromKanjidicNode for QueryCode {
+    type Error = query_code::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        query_code::from(node)
+    }
+}
+
+impl FromKanjidicNode for Radical {
+    type Error = radical::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        radical::from(node)
+    }
+}
+
+impl FromKanjidicNode for Reading {
+    type Error = reading::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        reading::from(node)
+    }
+}
+
+impl FromKanjidicNode for Reference {
+    type Error = reference::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        reference::from(node)
+    }
+}
+
+impl FromKanjidicNode for Skip {
+    type Error = SkipError;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        skip::from(node)
+    }
+}
+
+impl FromKanjidicNode for ShDesc {
+    type Error = ShError;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        spahn_hadamitzky::from(node)
+    }
+}
+
+impl FromKanjidicNode for Variant {
+    type Error = variant::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        variant::from(node)
+    }
+}
+
+impl FromKanjidicNode for Header {
+    type Error = header::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        header::from(node)
+    }
+}
+
+impl FromKanjidicNode for DatabaseVersion {
+    type Error = database_version::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        database_version::from(node)
+    }
+}
+
+impl FromKanjidicNode for DateOfCreation {
+    type Error = date_of_creation::Error;
+
+    fn from_node(node: Node) -> Result<Self, Self::Error> {
+        date_of_creation::from(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromKanjidicNode;
+    use crate::{character, header::Header, test_shared::DOC};
+    use kanjidic_types::{Character, Grade, QueryCode, Reading};
+
+    fn first<T: FromKanjidicNode>(tag: &str) -> Result<T, T::Error> {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name(tag))
+            .unwrap();
+        T::from_node(node)
+    }
+
+    #[test]
+    fn generic_parsing() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("character"))
+            .unwrap();
+        assert_eq!(first::<Character>("character"), character::from(node));
+        assert_eq!(first::<Grade>("grade"), Ok(Grade::Jouyou));
+        assert!(matches!(
+            first::<Reading>("reading"),
+            Ok(Reading::PinYin(_))
+        ));
+        assert!(first::<QueryCode>("q_code").is_ok());
+        assert!(first::<Header>("header").is_ok());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::shared::{text_uint, SharedError};
+use crate::{
+    error_code::ErrorCode,
+    pos_error::PosError,
+    shared::{text_uint, SharedError},
+};
 use kanjidic_types::Grade;
 use roxmltree::Node;
 
@@ -6,19 +10,23 @@ use roxmltree::Node;
 pub enum Error {
     #[error("(Grade) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Grade) {0} is not a recognized grade level")]
-    Unrecognized(u8),
+    #[error("(Grade) {1} is not a recognized grade level: {0}")]
+    Unrecognized(PosError, u8),
+}
+
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Unrecognized(_, _) => ErrorCode::E0109_UNKNOWN_GRADE,
+        }
+    }
 }
 
 pub fn from(node: Node) -> Result<Grade, Error> {
     let n: u8 = text_uint(&node)?;
-    match n {
-        1..=6 => Ok(Grade::Kyouiku(n)),
-        8 => Ok(Grade::Jouyou),
-        9 => Ok(Grade::Jinmeiyou),
-        10 => Ok(Grade::JinmeiyouJouyouVariant),
-        n => Err(Error::Unrecognized(n)),
-    }
+    Grade::from_raw(n).ok_or_else(|| Error::Unrecognized(PosError::from(&node), n))
 }
 
 #[cfg(test)]
@@ -36,4 +44,49 @@ mod tests {
         let grade = from(node);
         assert_eq!(grade, Ok(Grade::Jouyou))
     }
+
+    #[test]
+    fn curriculum_order() {
+        let mut grades = vec![
+            Grade::Jinmeiyou,
+            Grade::Jouyou,
+            Grade::Kyouiku(6),
+            Grade::JinmeiyouJouyouVariant,
+            Grade::Kyouiku(1),
+        ];
+        grades.sort();
+        assert_eq!(
+            grades,
+            vec![
+                Grade::Kyouiku(1),
+                Grade::Kyouiku(6),
+                Grade::Jouyou,
+                Grade::Jinmeiyou,
+                Grade::JinmeiyouJouyouVariant,
+            ]
+        );
+    }
+
+    #[test]
+    fn raw() {
+        for n in 0..=11 {
+            match Grade::from_raw(n) {
+                Some(grade) => assert_eq!(grade.to_raw(), n),
+                None => assert!(matches!(n, 0 | 7 | 11)),
+            }
+        }
+        assert_eq!(Grade::from_raw(8), Some(Grade::Jouyou));
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(Grade::Kyouiku(3).school_year(), Some(3));
+        assert_eq!(Grade::Jouyou.school_year(), None);
+        assert!(Grade::Kyouiku(3).is_jouyou());
+        assert!(Grade::Jouyou.is_jouyou());
+        assert!(!Grade::Jinmeiyou.is_jouyou());
+        assert!(Grade::JinmeiyouJouyouVariant.is_jinmeiyou());
+        assert_eq!(Grade::Kyouiku(3).to_string(), "Kyouiku grade 3");
+        assert_eq!(Grade::Jouyou.to_string(), "Jouyou");
+    }
 }
@@ -0,0 +1,173 @@
+use crate::{
+    db::{KanjidicDb, QueryFilter},
+    kunyomi,
+};
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema};
+use kanjidic_types::{Character, Grade, Language};
+
+/// The schema serving a [`KanjidicDb`].
+pub type KanjidicSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Builds a schema that answers queries from the given database.
+pub fn schema(db: KanjidicDb) -> KanjidicSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(db)
+        .finish()
+}
+
+/// The entry points for reading the dictionary.
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// The character with the given literal.
+    async fn kanji(&self, ctx: &Context<'_>, literal: String) -> Option<Kanji> {
+        let literal = literal.chars().next()?;
+        db(ctx).get(literal).map(Kanji::from)
+    }
+
+    /// The characters with the given on'yomi or kun'yomi.
+    async fn by_reading(&self, ctx: &Context<'_>, reading: String) -> Vec<Kanji> {
+        convert(db(ctx).by_reading(&reading))
+    }
+
+    /// The characters with a meaning matching the query, best matches first.
+    async fn search_meanings(
+        &self,
+        ctx: &Context<'_>,
+        query: String,
+        #[graphql(default = "en")] language: String,
+    ) -> Vec<Kanji> {
+        db(ctx)
+            .search_meanings(&query, &Language::from(language))
+            .into_iter()
+            .map(|found| Kanji::from(found.character))
+            .collect()
+    }
+
+    /// The characters matching all of the given conditions.
+    #[allow(clippy::too_many_arguments)]
+    async fn filter(
+        &self,
+        ctx: &Context<'_>,
+        grade: Option<u8>,
+        jlpt: Option<u8>,
+        min_strokes: Option<u8>,
+        max_strokes: Option<u8>,
+        frequency_below: Option<u16>,
+        component: Option<String>,
+    ) -> Vec<Kanji> {
+        let filter = QueryFilter {
+            grade,
+            jlpt,
+            min_strokes,
+            max_strokes,
+            frequency_below,
+            component: component.and_then(|component| component.chars().next()),
+        };
+        convert(db(ctx).query_with(&filter).collect())
+    }
+}
+
+/// A character in the dictionary.
+pub struct Kanji(Character);
+
+impl From<&Character> for Kanji {
+    fn from(character: &Character) -> Self {
+        Self(character.clone())
+    }
+}
+
+#[Object]
+impl Kanji {
+    /// The character itself.
+    async fn literal(&self) -> String {
+        self.0.literal_str().into_owned()
+    }
+
+    /// The grade, using the numbering from KANJIDIC2.
+    async fn grade(&self) -> Option<u8> {
+        self.0.grade.map(Grade::to_raw)
+    }
+
+    /// The accepted stroke count.
+    async fn strokes(&self) -> u8 {
+        self.0.stroke_counts.accepted
+    }
+
+    /// The newspaper frequency ranking.
+    async fn frequency(&self) -> Option<u16> {
+        self.0.frequency
+    }
+
+    /// The old JLPT level.
+    async fn jlpt(&self) -> Option<u8> {
+        self.0.jlpt
+    }
+
+    /// The on'yomi in katakana.
+    async fn onyomi(&self) -> Vec<&str> {
+        self.0.onyomi().collect()
+    }
+
+    /// The kun'yomi, with okurigana after a period.
+    async fn kunyomi(&self) -> Vec<String> {
+        self.0.kunyomi().map(kunyomi::text).collect()
+    }
+
+    /// Readings used in names.
+    async fn nanori(&self) -> &[String] {
+        &self.0.nanori
+    }
+
+    /// The meanings in the given language.
+    async fn meanings(&self, #[graphql(default = "en")] language: String) -> &[String] {
+        self.0.meanings(&language).unwrap_or_default()
+    }
+
+    /// The constituent radicals.
+    async fn components(&self) -> Vec<String> {
+        self.0
+            .decomposition
+            .iter()
+            .map(|component| component.to_string())
+            .collect()
+    }
+}
+
+fn db<'a>(ctx: &Context<'a>) -> &'a KanjidicDb {
+    ctx.data_unchecked::<KanjidicDb>()
+}
+
+fn convert(characters: Vec<&Character>) -> Vec<Kanji> {
+    characters.into_iter().map(Kanji::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::schema;
+    use crate::{db::KanjidicDb, test_shared::decomposed};
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    #[test]
+    fn resolves() {
+        let schema = schema(KanjidicDb::new(decomposed()));
+        let response = block_on(schema.execute(
+            r#"{
+                kanji(literal: "亜") { literal grade strokes onyomi }
+                filter(maxStrokes: 7, component: "口") { literal }
+            }"#,
+        ));
+        assert!(response.errors.is_empty());
+        let data = response.data.into_json().unwrap();
+        assert_eq!(
+            data["kanji"],
+            json!({ "literal": "亜", "grade": 8, "strokes": 7, "onyomi": ["ア"] })
+        );
+        assert!(data["filter"]
+            .as_array()
+            .unwrap()
+            .contains(&json!({ "literal": "亜" })));
+    }
+}
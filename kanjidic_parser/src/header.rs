@@ -1,11 +1,12 @@
 use crate::{
     database_version::{self, DatabaseVersion},
     date_of_creation::{self, DateOfCreation},
+    error_code::ErrorCode,
+    pos_error::PosError,
     shared::{text_uint, SharedError},
 };
 use roxmltree::Node;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
 
 /// Error while parsing the header.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
@@ -16,12 +17,41 @@ pub enum Error {
     DateOfCreation(#[from] date_of_creation::Error),
     #[error("(Header) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Header) Incomplete header provided")]
-    Incomplete,
+    #[error("(Header) Incomplete header provided: {0}")]
+    Incomplete(PosError),
+}
+
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::DatabaseVersion(err) => err.code(),
+            Error::DateOfCreation(err) => err.code(),
+            Error::Shared(err) => err.code(),
+            Error::Incomplete(_) => ErrorCode::E0201_INCOMPLETE_HEADER,
+        }
+    }
+
+    /// Where in the document the failure happened.
+    pub fn position(&self) -> &PosError {
+        match self {
+            Error::DatabaseVersion(err) => err.position(),
+            Error::DateOfCreation(err) => err.position(),
+            Error::Shared(err) => err.position(),
+            Error::Incomplete(pos) => pos,
+        }
+    }
 }
 
 /// Contains identification information about the version of the file.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "snapshot", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Header {
     /// Denotes the version of the database structure.
     pub file_version: u8,
@@ -46,10 +76,11 @@ impl HeaderBuilder {
         }
     }
 
-    pub fn build(self) -> Result<Header, Error> {
-        let database_version = self.database_version.ok_or(Error::Incomplete)?;
-        let date_of_creation = self.date_of_creation.ok_or(Error::Incomplete)?;
-        let file_version = self.file_version.ok_or(Error::Incomplete)?;
+    pub fn build(self, node: &Node) -> Result<Header, Error> {
+        let incomplete = || Error::Incomplete(PosError::from(node));
+        let database_version = self.database_version.ok_or_else(incomplete)?;
+        let date_of_creation = self.date_of_creation.ok_or_else(incomplete)?;
+        let file_version = self.file_version.ok_or_else(incomplete)?;
 
         Ok(Header {
             database_version,
@@ -59,36 +90,31 @@ impl HeaderBuilder {
     }
 }
 
-impl<'a, 'input> TryFrom<Node<'a, 'input>> for Header {
-    type Error = Error;
-
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
-        let mut builder = HeaderBuilder::new();
-        for child in node.children() {
-            match child.tag_name().name() {
-                "database_version" => {
-                    builder.database_version = Some(DatabaseVersion::try_from(child)?);
-                }
-                "date_of_creation" => {
-                    builder.date_of_creation = Some(DateOfCreation::try_from(child)?);
-                }
-                "file_version" => {
-                    builder.file_version = Some(text_uint(&child)?);
-                }
-                _ => {}
+pub fn from(node: Node) -> Result<Header, Error> {
+    let mut builder = HeaderBuilder::new();
+    for child in node.children() {
+        match child.tag_name().name() {
+            "database_version" => {
+                builder.database_version = Some(database_version::from(child)?);
+            }
+            "date_of_creation" => {
+                builder.date_of_creation = Some(date_of_creation::from(child)?);
+            }
+            "file_version" => {
+                builder.file_version = Some(text_uint(&child)?);
             }
+            _ => {}
         }
-        builder.build()
     }
+    builder.build(&node)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Header;
+    use super::{from, Header};
     use crate::{
         database_version::DatabaseVersion, date_of_creation::DateOfCreation, test_shared::DOC,
     };
-    use std::convert::TryFrom;
 
     #[test]
     fn parses_header() {
@@ -96,7 +122,7 @@ mod tests {
             .descendants()
             .find(|node| node.has_tag_name("header"))
             .unwrap();
-        let header = Header::try_from(node);
+        let header = from(node);
         assert_eq!(
             header,
             Ok(Header {
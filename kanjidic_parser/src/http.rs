@@ -0,0 +1,131 @@
+use crate::db::{KanjidicDb, QueryFilter};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use kanjidic_types::Language;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// The parameters of the `/search` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchParams {
+    /// An on'yomi or kun'yomi to look up.
+    pub reading: Option<String>,
+    /// A meaning to search for.
+    pub meaning: Option<String>,
+    /// The language of the meaning, English by default.
+    pub language: Option<String>,
+}
+
+/// Builds a router serving the characters in the database as JSON:
+///
+/// - `GET /kanji/{literal}` gets a single character.
+/// - `GET /search?reading=` gets the characters with a reading.
+/// - `GET /search?meaning=&language=` gets the characters with a matching meaning, best first.
+/// - `GET /query?grade=&jlpt=&minStrokes=&maxStrokes=&frequencyBelow=&component=`
+///   gets the characters matching a [`QueryFilter`].
+///
+/// The router can be served on its own or nested into a larger application.
+pub fn router(db: Arc<KanjidicDb>) -> Router {
+    Router::new()
+        .route("/kanji/{literal}", get(kanji))
+        .route("/search", get(search))
+        .route("/query", get(query))
+        .with_state(db)
+}
+
+async fn kanji(State(db): State<Arc<KanjidicDb>>, Path(literal): Path<String>) -> Response {
+    let character = literal.chars().next().and_then(|literal| db.get(literal));
+    match character {
+        Some(character) => Json(character).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn search(State(db): State<Arc<KanjidicDb>>, Query(params): Query<SearchParams>) -> Response {
+    match (params.reading, params.meaning) {
+        (Some(reading), None) => Json(db.by_reading(&reading)).into_response(),
+        (None, Some(meaning)) => {
+            let language = params.language.map_or(Language::En, Language::from);
+            let characters: Vec<_> = db
+                .search_meanings(&meaning, &language)
+                .into_iter()
+                .map(|found| found.character)
+                .collect();
+            Json(characters).into_response()
+        }
+        _ => (
+            StatusCode::BAD_REQUEST,
+            "Expected exactly one of reading or meaning",
+        )
+            .into_response(),
+    }
+}
+
+async fn query(State(db): State<Arc<KanjidicDb>>, Query(filter): Query<QueryFilter>) -> Response {
+    Json(db.query_with(&filter).collect()).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::router;
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Request, StatusCode},
+    };
+    use futures::executor::block_on;
+    use kanjidic_types::Character;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn get(uri: &str) -> (StatusCode, Vec<u8>) {
+        let router = router(Arc::new(KanjidicDb::new(CHARACTERS.clone())));
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        block_on(async {
+            let response = router.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, body.to_vec())
+        })
+    }
+
+    #[test]
+    fn kanji() {
+        let (status, body) = get("/kanji/%E4%BA%9C");
+        assert_eq!(status, StatusCode::OK);
+        let character: Character = serde_json::from_slice(&body).unwrap();
+        assert_eq!(character, CHARACTERS[0]);
+        assert_eq!(get("/kanji/a").0, StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn search() {
+        let (status, body) = get("/search?reading=%E3%82%A2");
+        assert_eq!(status, StatusCode::OK);
+        let characters: Vec<Character> = serde_json::from_slice(&body).unwrap();
+        assert!(characters.iter().any(|character| character.literal == '亜'));
+
+        let (status, body) = get("/search?meaning=Asia");
+        assert_eq!(status, StatusCode::OK);
+        let characters: Vec<Character> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(characters[0].literal, '亜');
+
+        assert_eq!(get("/search").0, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn query() {
+        let (status, body) = get("/query?grade=8&maxStrokes=7");
+        assert_eq!(status, StatusCode::OK);
+        let characters: Vec<Character> = serde_json::from_slice(&body).unwrap();
+        assert!(characters.iter().any(|character| character.literal == '亜'));
+        assert!(characters
+            .iter()
+            .all(|character| character.stroke_counts.accepted <= 7));
+    }
+}
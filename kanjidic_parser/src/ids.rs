@@ -0,0 +1,238 @@
+use crate::character::string_to_char;
+use kanjidic_types::Character;
+use std::collections::HashMap;
+
+/// An Ideographic Description Character, which arranges the components that follow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Operator {
+    /// ⿰
+    LeftToRight,
+    /// ⿱
+    AboveToBelow,
+    /// ⿲
+    LeftToMiddleAndRight,
+    /// ⿳
+    AboveToMiddleAndBelow,
+    /// ⿴
+    FullSurround,
+    /// ⿵
+    SurroundFromAbove,
+    /// ⿶
+    SurroundFromBelow,
+    /// ⿷
+    SurroundFromLeft,
+    /// ⿸
+    SurroundFromUpperLeft,
+    /// ⿹
+    SurroundFromUpperRight,
+    /// ⿺
+    SurroundFromLowerLeft,
+    /// ⿻
+    Overlaid,
+}
+
+impl Operator {
+    /// Gets the operator for an Ideographic Description Character.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '⿰' => Some(Self::LeftToRight),
+            '⿱' => Some(Self::AboveToBelow),
+            '⿲' => Some(Self::LeftToMiddleAndRight),
+            '⿳' => Some(Self::AboveToMiddleAndBelow),
+            '⿴' => Some(Self::FullSurround),
+            '⿵' => Some(Self::SurroundFromAbove),
+            '⿶' => Some(Self::SurroundFromBelow),
+            '⿷' => Some(Self::SurroundFromLeft),
+            '⿸' => Some(Self::SurroundFromUpperLeft),
+            '⿹' => Some(Self::SurroundFromUpperRight),
+            '⿺' => Some(Self::SurroundFromLowerLeft),
+            '⿻' => Some(Self::Overlaid),
+            _ => None,
+        }
+    }
+
+    /// The Ideographic Description Character for the operator.
+    pub fn to_char(self) -> char {
+        match self {
+            Self::LeftToRight => '⿰',
+            Self::AboveToBelow => '⿱',
+            Self::LeftToMiddleAndRight => '⿲',
+            Self::AboveToMiddleAndBelow => '⿳',
+            Self::FullSurround => '⿴',
+            Self::SurroundFromAbove => '⿵',
+            Self::SurroundFromBelow => '⿶',
+            Self::SurroundFromLeft => '⿷',
+            Self::SurroundFromUpperLeft => '⿸',
+            Self::SurroundFromUpperRight => '⿹',
+            Self::SurroundFromLowerLeft => '⿺',
+            Self::Overlaid => '⿻',
+        }
+    }
+
+    /// The number of components the operator arranges.
+    pub fn arity(self) -> usize {
+        match self {
+            Self::LeftToMiddleAndRight | Self::AboveToMiddleAndBelow => 3,
+            _ => 2,
+        }
+    }
+}
+
+/// Ideographic Description Sequences for each character,
+/// such as `⿱一⿻口丨` for 亜.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IdsDatabase {
+    sequences: HashMap<char, Vec<String>>,
+}
+
+impl IdsDatabase {
+    /// Reads a dataset in the tab-separated layout of the CHISE and cjkvi `ids.txt` files,
+    /// where each line holds a codepoint, the character, and one or more sequences.
+    /// Source region tags such as `[GTJ]` are dropped.
+    pub fn from_ids_txt(contents: &str) -> Self {
+        let sequences = contents
+            .lines()
+            .filter(|line| !line.starts_with('#') && !line.starts_with(';'))
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                let _codepoint = fields.next()?;
+                let literal = string_to_char(fields.next()?)?;
+                let sequences: Vec<String> = fields
+                    .map(|field| match field.find('[') {
+                        Some(end) => &field[..end],
+                        None => field,
+                    })
+                    .filter(|sequence| is_decomposed(sequence))
+                    .map(str::to_owned)
+                    .collect();
+                if sequences.is_empty() {
+                    None
+                } else {
+                    Some((literal, sequences))
+                }
+            })
+            .collect();
+        Self { sequences }
+    }
+
+    /// The preferred sequence for the character.
+    pub fn get(&self, literal: char) -> Option<&str> {
+        self.all(literal).first().map(String::as_str)
+    }
+
+    /// Every known sequence for the character, including regional variants.
+    pub fn all(&self, literal: char) -> &[String] {
+        self.sequences.get(&literal).map_or(&[], Vec::as_slice)
+    }
+
+    /// Pairs each character with its preferred sequence, skipping characters without one.
+    pub fn for_characters<'a>(
+        &'a self,
+        characters: &'a [Character],
+    ) -> impl Iterator<Item = (char, &'a str)> + 'a {
+        characters.iter().filter_map(move |character| {
+            self.get(character.literal)
+                .map(|sequence| (character.literal, sequence))
+        })
+    }
+
+    /// Adds the sequences from another dataset,
+    /// after any that are already known for the same character.
+    pub fn merge(&mut self, other: IdsDatabase) {
+        for (literal, sequences) in other.sequences {
+            let known = self.sequences.entry(literal).or_default();
+            for sequence in sequences {
+                if !known.contains(&sequence) {
+                    known.push(sequence);
+                }
+            }
+        }
+    }
+
+    /// Fills in the preferred sequence of each character that has one.
+    pub fn apply(&self, characters: &mut [Character]) {
+        for character in characters {
+            if let Some(sequence) = self.get(character.literal) {
+                character.ids = Some(sequence.to_owned());
+            }
+        }
+    }
+
+    /// The number of characters with a sequence.
+    pub fn len(&self) -> usize {
+        self.sequences.len()
+    }
+
+    /// Whether there are no sequences.
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty()
+    }
+}
+
+/// Whether the sequence is well formed and breaks the character into parts,
+/// rather than repeating an atomic character.
+/// Entity references such as `&CDP-8BF1;` stand for a single component.
+fn is_decomposed(sequence: &str) -> bool {
+    let mut expected = 1usize;
+    let mut chars = sequence.chars();
+    while let Some(c) = chars.next() {
+        if expected == 0 {
+            return false;
+        }
+        expected -= 1;
+        if c == '&' && !chars.by_ref().any(|c| c == ';') {
+            return false;
+        }
+        if let Some(operator) = Operator::from_char(c) {
+            expected += operator.arity();
+        }
+    }
+    expected == 0
+        && sequence
+            .chars()
+            .next()
+            .and_then(Operator::from_char)
+            .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IdsDatabase, Operator};
+    use crate::test_shared::CHARACTERS;
+
+    const IDS: &str = "# comment\n\
+        U+4E00\t一\t一\n\
+        U+4E9C\t亜\t⿱一⿻口丨\n\
+        U+5516\t唖\t⿰口亜[GTJ]\t⿰口亞[K]\n\
+        U+5A03\t娃\t⿰女\n\
+        U+5A1F\t娟\t⿰女&CDP-8BF1;\n";
+
+    #[test]
+    fn sequences() {
+        let ids = IdsDatabase::from_ids_txt(IDS);
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids.get('娟'), Some("⿰女&CDP-8BF1;"));
+        assert_eq!(ids.get('亜'), Some("⿱一⿻口丨"));
+        assert_eq!(ids.all('唖'), ["⿰口亜", "⿰口亞"]);
+        assert_eq!(ids.get('一'), None);
+        assert_eq!(ids.get('娃'), None);
+    }
+
+    #[test]
+    fn characters() {
+        let ids = IdsDatabase::from_ids_txt(IDS);
+        let found: Vec<_> = ids.for_characters(&CHARACTERS).collect();
+        assert!(found.contains(&('亜', "⿱一⿻口丨")));
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            Operator::from_char('⿲'),
+            Some(Operator::LeftToMiddleAndRight)
+        );
+        assert_eq!(Operator::LeftToMiddleAndRight.arity(), 3);
+        assert_eq!(Operator::Overlaid.to_char(), '⿻');
+        assert_eq!(Operator::from_char('口'), None);
+    }
+}
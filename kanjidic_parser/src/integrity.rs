@@ -0,0 +1,221 @@
+use crate::kanjidic::Kanjidic;
+use kanjidic_types::{hangul, Character, Codepoint, QueryCode, Reading, Reference, Variant};
+use std::collections::HashSet;
+
+/// A cross-reference from a character that nothing in the dictionary answers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dangling {
+    /// A variant whose codepoint or index number no character has.
+    Variant { literal: char, variant: Variant },
+    /// A decomposition component that is a kanji missing from the dictionary.
+    Component { literal: char, component: char },
+}
+
+/// Finds the variants and decomposition components that do not resolve to a character.
+/// Encoded variants must match a character's codepoint, and indexed variants such as
+/// `Variant::Nelson` must match the corresponding dictionary reference or query code.
+/// Components only need to resolve when they are CJK ideographs, since decompositions
+/// also use radical forms and katakana that are not dictionary entries.
+pub fn dangling(characters: &[Character]) -> Vec<Dangling> {
+    let literals: HashSet<char> = characters
+        .iter()
+        .map(|character| character.literal)
+        .collect();
+    let codepoints: HashSet<Codepoint> = characters
+        .iter()
+        .flat_map(|character| character.codepoints.iter().copied())
+        .collect();
+    let references: HashSet<Reference> = characters
+        .iter()
+        .flat_map(|character| character.references.iter().copied())
+        .collect();
+    let query_codes: HashSet<QueryCode> = characters
+        .iter()
+        .flat_map(|character| character.query_codes.iter().copied())
+        .collect();
+    let resolves = |variant: &Variant| match *variant {
+        Variant::Jis208(kuten) => codepoints.contains(&Codepoint::Jis208(kuten)),
+        Variant::Jis212(kuten) => codepoints.contains(&Codepoint::Jis212(kuten)),
+        Variant::Jis213(kuten) => codepoints.contains(&Codepoint::Jis213(kuten)),
+        Variant::Unicode(unicode) => codepoints.contains(&Codepoint::Unicode(unicode)),
+        Variant::DeRoo(de_roo) => query_codes.contains(&QueryCode::DeRoo(de_roo)),
+        Variant::Halpern(index) => references.contains(&Reference::Njecd(index)),
+        Variant::SpahnHadamitzky(descriptor) => {
+            query_codes.contains(&QueryCode::SpahnHadamitzky(descriptor))
+        }
+        Variant::Nelson(index) => references.contains(&Reference::NelsonClassic(index)),
+        Variant::ONeill(oneill) => references.contains(&Reference::OneillNames(oneill)),
+    };
+    let mut dangling = vec![];
+    for character in characters {
+        let literal = character.literal;
+        for variant in &character.variants {
+            if !resolves(variant) {
+                dangling.push(Dangling::Variant {
+                    literal,
+                    variant: *variant,
+                });
+            }
+        }
+        for &component in &character.decomposition {
+            if is_ideograph(component) && !literals.contains(&component) {
+                dangling.push(Dangling::Component { literal, component });
+            }
+        }
+    }
+    dangling
+}
+
+/// Whether the character is in the CJK Unified Ideographs block or one of its extensions.
+fn is_ideograph(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{20000}'..='\u{2A6DF}'
+            | '\u{2A700}'..='\u{2EBEF}'
+            | '\u{30000}'..='\u{3134F}'
+    )
+}
+
+/// A Korean reading that the character does not also give in its other script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KoreanMismatch {
+    pub literal: char,
+    /// The Hangul or romanized reading without a counterpart.
+    pub reading: Reading,
+}
+
+/// Finds the Korean readings whose Hangul and romanized forms disagree.
+/// Each Hangul reading must have a romanized reading that spells it, as judged by
+/// [`hangul::romanization_matches`], and each romanized reading must spell a Hangul reading.
+/// Characters that only give their Korean readings in one script are not checked.
+pub fn korean_mismatches(characters: &[Character]) -> Vec<KoreanMismatch> {
+    let mut mismatches = vec![];
+    for character in characters {
+        let mut hangul_readings = vec![];
+        let mut romanized_readings = vec![];
+        for reading in &character.readings {
+            match reading {
+                Reading::KoreanHangul(text) => hangul_readings.push(text.as_str()),
+                Reading::KoreanRomanized(text) => romanized_readings.push(text.as_str()),
+                _ => {}
+            }
+        }
+        if hangul_readings.is_empty() || romanized_readings.is_empty() {
+            continue;
+        }
+        for reading in &character.readings {
+            let matched = match reading {
+                Reading::KoreanHangul(text) => romanized_readings
+                    .iter()
+                    .any(|romanized| hangul::romanization_matches(romanized, text)),
+                Reading::KoreanRomanized(text) => hangul_readings
+                    .iter()
+                    .any(|written| hangul::romanization_matches(text, written)),
+                _ => true,
+            };
+            if !matched {
+                mismatches.push(KoreanMismatch {
+                    literal: character.literal,
+                    reading: reading.clone(),
+                });
+            }
+        }
+    }
+    mismatches
+}
+
+impl Kanjidic {
+    /// Finds the cross-references that do not resolve, as described in [`dangling`].
+    pub fn dangling_references(&self) -> Vec<Dangling> {
+        dangling(&self.characters)
+    }
+
+    /// Finds the Korean readings whose two scripts disagree, as described in [`korean_mismatches`].
+    pub fn korean_mismatches(&self) -> Vec<KoreanMismatch> {
+        korean_mismatches(&self.characters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dangling, korean_mismatches, Dangling, KoreanMismatch};
+    use crate::test_shared::CHARACTERS;
+    use kanjidic_types::{Reading, Variant};
+
+    #[test]
+    fn resolved() {
+        let mut characters = CHARACTERS[..2].to_vec();
+        characters[1].variants.clear();
+        characters[1].decomposition.clear();
+        characters[0].variants = vec![Variant::Unicode(characters[1].literal as u32)];
+        characters[0].decomposition = vec!['｜', characters[1].literal];
+        assert_eq!(dangling(&characters), vec![]);
+    }
+
+    #[test]
+    fn unresolved() {
+        let mut characters = CHARACTERS[..1].to_vec();
+        characters[0].variants = vec![Variant::Unicode(0x4E9E), Variant::Nelson(1)];
+        characters[0].decomposition = vec!['｜', '口'];
+        assert_eq!(
+            dangling(&characters),
+            vec![
+                Dangling::Variant {
+                    literal: '亜',
+                    variant: Variant::Unicode(0x4E9E)
+                },
+                Dangling::Variant {
+                    literal: '亜',
+                    variant: Variant::Nelson(1)
+                },
+                Dangling::Component {
+                    literal: '亜',
+                    component: '口'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn korean() {
+        assert_eq!(korean_mismatches(&CHARACTERS), vec![]);
+
+        let mut characters = CHARACTERS.clone();
+        let reading = characters[0]
+            .readings
+            .iter_mut()
+            .find(|reading| matches!(reading, Reading::KoreanRomanized(_)))
+            .unwrap();
+        *reading = Reading::KoreanRomanized("um".to_owned());
+        let mismatches = korean_mismatches(&characters);
+        assert!(mismatches.contains(&KoreanMismatch {
+            literal: '亜',
+            reading: Reading::KoreanRomanized("um".to_owned()),
+        }));
+        assert!(mismatches.contains(&KoreanMismatch {
+            literal: '亜',
+            reading: Reading::KoreanHangul("아".to_owned()),
+        }));
+    }
+
+    #[test]
+    fn korean_conversion() {
+        let hangul = Reading::KoreanHangul("옥".to_owned());
+        assert_eq!(
+            hangul.to_korean_romanized(),
+            Some(Reading::KoreanRomanized("og".to_owned()))
+        );
+        let romanized = Reading::KoreanRomanized("weon".to_owned());
+        assert_eq!(
+            romanized.to_korean_hangul(),
+            Some(Reading::KoreanHangul("원".to_owned()))
+        );
+        assert_eq!(
+            Reading::KoreanRomanized("geub".to_owned()).to_korean_hangul(),
+            Some(Reading::KoreanHangul("급".to_owned()))
+        );
+        assert_eq!(Reading::Onyomi("ア".to_owned()).to_korean_hangul(), None);
+    }
+}
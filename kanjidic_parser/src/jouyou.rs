@@ -0,0 +1,51 @@
+use kanjidic_types::{Character, Grade};
+
+/// The kanji added to the jouyou list in its 2010 revision.
+/// The dictionary's spellings of 𠮟, 塡, 剝, and 頰 are listed alongside them.
+const ADDED_2010: &str = "挨曖宛嵐畏萎椅彙茨咽淫唄鬱怨媛艶旺岡臆俺苛牙瓦楷潰諧崖蓋骸柿顎葛釜鎌韓玩伎亀毀畿臼嗅巾僅錦惧串窟熊詣憬稽隙桁拳鍵舷股虎錮勾梗喉乞傲駒頃痕沙挫采塞埼柵刹拶斬恣摯餌鹿𠮟叱嫉腫呪袖羞蹴憧拭尻芯腎須裾凄醒脊戚煎羨腺詮箋膳狙遡曽爽痩踪捉遜汰唾堆戴誰旦綻緻酎貼嘲捗椎爪鶴諦溺塡填妬賭藤瞳栃頓貪丼那奈梨謎鍋匂虹捻罵剝剥箸氾汎阪斑眉膝肘訃阜蔽餅璧蔑哺蜂貌頰頬睦勃昧枕蜜冥麺冶弥闇喩湧妖瘍沃拉辣藍璃慄侶瞭瑠呂賂弄籠麓脇";
+
+/// The kanji added when the jouyou list replaced the touyou list in 1981.
+const ADDED_1981: &str = "猿凹渦靴稼拐涯垣殻潟喝褐缶頑挟矯襟隅渓蛍嫌洪溝昆崎皿桟傘肢遮蛇酌汁塾尚宵縄壌唇甚据杉斉逝仙栓挿曹槽藻駄濯棚挑眺釣塚漬亭偵泥搭棟洞凸屯把覇漠肌鉢披扉猫頻瓶雰塀泡俸褒朴僕堀磨抹岬妄厄癒悠羅竜戻枠";
+
+/// The year the kanji entered the official list that the jouyou kanji descend from:
+/// 1946 for the original touyou kanji, or 1981 or 2010 for later additions.
+/// Gives `None` for characters outside the current jouyou list.
+pub fn since(character: &Character) -> Option<u16> {
+    match character.grade {
+        Some(Grade::Kyouiku(_)) | Some(Grade::Jouyou) => {}
+        _ => return None,
+    }
+    let literal = character.literal;
+    if ADDED_2010.contains(literal) {
+        Some(2010)
+    } else if ADDED_1981.contains(literal) {
+        Some(1981)
+    } else {
+        Some(1946)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{since, ADDED_1981, ADDED_2010};
+    use crate::test_shared::CHARACTERS;
+    use kanjidic_types::Grade;
+
+    #[test]
+    fn lists() {
+        assert_eq!(ADDED_1981.chars().count(), 95);
+        assert_eq!(ADDED_2010.chars().count(), 196 + 4);
+    }
+
+    #[test]
+    fn years() {
+        let mut character = CHARACTERS[0].clone();
+        assert_eq!(since(&character), Some(1946));
+        character.literal = '猫';
+        assert_eq!(since(&character), Some(1981));
+        character.literal = '嵐';
+        assert_eq!(since(&character), Some(2010));
+        character.grade = Some(Grade::Jinmeiyou);
+        assert_eq!(since(&character), None);
+    }
+}
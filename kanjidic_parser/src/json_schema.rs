@@ -0,0 +1,33 @@
+use crate::kanjidic::Kanjidic;
+use kanjidic_types::Character;
+use schemars::{schema::RootSchema, schema_for};
+
+/// The JSON Schema of a whole dictionary as written by the JSON export.
+pub fn schema() -> RootSchema {
+    schema_for!(Kanjidic)
+}
+
+/// The JSON Schema of a single character.
+pub fn character_schema() -> RootSchema {
+    schema_for!(Character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{character_schema, schema};
+    use crate::test_shared::CHARACTERS;
+
+    #[test]
+    fn describes_output() {
+        let schema = serde_json::to_value(schema()).unwrap();
+        assert!(schema["definitions"]["Character"].is_object());
+        assert!(schema["properties"]["header"].is_object());
+
+        let schema = serde_json::to_value(character_schema()).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        let character = serde_json::to_value(&CHARACTERS[0]).unwrap();
+        for key in character.as_object().unwrap().keys() {
+            assert!(properties.contains_key(key), "{} is not described", key);
+        }
+    }
+}
@@ -1,11 +1,18 @@
 use crate::{
     character,
+    error_code::ErrorCode,
     header::{self, Header},
+    parse_options::ParseOptions,
+    pos_error::PosError,
 };
 use kanjidic_types::Character;
 use roxmltree::{Document, Node};
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{
+    collections::{BTreeMap, HashSet},
+    convert::TryFrom,
+    sync::atomic::Ordering,
+};
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
@@ -13,40 +20,197 @@ pub enum Error {
     Xml(#[from] roxmltree::Error),
     #[error("Could not find header node")]
     MissingHeader,
+    #[error("Could not find the kanjidic2 root element")]
+    MissingRoot,
     #[error("Error parsing database version")]
     Header(#[from] header::Error),
-    #[error("Error parsing a character")]
-    Character(#[from] character::CharacterError),
+    #[error("Error parsing character {1} at {0}: {2}")]
+    Character(PosError, String, character::CharacterError),
+    #[error("Parsing was cancelled")]
+    Cancelled,
+}
+
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Xml(_) => ErrorCode::E0001_INVALID_XML,
+            Error::MissingHeader => ErrorCode::E0003_MISSING_HEADER,
+            Error::MissingRoot => ErrorCode::E0002_MISSING_ROOT,
+            Error::Header(err) => err.code(),
+            Error::Character(_, _, err) => err.code(),
+            Error::Cancelled => ErrorCode::E0004_CANCELLED,
+        }
+    }
+}
+
+/// How many characters a parse has been through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Progress {
+    /// The number of characters parsed so far.
+    pub parsed: usize,
+    /// The number of characters in the document.
+    pub total: usize,
 }
 
+/// Characters that failed to parse, each paired with the index of its element in the file.
+pub type Failures = Vec<(usize, character::CharacterError)>;
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "snapshot", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Kanjidic {
     pub header: Header,
     pub characters: Vec<Character>,
+    /// The sorted literals of characters merged in from the KANJD212 supplement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supplementary: Vec<char>,
+    /// Custom keywords set by [`apply_overrides`](Kanjidic::apply_overrides), keyed by literal.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keywords: BTreeMap<String, String>,
 }
 
-impl<'a> TryFrom<&'a Document<'a>> for Kanjidic {
-    type Error = Error;
+impl Kanjidic {
+    /// Parses the contents of a complete KANJIDIC2 file,
+    /// skipping over the DTD that precedes the dictionary.
+    pub fn from_file_contents(xml: &str) -> Result<Self, Error> {
+        Self::from_file_contents_with(xml, &ParseOptions::default())
+    }
 
-    fn try_from(doc: &'a Document) -> Result<Self, Self::Error> {
+    /// Parses the contents of a complete KANJIDIC2 file,
+    /// skipping the sections of each character that the options exclude.
+    pub fn from_file_contents_with(xml: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let start = xml.find("<kanjidic2>").ok_or(Error::MissingRoot)?;
+        let doc = Document::parse(&xml[start..])?;
+        Self::from_document_with(&doc, options)
+    }
+
+    /// Parses a KANJIDIC2 document,
+    /// skipping the sections of each character that the options exclude.
+    pub fn from_document_with(doc: &Document, options: &ParseOptions) -> Result<Self, Error> {
         let root = doc.root_element();
-        let header = Header::try_from(
+        let header = header::from(
             root.children()
                 .find(|child| child.has_tag_name("header"))
                 .ok_or(Error::MissingHeader)?,
         )?;
-        let characters: Result<Vec<Character>, character::CharacterError> = root
+        let nodes: Vec<Node> = root
             .children()
             .filter(|child| child.has_tag_name("character"))
-            .collect::<Vec<Node>>()
+            .collect();
+        let total = nodes.len();
+        let mut characters = Vec::with_capacity(total);
+        for (i, node) in nodes.into_iter().enumerate() {
+            if is_cancelled(options) {
+                return Err(Error::Cancelled);
+            }
+            let character = character::from_with(node, options).map_err(|err| {
+                Error::Character(PosError::from(&node), literal(&node).to_owned(), err)
+            })?;
+            characters.push(character);
+            if let Some(on_progress) = options.on_progress {
+                on_progress(Progress {
+                    parsed: i + 1,
+                    total,
+                });
+            }
+        }
+        Ok(Self {
+            header,
+            characters,
+            supplementary: vec![],
+            keywords: BTreeMap::new(),
+        })
+    }
+
+    /// Adds characters from the KANJD212 supplement that the dictionary lacks,
+    /// flagging them as supplementary. Returns the number added.
+    pub fn merge_supplement(&mut self, characters: Vec<Character>) -> usize {
+        let mut known: HashSet<String> = self
+            .characters
             .iter()
-            .map(|node| character::from(*node))
-            .collect::<Result<Vec<Character>, character::CharacterError>>();
-        let characters = characters?;
-        Ok(Self { header, characters })
+            .map(|c| c.literal_str().into_owned())
+            .collect();
+        let before = self.characters.len();
+        for character in characters {
+            if known.insert(character.literal_str().into_owned()) {
+                self.supplementary.push(character.literal);
+                self.characters.push(character);
+            }
+        }
+        self.supplementary.sort_unstable();
+        self.characters.len() - before
+    }
+
+    /// Whether the character came from the KANJD212 supplement.
+    pub fn is_supplementary(&self, literal: char) -> bool {
+        self.supplementary.binary_search(&literal).is_ok()
+    }
+
+    /// Parses every character in a complete KANJIDIC2 file,
+    /// keeping the ones that succeed rather than stopping at the first failure.
+    /// Progress and cancellation in the options apply as they do when parsing a whole dictionary.
+    pub fn parse_collecting(
+        xml: &str,
+        options: &ParseOptions,
+    ) -> Result<(Vec<Character>, Failures), Error> {
+        let start = xml.find("<kanjidic2>").ok_or(Error::MissingRoot)?;
+        let doc = Document::parse(&xml[start..])?;
+        let nodes: Vec<Node> = doc
+            .root_element()
+            .children()
+            .filter(|child| child.has_tag_name("character"))
+            .collect();
+        let total = nodes.len();
+        let mut characters = vec![];
+        let mut failures = vec![];
+        for (i, node) in nodes.into_iter().enumerate() {
+            if is_cancelled(options) {
+                return Err(Error::Cancelled);
+            }
+            match character::from_with(node, options) {
+                Ok(character) => characters.push(character),
+                Err(err) => failures.push((i, err)),
+            }
+            if let Some(on_progress) = options.on_progress {
+                on_progress(Progress {
+                    parsed: i + 1,
+                    total,
+                });
+            }
+        }
+        Ok((characters, failures))
+    }
+}
+
+impl<'a> TryFrom<&'a Document<'a>> for Kanjidic {
+    type Error = Error;
+
+    fn try_from(doc: &'a Document) -> Result<Self, Self::Error> {
+        Self::from_document_with(doc, &ParseOptions::default())
     }
 }
 
+fn is_cancelled(options: &ParseOptions) -> bool {
+    options
+        .cancel
+        .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+}
+
+/// The text of the character's literal, for identifying it in errors
+/// even when the literal itself is malformed.
+fn literal<'a>(node: &Node<'a, '_>) -> &'a str {
+    node.children()
+        .find(|child| child.has_tag_name("literal"))
+        .and_then(|literal| literal.text())
+        .unwrap_or("?")
+}
+
 impl TryFrom<&str> for Kanjidic {
     type Error = Error;
 
@@ -58,14 +222,123 @@ impl TryFrom<&str> for Kanjidic {
 
 #[cfg(test)]
 mod tests {
-    use super::Kanjidic;
-    use crate::test_shared::DOC;
+    use super::{Error, Kanjidic, Progress};
+    use crate::{
+        character::CharacterError,
+        error_code::ErrorCode,
+        legacy,
+        parse_options::ParseOptions,
+        test_shared::{DOC, KANJIDIC},
+    };
     use roxmltree::Document;
-    use std::convert::TryFrom;
+    use std::{
+        cell::{Cell, RefCell},
+        convert::TryFrom,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    #[test]
+    fn character_error_location() {
+        let xml = "<kanjidic2>\n\
+            <header><file_version>4</file_version>\
+            <database_version>2021-176</database_version>\
+            <date_of_creation>2021-06-25</date_of_creation></header>\n\
+            <character><literal>亜</literal><misc><grade>7</grade></misc></character>\n\
+            </kanjidic2>";
+        match Kanjidic::try_from(xml) {
+            Err(Error::Character(position, literal, CharacterError::Grade(_))) => {
+                assert_eq!(literal, "亜");
+                assert_eq!(position.position().row, 3);
+                assert_eq!(&xml[position.offset()..][..11], "<character>");
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn collects_failures() {
+        let xml = "<kanjidic2>\n\
+            <character><literal>亜</literal><misc><stroke_count>7</stroke_count></misc></character>\n\
+            <character><literal>唖</literal><misc><grade>7</grade></misc></character>\n\
+            <character><literal>娃</literal><misc><stroke_count>9</stroke_count></misc></character>\n\
+            </kanjidic2>";
+        let (characters, failures) =
+            Kanjidic::parse_collecting(xml, &ParseOptions::default()).unwrap();
+        let literals: Vec<char> = characters.iter().map(|c| c.literal).collect();
+        assert_eq!(literals, vec!['亜', '娃']);
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(failures[0], (1, CharacterError::Grade(_))));
+        assert_eq!(failures[0].1.code(), ErrorCode::E0109_UNKNOWN_GRADE);
+
+        let cancel = AtomicBool::new(true);
+        let options = ParseOptions {
+            cancel: Some(&cancel),
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Kanjidic::parse_collecting(xml, &options),
+            Err(Error::Cancelled)
+        );
+    }
+
+    #[test]
+    fn supplement() {
+        let mut kanjidic = KANJIDIC.clone();
+        let supplement = legacy::parse_supplement(
+            "丂 3021 U4e02 B1 S2 {obstructed}\n亜 3022 U4e9c B1 S7 {Asia}\n",
+        )
+        .unwrap();
+        assert_eq!(kanjidic.merge_supplement(supplement), 1);
+        assert!(kanjidic.is_supplementary('丂'));
+        assert!(!kanjidic.is_supplementary('亜'));
+        assert_eq!(kanjidic.characters.last().unwrap().literal, '丂');
+    }
 
     #[test]
     #[ignore]
     fn kanjidic() {
         let _kanjidic = Kanjidic::try_from(&DOC as &Document).unwrap();
     }
+
+    #[test]
+    fn reports_progress() {
+        let reports = RefCell::new(vec![]);
+        let on_progress = |progress| reports.borrow_mut().push(progress);
+        let options = ParseOptions {
+            on_progress: Some(&on_progress),
+            ..ParseOptions::default()
+        };
+        let kanjidic = Kanjidic::from_document_with(&DOC, &options).unwrap();
+        let reports = reports.into_inner();
+        let total = kanjidic.characters.len();
+        assert_eq!(reports.len(), total);
+        assert_eq!(reports[0], Progress { parsed: 1, total });
+        assert_eq!(
+            reports[total - 1],
+            Progress {
+                parsed: total,
+                total
+            }
+        );
+    }
+
+    #[test]
+    fn cancelled() {
+        let cancel = AtomicBool::new(false);
+        let parsed = Cell::new(0);
+        let on_progress = |progress: Progress| {
+            parsed.set(progress.parsed);
+            if progress.parsed == 10 {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        };
+        let options = ParseOptions {
+            on_progress: Some(&on_progress),
+            cancel: Some(&cancel),
+            ..ParseOptions::default()
+        };
+        let result = Kanjidic::from_document_with(&DOC, &options);
+        assert_eq!(result, Err(Error::Cancelled));
+        assert_eq!(parsed.get(), 10);
+    }
 }
@@ -0,0 +1,166 @@
+use crate::pos_error::PosError;
+use roxmltree::{Document, Node, ParsingOptions};
+use std::{collections::HashMap, ops::Range};
+
+/// The namespace of KanjiVG's `kvg:` attributes.
+pub const NAMESPACE: &str = "http://kanjivg.tagaini.net";
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("(KanjiVG) Xml: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("(KanjiVG) Stroke without path data: {0}")]
+    MissingPath(PosError),
+}
+
+/// The strokes of a character in writing order, grouped by component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrokeData {
+    /// The character the strokes draw.
+    pub literal: char,
+    /// Each stroke in the order it is written.
+    pub strokes: Vec<Stroke>,
+    /// The components of the character, outermost first,
+    /// starting with the group for the whole character.
+    pub groups: Vec<StrokeGroup>,
+}
+
+/// A single stroke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stroke {
+    /// The stroke type, such as `㇐`.
+    pub kind: Option<String>,
+    /// The SVG path data, in KanjiVG's 109 by 109 coordinate space.
+    pub path: String,
+}
+
+/// A component of a character made of consecutive strokes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrokeGroup {
+    /// The character the component resembles.
+    pub element: Option<char>,
+    /// Where the component sits, such as `left` or `top`.
+    pub position: Option<String>,
+    /// The indices of the component's strokes.
+    pub strokes: Range<usize>,
+    /// How many groups enclose this one.
+    pub depth: usize,
+}
+
+/// Reads the stroke data from KanjiVG XML,
+/// either the combined `kanjivg.xml` release or an individual character SVG.
+/// Variant forms such as `04e9c-Kaisho` are skipped.
+pub fn from_xml(xml: &str) -> Result<HashMap<char, StrokeData>, Error> {
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    let doc = Document::parse_with_options(xml, options)?;
+    doc.descendants()
+        .filter(|node| node.has_tag_name("g"))
+        .filter_map(|node| literal(&node).map(|literal| (literal, node)))
+        .map(|(literal, node)| {
+            let mut data = StrokeData {
+                literal,
+                strokes: vec![],
+                groups: vec![],
+            };
+            group(node, 0, &mut data)?;
+            Ok((literal, data))
+        })
+        .collect()
+}
+
+/// The character of a top-level group, whose ID is just its codepoint, like `kvg:04e9c`.
+fn literal(node: &Node) -> Option<char> {
+    let codepoint = node.attribute("id")?.strip_prefix("kvg:")?;
+    if !codepoint.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(codepoint, 16)
+        .ok()
+        .and_then(std::char::from_u32)
+}
+
+fn group(node: Node, depth: usize, data: &mut StrokeData) -> Result<(), Error> {
+    let index = data.groups.len();
+    let start = data.strokes.len();
+    data.groups.push(StrokeGroup {
+        element: node
+            .attribute((NAMESPACE, "element"))
+            .and_then(|element| element.chars().next()),
+        position: node.attribute((NAMESPACE, "position")).map(str::to_owned),
+        strokes: start..start,
+        depth,
+    });
+    for child in node.children() {
+        match child.tag_name().name() {
+            "g" => group(child, depth + 1, data)?,
+            "path" => data.strokes.push(Stroke {
+                kind: child.attribute((NAMESPACE, "type")).map(str::to_owned),
+                path: child
+                    .attribute("d")
+                    .ok_or_else(|| Error::MissingPath(PosError::from(&child)))?
+                    .to_owned(),
+            }),
+            _ => {}
+        }
+    }
+    data.groups[index].strokes.end = data.strokes.len();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_xml;
+
+    const SVG: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:kvg="http://kanjivg.tagaini.net" width="109" height="109" viewBox="0 0 109 109">
+<g id="kvg:StrokePaths_04e00" style="fill:none;stroke:#000000;">
+<g id="kvg:04e00" kvg:element="一" kvg:radical="general">
+	<path id="kvg:04e00-s1" kvg:type="㇐" d="M11,54.25c3.19,0.62,6.25,0.75,9.73,0.5"/>
+</g>
+</g>
+<g id="kvg:StrokeNumbers_04e00"><text transform="matrix(1 0 0 1 4.25 54.13)">1</text></g>
+</svg>"#;
+
+    const NESTED: &str = r#"<kanjivg xmlns:kvg="http://kanjivg.tagaini.net">
+<kanji id="kvg:kanji_04e0b">
+<g id="kvg:04e0b" kvg:element="下">
+	<g id="kvg:04e0b-g1" kvg:element="一" kvg:position="top">
+		<path id="kvg:04e0b-s1" kvg:type="㇐" d="M12,22h83"/>
+	</g>
+	<g id="kvg:04e0b-g2" kvg:element="卜" kvg:position="bottom">
+		<path id="kvg:04e0b-s2" kvg:type="㇑" d="M52,24v75"/>
+		<path id="kvg:04e0b-s3" kvg:type="㇔" d="M55,49l22,12"/>
+	</g>
+</g>
+</kanji>
+<kanji id="kvg:kanji_04e0b-Kaisho">
+<g id="kvg:04e0b-Kaisho" kvg:element="下"></g>
+</kanji>
+</kanjivg>"#;
+
+    #[test]
+    fn single_svg() {
+        let data = from_xml(SVG).unwrap();
+        let one = &data[&'一'];
+        assert_eq!(one.strokes.len(), 1);
+        assert_eq!(one.strokes[0].kind.as_deref(), Some("㇐"));
+        assert_eq!(one.groups[0].element, Some('一'));
+    }
+
+    #[test]
+    fn groups() {
+        let data = from_xml(NESTED).unwrap();
+        assert_eq!(data.len(), 1);
+        let below = &data[&'下'];
+        assert_eq!(below.strokes.len(), 3);
+        assert_eq!(below.groups.len(), 3);
+        assert_eq!(below.groups[0].strokes, 0..3);
+        assert_eq!(below.groups[2].element, Some('卜'));
+        assert_eq!(below.groups[2].position.as_deref(), Some("bottom"));
+        assert_eq!(below.groups[2].strokes, 1..3);
+        assert_eq!(below.groups[2].depth, 1);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::list;
+use std::{collections::HashMap, str::FromStr};
+
+pub use list::Error;
+
+/// A level of the Japan Kanji Aptitude Test, from easiest to hardest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KankenLevel {
+    Ten,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    /// 準2級
+    PreTwo,
+    Two,
+    /// 準1級
+    PreOne,
+    One,
+}
+
+/// A level that is not one of the Kanken's.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("(Kanken) Unrecognized level: {0}")]
+pub struct LevelError(String);
+
+impl FromStr for KankenLevel {
+    type Err = LevelError;
+
+    /// Reads a level written as `3`, `3級`, `準2`, `準2級`, or `pre-2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let level = s.trim().trim_end_matches('級');
+        let (pre, number) = match level
+            .strip_prefix('準')
+            .or_else(|| level.strip_prefix("pre-"))
+        {
+            Some(number) => (true, number),
+            None => (false, level),
+        };
+        match (pre, number) {
+            (false, "10") => Ok(Self::Ten),
+            (false, "9") => Ok(Self::Nine),
+            (false, "8") => Ok(Self::Eight),
+            (false, "7") => Ok(Self::Seven),
+            (false, "6") => Ok(Self::Six),
+            (false, "5") => Ok(Self::Five),
+            (false, "4") => Ok(Self::Four),
+            (false, "3") => Ok(Self::Three),
+            (true, "2") => Ok(Self::PreTwo),
+            (false, "2") => Ok(Self::Two),
+            (true, "1") => Ok(Self::PreOne),
+            (false, "1") => Ok(Self::One),
+            _ => Err(LevelError(s.to_owned())),
+        }
+    }
+}
+
+/// Parses a mapping of kanji to the Kanken level that first tests them,
+/// in the format read by [`list::parse`], such as `亜,準2`.
+pub fn parse(contents: &str) -> Result<HashMap<char, KankenLevel>, Error> {
+    list::parse(contents, list::kanji, |level| level.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Error, KankenLevel};
+
+    #[test]
+    fn levels() {
+        assert_eq!("10".parse::<KankenLevel>(), Ok(KankenLevel::Ten));
+        assert_eq!("準2級".parse::<KankenLevel>(), Ok(KankenLevel::PreTwo));
+        assert_eq!("pre-1".parse::<KankenLevel>(), Ok(KankenLevel::PreOne));
+        assert!("準3".parse::<KankenLevel>().is_err());
+        assert!(KankenLevel::PreTwo < KankenLevel::Two);
+    }
+
+    #[test]
+    fn entries() {
+        let levels = parse("kanji,level\n亜,準2\n一\t10級\n").unwrap();
+        assert_eq!(levels.get(&'亜'), Some(&KankenLevel::PreTwo));
+        assert_eq!(levels.get(&'一'), Some(&KankenLevel::Ten));
+        assert_eq!(parse("亜,準2\n亜,11\n"), Err(Error::Value(2)));
+    }
+}
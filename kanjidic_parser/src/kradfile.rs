@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("(Kradfile) Malformed entry on line {0}")]
+    Malformed(usize),
+    #[error("(Kradfile) File is not valid EUC-JP")]
+    Encoding,
+}
+
+/// Parses the contents of a KRADFILE or KRADFILE2 into the components of each kanji,
+/// where each line looks like `亜 : ｜ 一 口` and comments start with `#`.
+/// The result can be used as a [`crate::decomposition::DecompositionProvider`].
+pub fn parse(contents: &str) -> Result<HashMap<char, Vec<char>>, Error> {
+    let mut decompositions = HashMap::new();
+    for (i, line) in contents.lines().enumerate() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let (kanji, components) = entry(line).ok_or(Error::Malformed(i + 1))?;
+        decompositions.insert(kanji, components);
+    }
+    Ok(decompositions)
+}
+
+fn entry(line: &str) -> Option<(char, Vec<char>)> {
+    let (kanji, components) = line.split_once(':')?;
+    let mut kanji = kanji.trim().chars();
+    let literal = kanji.next()?;
+    if kanji.next().is_some() {
+        return None;
+    }
+    let components = components
+        .split_whitespace()
+        .map(|component| {
+            let mut chars = component.chars();
+            match (chars.next(), chars.next()) {
+                (Some(component), None) => Some(component),
+                _ => None,
+            }
+        })
+        .collect::<Option<Vec<char>>>()?;
+    Some((literal, components))
+}
+
+/// Parses a KRADFILE or KRADFILE2 in the EUC-JP encoding the EDRDG distributes them in.
+#[cfg(feature = "encoding")]
+pub fn parse_euc_jp(bytes: &[u8]) -> Result<HashMap<char, Vec<char>>, Error> {
+    parse(&decode_euc_jp(bytes)?)
+}
+
+/// Decodes EUC-JP text, such as the EDRDG's KRADFILE and RADKFILE releases.
+#[cfg(feature = "encoding")]
+pub fn decode_euc_jp(bytes: &[u8]) -> Result<String, Error> {
+    encoding_rs::EUC_JP
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(|text| text.into_owned())
+        .ok_or(Error::Encoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Error};
+    use crate::decomposition::DecompositionProvider;
+
+    #[test]
+    fn entries() {
+        let kradfile = "# KRADFILE\n亜 : ｜ 一 口\n唖 : ｜ 一 口\n";
+        let decompositions = parse(kradfile).unwrap();
+        assert_eq!(decompositions.len(), 2);
+        assert_eq!(
+            decompositions.components('亜'),
+            Some(&['｜', '一', '口'][..])
+        );
+        assert_eq!(decompositions.components('娃'), None);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(parse("亜 ｜ 一 口\n"), Err(Error::Malformed(1)));
+        assert_eq!(parse("# comment\n亜唖 : 口\n"), Err(Error::Malformed(2)));
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn euc_jp() {
+        // 亜 : 一 in EUC-JP
+        let bytes = [0xb0, 0xa1, b' ', b':', b' ', 0xb0, 0xec, b'\n'];
+        let decompositions = super::parse_euc_jp(&bytes).unwrap();
+        assert_eq!(decompositions[&'亜'], vec!['一']);
+    }
+}
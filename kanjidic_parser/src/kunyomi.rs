@@ -1,6 +1,7 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
-    shared::{text, SharedError},
+    shared::{self, SharedError},
 };
 use kanjidic_types::{kunyomi, Kunyomi};
 use roxmltree::Node;
@@ -14,16 +15,32 @@ pub enum Error {
     Parse(PosError, kunyomi::ParseError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Parse(_, _) => ErrorCode::E0116_INVALID_KUNYOMI,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Kunyomi, Error> {
-    let text = text(&node)?;
+    let text = shared::text(&node)?;
     Kunyomi::try_from(text).map_err(|err| Error::Parse(PosError::from(&node), err))
 }
 
+/// The kunyomi as written in KANJIDIC2, such as `つ.ぐ`.
+pub fn text(kunyomi: &Kunyomi) -> String {
+    kunyomi.to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::from;
-    use crate::test_shared::DOC;
+    use crate::test_shared::{CHARACTERS, DOC};
     use kanjidic_types::{kunyomi::KunyomiKind, Kunyomi};
+    use std::convert::TryFrom;
 
     #[test]
     fn kunyomi() {
@@ -47,4 +64,34 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn inflected_words() {
+        let tsugu = Kunyomi::try_from("つ.ぐ").unwrap();
+        assert!(tsugu.matches_word("つぐ"));
+        assert!(tsugu.matches_word("ついで"));
+        assert!(tsugu.matches_word("つがない"));
+        assert!(tsugu.matches_word("ツギ"));
+        assert!(!tsugu.matches_word("つかない"));
+        assert!(!tsugu.matches_word("あぐ"));
+
+        let taberu = Kunyomi::try_from("た.べる").unwrap();
+        assert!(taberu.matches_word("たべない"));
+        assert!(taberu.matches_word("たべ"));
+        assert!(!taberu.matches_word("たぶ"));
+        assert!(taberu.matches_word("たべさせる"));
+        assert!(!taberu.matches_word("たべく"));
+
+        let atarashii = Kunyomi::try_from("あたら.しい").unwrap();
+        assert!(atarashii.matches_word("あたらしかった"));
+        assert!(atarashii.matches_word("あたらしく"));
+        assert!(!atarashii.matches_word("あたらしね"));
+
+        let yama = Kunyomi::try_from("やま").unwrap();
+        assert!(yama.matches_word("やま"));
+        assert!(!yama.matches_word("やまい"));
+
+        assert!(CHARACTERS[0].kunyomi_matches("ついで"));
+        assert!(!CHARACTERS[0].kunyomi_matches("あ"));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{text, SharedError},
 };
@@ -14,6 +15,16 @@ pub enum Error {
     Parse(PosError, kuten::ParseError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Parse(_, _) => ErrorCode::E0105_INVALID_KUTEN,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Kuten, Error> {
     Kuten::try_from(text(&node)?).map_err(|err| Error::Parse(PosError::from(&node), err))
 }
@@ -21,8 +32,8 @@ pub fn from(node: Node) -> Result<Kuten, Error> {
 #[cfg(test)]
 mod tests {
     use super::from;
-    use crate::test_shared::DOC;
-    use kanjidic_types::Kuten;
+    use crate::test_shared::{CHARACTERS, DOC};
+    use kanjidic_types::{Codepoint, JisStandard, Kuten};
 
     #[test]
     fn parse_kuten() {
@@ -46,4 +57,83 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn unicode_conversion() {
+        let kuten = Kuten {
+            plane: 1,
+            ku: 16,
+            ten: 1,
+        };
+        assert_eq!(kuten.to_unicode(JisStandard::X0208), Some('亜'));
+        assert_eq!(Kuten::from_unicode('亜', JisStandard::X0208), Some(kuten));
+        assert_eq!(Kuten::from_unicode('a', JisStandard::X0208), None);
+        for character in CHARACTERS.iter() {
+            for &codepoint in character.codepoints.iter() {
+                assert_eq!(codepoint.to_unicode(), Some(character.literal));
+                assert!(Codepoint::all_for(character.literal).contains(&codepoint));
+            }
+        }
+    }
+
+    #[test]
+    fn legacy_encodings() {
+        let kuten = Kuten {
+            plane: 1,
+            ku: 16,
+            ten: 1,
+        };
+        assert_eq!(kuten.to_euc_jp(JisStandard::X0208), Some(vec![0xB0, 0xA1]));
+        assert_eq!(
+            kuten.to_euc_jp(JisStandard::X0212),
+            Some(vec![0x8F, 0xB0, 0xA1])
+        );
+        assert_eq!(
+            kuten.to_shift_jis(JisStandard::X0208),
+            Some(vec![0x88, 0x9F])
+        );
+        assert_eq!(kuten.to_shift_jis(JisStandard::X0212), None);
+        let plane_2 = Kuten {
+            plane: 2,
+            ku: 94,
+            ten: 86,
+        };
+        assert_eq!(
+            plane_2.to_shift_jis(JisStandard::X0213),
+            Some(vec![0xFC, 0xF4])
+        );
+        for character in CHARACTERS.iter() {
+            for &codepoint in character.codepoints.iter() {
+                let (kuten, standard) = match codepoint {
+                    Codepoint::Jis208(kuten) => (kuten, JisStandard::X0208),
+                    Codepoint::Jis212(kuten) => (kuten, JisStandard::X0212),
+                    Codepoint::Jis213(kuten) => (kuten, JisStandard::X0213),
+                    Codepoint::Unicode(_) => continue,
+                };
+                let euc_jp = codepoint.to_euc_jp().unwrap();
+                assert_eq!(Kuten::from_euc_jp(&euc_jp, standard), Some(kuten));
+                if let Some(shift_jis) = codepoint.to_shift_jis() {
+                    assert_eq!(Kuten::from_shift_jis(&shift_jis, standard), Some(kuten));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iso_2022_jp() {
+        let kuten = Kuten {
+            plane: 1,
+            ku: 16,
+            ten: 1,
+        };
+        assert_eq!(
+            Codepoint::Jis208(kuten).to_iso_2022_jp(),
+            Some(b"\x1B$B0!\x1B(B".to_vec())
+        );
+        assert_eq!(
+            Codepoint::Jis212(kuten).to_iso_2022_jp(),
+            Some(b"\x1B$(D0!\x1B(B".to_vec())
+        );
+        assert_eq!(Codepoint::Unicode(0x4E9C).to_iso_2022_jp(), None);
+    }
 }
@@ -0,0 +1,473 @@
+use crate::{character::string_to_char, decomposition, moro, parse_options::ParseOptions};
+use kanjidic_types::{
+    character::Translations,
+    moro::MoroSuffix,
+    query_code::{Misclassification, MisclassificationKind},
+    radical::RadicalKind,
+    BusyPeople, Character, Codepoint, DeRoo, FourCorner, Grade, KangXi, Kunyomi, Kuten, Language,
+    Moro, Oneill, PinYin, QueryCode, Radical, Reading, Reference, ShDesc, Skip, StrokeCount,
+    Variant,
+};
+use std::convert::TryFrom;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("(Legacy) Line {0}: {1}")]
+    Line(usize, LineError),
+    #[error("(Legacy) File is not valid EUC-JP")]
+    Encoding,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum LineError {
+    #[error("(Legacy) Missing the {0}")]
+    Missing(&'static str),
+    #[error("(Legacy) Invalid field {0}")]
+    Field(String),
+}
+
+/// Parses a dictionary in the original line-oriented KANJIDIC format,
+/// where each line looks like `亜 3021 U4e9c B1 C7 G8 S7 ... ア つ.ぐ T1 や つぐ {Asia}`.
+/// Comment lines start with `#` or `＃`.
+pub fn parse(contents: &str) -> Result<Vec<Character>, Error> {
+    parse_lines(contents, from_line)
+}
+
+/// Parses the KANJD212 supplement, which has the same layout as KANJIDIC
+/// but identifies each kanji by its JIS X 0212 code.
+pub fn parse_supplement(contents: &str) -> Result<Vec<Character>, Error> {
+    parse_lines(contents, supplement_line)
+}
+
+fn parse_lines(
+    contents: &str,
+    from_line: fn(&str) -> Result<Character, LineError>,
+) -> Result<Vec<Character>, Error> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !(line.starts_with('#') || line.starts_with('＃') || line.is_empty()))
+        .map(|(i, line)| from_line(line).map_err(|err| Error::Line(i + 1, err)))
+        .collect()
+}
+
+/// Parses an original KANJIDIC file in the EUC-JP encoding the EDRDG distributes it in.
+#[cfg(feature = "encoding")]
+pub fn parse_euc_jp(bytes: &[u8]) -> Result<Vec<Character>, Error> {
+    let contents = crate::kradfile::decode_euc_jp(bytes).map_err(|_| Error::Encoding)?;
+    parse(&contents)
+}
+
+/// Parses a KANJD212 supplement in the EUC-JP encoding the EDRDG distributes it in.
+#[cfg(feature = "encoding")]
+pub fn parse_supplement_euc_jp(bytes: &[u8]) -> Result<Vec<Character>, Error> {
+    let contents = crate::kradfile::decode_euc_jp(bytes).map_err(|_| Error::Encoding)?;
+    parse_supplement(&contents)
+}
+
+/// What the bare readings on the line currently are.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Readings,
+    Nanori,
+    RadicalNames,
+}
+
+/// Parses a single character entry.
+pub fn from_line(line: &str) -> Result<Character, LineError> {
+    entry(line, Codepoint::Jis208)
+}
+
+/// Parses a single character entry from the KANJD212 supplement.
+pub fn supplement_line(line: &str) -> Result<Character, LineError> {
+    entry(line, Codepoint::Jis212)
+}
+
+fn entry(line: &str, jis_codepoint: fn(Kuten) -> Codepoint) -> Result<Character, LineError> {
+    let mut fields = Fields { rest: line };
+    let literal = fields
+        .next()
+        .and_then(string_to_char)
+        .ok_or(LineError::Missing("literal"))?;
+    let jis = fields.next().ok_or(LineError::Missing("JIS code"))?;
+    let mut codepoints = vec![jis_codepoint(jis_kuten(jis).ok_or_else(|| invalid(jis))?)];
+    let mut radicals = vec![];
+    let mut grade = None;
+    let mut strokes = vec![];
+    let mut variants = vec![];
+    let mut frequency = None;
+    let mut radical_names = vec![];
+    let mut jlpt = None;
+    let mut references = vec![];
+    let mut query_codes = vec![];
+    let mut readings = vec![];
+    let mut meanings = vec![];
+    let mut nanori = vec![];
+    let mut moro_index = None;
+    let mut moro_page = None;
+    let mut mode = Mode::Readings;
+
+    for field in fields {
+        if let Some(meaning) = field.strip_prefix('{') {
+            meanings.push(meaning.trim_end_matches('}').to_owned());
+            continue;
+        }
+        let first = field.chars().next().unwrap_or_default();
+        if is_kana(first) || (first == '-' && field.chars().nth(1).is_some_and(is_kana)) {
+            let reading = field.to_owned();
+            match mode {
+                Mode::Nanori => nanori.push(reading),
+                Mode::RadicalNames => radical_names.push(reading),
+                Mode::Readings if field.chars().any(is_hiragana) => readings.push(
+                    Reading::Kunyomi(Kunyomi::try_from(field).map_err(|_| invalid(field))?),
+                ),
+                Mode::Readings => readings.push(Reading::Onyomi(reading)),
+            }
+            continue;
+        }
+        let parsed = field_value(field);
+        match parsed {
+            Some(Field::Unicode(value)) => codepoints.push(Codepoint::Unicode(value)),
+            Some(Field::Radical(radical)) => radicals.push(radical),
+            Some(Field::Grade(value)) => grade = Some(value),
+            Some(Field::Strokes(value)) => strokes.push(value),
+            Some(Field::Variant(value)) => variants.push(value),
+            Some(Field::Frequency(value)) => frequency = Some(value),
+            Some(Field::Jlpt(value)) => jlpt = Some(value),
+            Some(Field::Reference(value)) => references.push(value),
+            Some(Field::MoroIndex(index)) => moro_index = Some(index),
+            Some(Field::MoroPage(volume, page)) => moro_page = Some((volume, page)),
+            Some(Field::QueryCode(value)) => query_codes.push(value),
+            Some(Field::Reading(value)) => readings.push(value),
+            Some(Field::Mode(value)) => mode = value,
+            Some(Field::Ignored) => {}
+            None => return Err(invalid(field)),
+        }
+    }
+
+    if let Some((index, suffix)) = moro_index {
+        let (volume, page) = moro_page.unzip();
+        references.push(Reference::Moro(Moro {
+            volume,
+            page,
+            index,
+            suffix,
+        }));
+    }
+    // KANJIDIC2 lists the classical radical first
+    radicals.sort_by_key(|radical: &Radical| radical.kind != RadicalKind::Classical);
+    let mut strokes = strokes.into_iter();
+    let stroke_counts = StrokeCount {
+        accepted: strokes.next().ok_or(LineError::Missing("stroke count"))?,
+        miscounts: strokes.collect(),
+    };
+    let mut translations = Translations::new();
+    if !meanings.is_empty() {
+        translations.insert(Language::En, meanings);
+    }
+
+    Ok(Character {
+        literal,
+        literal_sequence: None,
+        codepoints,
+        radicals,
+        grade,
+        stroke_counts,
+        variants,
+        frequency,
+        radical_names,
+        jlpt,
+        references,
+        query_codes,
+        readings,
+        translations,
+        nanori,
+        decomposition: decomposition::components(ParseOptions::default().decomposition, literal),
+        ids: None,
+    })
+}
+
+/// Splits a line on spaces, keeping braced meanings such as `{rank next}` whole.
+struct Fields<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.trim_start();
+        if rest.is_empty() {
+            return None;
+        }
+        let end = if rest.starts_with('{') {
+            rest.find('}').map_or(rest.len(), |end| end + 1)
+        } else {
+            rest.find(' ').unwrap_or(rest.len())
+        };
+        let (field, rest) = rest.split_at(end);
+        self.rest = rest;
+        Some(field)
+    }
+}
+
+enum Field {
+    Unicode(u32),
+    Radical(Radical),
+    Grade(Grade),
+    Strokes(u8),
+    Variant(Variant),
+    Frequency(u16),
+    Jlpt(u8),
+    Reference(Reference),
+    MoroIndex((u16, MoroSuffix)),
+    MoroPage(u8, u16),
+    QueryCode(QueryCode),
+    Reading(Reading),
+    Mode(Mode),
+    Ignored,
+}
+
+/// Reads a coded field, returning `None` if a recognized code has a malformed value.
+/// Longer codes are checked before the shorter codes they start with.
+fn field_value(field: &str) -> Option<Field> {
+    let with = |code: &str| field.strip_prefix(code);
+    let reference = |value: Option<Reference>| value.map(Field::Reference);
+    if let Some(value) = with("XJ0") {
+        Some(Field::Variant(Variant::Jis208(jis_kuten(value)?)))
+    } else if let Some(value) = with("XJ1") {
+        Some(Field::Variant(Variant::Jis212(jis_kuten(value)?)))
+    } else if let Some(value) = with("XDR") {
+        Some(Field::Variant(Variant::DeRoo(DeRoo::try_from(value).ok()?)))
+    } else if let Some(value) = with("XH") {
+        Some(Field::Variant(Variant::Halpern(value.parse().ok()?)))
+    } else if let Some(value) = with("XI") {
+        Some(Field::Variant(Variant::SpahnHadamitzky(
+            ShDesc::try_from(value).ok()?,
+        )))
+    } else if let Some(value) = with("XN") {
+        Some(Field::Variant(Variant::Nelson(value.parse().ok()?)))
+    } else if let Some(value) = with("XO") {
+        Some(Field::Variant(Variant::ONeill(
+            Oneill::try_from(value).ok()?,
+        )))
+    } else if let Some(value) = with("ZPP") {
+        misclassification(MisclassificationKind::Position, value)
+    } else if let Some(value) = with("ZSP") {
+        misclassification(MisclassificationKind::StrokeCount, value)
+    } else if let Some(value) = with("ZBP") {
+        misclassification(MisclassificationKind::StrokeAndPosition, value)
+    } else if let Some(value) = with("ZRP") {
+        misclassification(MisclassificationKind::Ambiguous, value)
+    } else if let Some(value) = with("DA") {
+        reference(value.parse().ok().map(Reference::ShKk2))
+    } else if let Some(value) = with("DB") {
+        reference(BusyPeople::try_from(value).ok().map(Reference::BusyPeople))
+    } else if let Some(value) = with("DC") {
+        reference(value.parse().ok().map(Reference::Crowley))
+    } else if let Some(value) = with("DF") {
+        reference(value.parse().ok().map(Reference::Jfcards))
+    } else if let Some(value) = with("DG") {
+        reference(value.parse().ok().map(Reference::KodanshaCompact))
+    } else if let Some(value) = with("DH") {
+        reference(value.parse().ok().map(Reference::Henshall3))
+    } else if let Some(value) = with("DJ") {
+        reference(value.parse().ok().map(Reference::KanjiInContext))
+    } else if let Some(value) = with("DK") {
+        reference(value.parse().ok().map(Reference::Kkld))
+    } else if let Some(value) = with("DL") {
+        reference(value.parse().ok().map(Reference::Kkld2ed))
+    } else if let Some(value) = with("DM") {
+        reference(value.parse().ok().map(Reference::Maniette))
+    } else if let Some(value) = with("DN") {
+        reference(value.parse().ok().map(Reference::Heisig6))
+    } else if let Some(value) = with("DO") {
+        reference(value.parse().ok().map(Reference::OneillKk))
+    } else if let Some(value) = with("DP") {
+        reference(value.parse().ok().map(Reference::Kkd))
+    } else if let Some(value) = with("DR") {
+        Some(Field::QueryCode(QueryCode::DeRoo(
+            DeRoo::try_from(value).ok()?,
+        )))
+    } else if let Some(value) = with("DS") {
+        reference(value.parse().ok().map(Reference::Sakade))
+    } else if let Some(value) = with("DT") {
+        reference(value.parse().ok().map(Reference::TuttleCards))
+    } else if let Some(value) = with("IN") {
+        reference(value.parse().ok().map(Reference::ShKk))
+    } else if let Some(value) = with("MN") {
+        let (_, index) = moro::parse_index(value).ok()?;
+        Some(Field::MoroIndex(index))
+    } else if let Some(value) = with("MP") {
+        let (volume, page) = value.split_once('.')?;
+        Some(Field::MoroPage(volume.parse().ok()?, page.parse().ok()?))
+    } else if let Some(value) = with("B") {
+        radical(RadicalKind::Nelson, value)
+    } else if let Some(value) = with("C") {
+        radical(RadicalKind::Classical, value)
+    } else if let Some(value) = with("E") {
+        reference(value.parse().ok().map(Reference::Henshall))
+    } else if let Some(value) = with("F") {
+        Some(Field::Frequency(value.parse().ok()?))
+    } else if let Some(value) = with("G") {
+        Some(Field::Grade(Grade::from_raw(value.parse().ok()?)?))
+    } else if let Some(value) = with("H") {
+        reference(value.parse().ok().map(Reference::Njecd))
+    } else if let Some(value) = with("I") {
+        Some(Field::QueryCode(QueryCode::SpahnHadamitzky(
+            ShDesc::try_from(value).ok()?,
+        )))
+    } else if let Some(value) = with("J") {
+        Some(Field::Jlpt(value.parse().ok()?))
+    } else if let Some(value) = with("K") {
+        reference(value.parse().ok().map(Reference::Gakken))
+    } else if let Some(value) = with("L") {
+        reference(value.parse().ok().map(Reference::Heisig))
+    } else if let Some(value) = with("N") {
+        reference(value.parse().ok().map(Reference::NelsonClassic))
+    } else if let Some(value) = with("O") {
+        reference(Oneill::try_from(value).ok().map(Reference::OneillNames))
+    } else if let Some(value) = with("P") {
+        Some(Field::QueryCode(QueryCode::Skip(
+            Skip::try_from(value).ok()?,
+        )))
+    } else if let Some(value) = with("Q") {
+        Some(Field::QueryCode(QueryCode::FourCorner(
+            FourCorner::try_from(value).ok()?,
+        )))
+    } else if let Some(value) = with("S") {
+        Some(Field::Strokes(value.parse().ok()?))
+    } else if let Some(value) = with("T") {
+        match value {
+            "1" => Some(Field::Mode(Mode::Nanori)),
+            "2" => Some(Field::Mode(Mode::RadicalNames)),
+            _ => None,
+        }
+    } else if let Some(value) = with("U") {
+        Some(Field::Unicode(u32::from_str_radix(value, 16).ok()?))
+    } else if let Some(value) = with("V") {
+        reference(value.parse().ok().map(Reference::NelsonNew))
+    } else if let Some(value) = with("W") {
+        Some(Field::Reading(Reading::KoreanRomanized(value.to_owned())))
+    } else if let Some(value) = with("Y") {
+        Some(Field::Reading(Reading::PinYin(
+            PinYin::try_from(value).ok()?,
+        )))
+    } else {
+        // Codes that KANJIDIC2 does not carry, such as cross-reference hints
+        Some(Field::Ignored)
+    }
+}
+
+fn radical(kind: RadicalKind, value: &str) -> Option<Field> {
+    let radical = KangXi::try_from(value.parse::<u8>().ok()?).ok()?;
+    Some(Field::Radical(Radical { kind, radical }))
+}
+
+fn misclassification(kind: MisclassificationKind, value: &str) -> Option<Field> {
+    Some(Field::QueryCode(QueryCode::Misclassification(
+        Misclassification {
+            kind,
+            skip: Skip::try_from(value).ok()?,
+        },
+    )))
+}
+
+/// Converts a JIS X 0208 code in hexadecimal, such as `3021`, to its kuten.
+fn jis_kuten(hex: &str) -> Option<Kuten> {
+    let code = u16::from_str_radix(hex, 16).ok()?;
+    let [high, low] = code.to_be_bytes();
+    Some(Kuten {
+        plane: 1,
+        ku: high.checked_sub(0x20)?,
+        ten: low.checked_sub(0x20)?,
+    })
+}
+
+fn is_hiragana(c: char) -> bool {
+    ('\u{3040}'..='\u{309f}').contains(&c)
+}
+
+fn is_kana(c: char) -> bool {
+    ('\u{3040}'..='\u{30ff}').contains(&c)
+}
+
+fn invalid(field: &str) -> LineError {
+    LineError::Field(field.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_line, parse, parse_supplement, Error, LineError};
+    use crate::test_shared::CHARACTERS;
+    use kanjidic_types::{Codepoint, Kuten, Reading, Reference};
+
+    const LINE: &str = "亜 3021 U4e9c B1 C7 G8 S7 XJ13F59 F1509 J1 N43 V81 H3540 \
+        DP4354 DK2204 DL2966 L1809 DN1923 K1331 O525 DO1788 MN272 MP1.0525 E997 IN1616 \
+        DA1724 DS1950 DF1032 DT1092 DJ1818 DG35 DM1827 P4-7-1 I0a7.14 Q1010.6 DR3273 \
+        Yya4 Wa ア つ.ぐ T1 や つぐ {Asia} {rank next} {come after} {-ous}";
+
+    #[test]
+    fn line() {
+        let character = from_line(LINE).unwrap();
+        assert_eq!(character.literal, '亜');
+        assert_eq!(
+            character.codepoints,
+            vec![
+                Codepoint::Jis208(Kuten {
+                    plane: 1,
+                    ku: 16,
+                    ten: 1
+                }),
+                Codepoint::Unicode(0x4e9c),
+            ]
+        );
+        assert_eq!(character.stroke_counts.accepted, 7);
+        assert_eq!(character.frequency, Some(1509));
+        assert!(character.references.contains(&Reference::Heisig(1809)));
+        assert!(character
+            .readings
+            .contains(&Reading::Onyomi("ア".to_owned())));
+        assert_eq!(character.nanori, vec!["や", "つぐ"]);
+        assert_eq!(
+            character.translations["en"],
+            vec!["Asia", "rank next", "come after", "-ous"]
+        );
+    }
+
+    #[test]
+    fn matches_kanjidic2() {
+        let character = from_line(LINE).unwrap();
+        let expected = &CHARACTERS[0];
+        assert_eq!(character.radicals, expected.radicals);
+        assert_eq!(character.grade, expected.grade);
+        assert_eq!(character.query_codes, expected.query_codes);
+        assert_eq!(character.decomposition, expected.decomposition);
+    }
+
+    #[test]
+    fn supplement() {
+        let characters = parse_supplement("丂 3021 U4e02 B1 S2 {obstructed}\n").unwrap();
+        assert_eq!(
+            characters[0].codepoints[0],
+            Codepoint::Jis212(Kuten {
+                plane: 1,
+                ku: 16,
+                ten: 1
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let file = "＃ KANJIDIC\n亜 3021 G8\n";
+        assert_eq!(
+            parse(file),
+            Err(Error::Line(2, LineError::Missing("stroke count")))
+        );
+        assert_eq!(
+            from_line("亜 3021 S7 Gx"),
+            Err(LineError::Field("Gx".to_owned()))
+        );
+    }
+}
@@ -1,29 +1,89 @@
+#[cfg(feature = "rkyv")]
+pub mod archive;
+pub mod borrowed;
 pub mod busy_people;
 pub mod character;
+#[cfg(feature = "chise")]
+pub mod chise;
 pub mod codepoint;
 pub mod database_version;
 pub mod date_of_creation;
+pub mod db;
 pub mod de_roo;
+pub mod decomposition;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+pub mod diff;
+#[cfg(feature = "embed")]
+pub mod embed;
+#[cfg(feature = "encoding")]
+pub mod encoding;
+pub mod error_code;
+pub mod export;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod four_corner;
+pub mod from_node;
 pub mod grade;
+#[cfg(feature = "api-graphql")]
+pub mod graphql;
 pub mod header;
+#[cfg(feature = "api-http")]
+pub mod http;
+pub mod ids;
+pub mod integrity;
+pub mod jouyou;
+#[cfg(feature = "schemars")]
+pub mod json_schema;
 pub mod kanjidic;
+#[cfg(feature = "kanjivg")]
+pub mod kanjivg;
+pub mod kanken;
+pub mod kradfile;
 pub mod kunyomi;
 pub mod kuten;
+pub mod legacy;
+pub mod list;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
 pub mod moro;
 pub mod oneill;
+pub mod overlay;
+pub mod parse_options;
 pub mod pin_yin;
 pub mod pos_error;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod query_code;
 pub mod radical;
+pub mod radkfile;
 pub mod reading;
 pub mod reference;
+pub mod rtk;
+pub mod schema;
 pub mod shared;
 pub mod skip;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
 pub mod spahn_hadamitzky;
+pub mod stats;
 pub mod stroke_count;
+pub mod subset;
 pub mod translation;
+#[cfg(feature = "unihan")]
+pub mod unihan;
 pub mod variant;
+pub mod wanikani;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod writer;
+
+pub use from_node::FromKanjidicNode;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
 
 #[cfg(test)]
 mod test_shared;
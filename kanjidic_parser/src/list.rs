@@ -0,0 +1,85 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("(List) Malformed entry on line {0}")]
+    Malformed(usize),
+    #[error("(List) Unrecognized value on line {0}")]
+    Value(usize),
+}
+
+/// Parses a list where each line holds a key and a value separated by a comma or tab,
+/// such as `亜,53`. Everything after the separator is the value.
+/// Comments start with `#`, and a header row whose key or value does not parse is skipped.
+/// Later entries replace earlier ones with the same key.
+pub fn parse<K, V>(
+    contents: &str,
+    key: impl Fn(&str) -> Option<K>,
+    value: impl Fn(&str) -> Option<V>,
+) -> Result<HashMap<K, V>, Error>
+where
+    K: Eq + Hash,
+{
+    let mut entries = HashMap::new();
+    for (i, line) in contents.lines().enumerate() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let (k, v) = line
+            .split_once(',')
+            .or_else(|| line.split_once('\t'))
+            .ok_or(Error::Malformed(i + 1))?;
+        match (key(k.trim()), value(v.trim())) {
+            (Some(k), Some(v)) => entries.insert(k, v),
+            _ if i == 0 => continue,
+            (None, _) => return Err(Error::Malformed(i + 1)),
+            (Some(_), None) => return Err(Error::Value(i + 1)),
+        };
+    }
+    Ok(entries)
+}
+
+/// Reads a key made of a single kanji.
+pub fn kanji(field: &str) -> Option<char> {
+    let mut chars = field.chars();
+    match (chars.next(), chars.next()) {
+        (Some(kanji), None) => Some(kanji),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{kanji, parse, Error};
+
+    fn levels(contents: &str) -> Result<Vec<(char, u8)>, Error> {
+        let mut entries: Vec<_> = parse(contents, kanji, |level| level.parse().ok())?
+            .into_iter()
+            .collect();
+        entries.sort_unstable();
+        Ok(entries)
+    }
+
+    #[test]
+    fn entries() {
+        assert_eq!(
+            levels("kanji,level\n# Comment\n亜,53\n\n一\t1\n"),
+            Ok(vec![('一', 1), ('亜', 53)])
+        );
+        let keywords = parse(
+            "1,one\n2,ditto, again\n",
+            |frame| frame.parse::<u16>().ok(),
+            |s| Some(s.to_owned()),
+        )
+        .unwrap();
+        assert_eq!(keywords[&2], "ditto, again");
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(levels("亜,53\n亜 53\n"), Err(Error::Malformed(2)));
+        assert_eq!(levels("亜,53\n亜亜,53\n"), Err(Error::Malformed(2)));
+        assert_eq!(levels("亜,53\n亜,many\n"), Err(Error::Value(2)));
+        assert_eq!(levels("亜 53\n"), Err(Error::Malformed(1)));
+    }
+}
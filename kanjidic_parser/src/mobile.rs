@@ -0,0 +1,173 @@
+use crate::{
+    db::{KanjidicDb, QueryFilter},
+    kanjidic::{self, Kanjidic},
+    kunyomi,
+};
+use kanjidic_types::{Character, Grade, Language};
+use std::{fs, sync::Arc};
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum Error {
+    #[error("(Mobile) Could not read the dictionary file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("(Mobile) Could not parse the dictionary: {0}")]
+    Parse(#[from] kanjidic::Error),
+}
+
+/// The commonly used fields of a character, flattened for foreign-language bindings.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct Kanji {
+    /// The character itself, including any scalar values after the first.
+    pub literal: String,
+    /// The grade, using the numbering from KANJIDIC2.
+    pub grade: Option<u8>,
+    /// The accepted stroke count.
+    pub strokes: u8,
+    /// The newspaper frequency ranking.
+    pub frequency: Option<u16>,
+    /// The old JLPT level.
+    pub jlpt: Option<u8>,
+    /// The on'yomi in katakana.
+    pub onyomi: Vec<String>,
+    /// The kun'yomi, with okurigana after a period.
+    pub kunyomi: Vec<String>,
+    /// The English meanings.
+    pub meanings: Vec<String>,
+    /// Readings used in names.
+    pub nanori: Vec<String>,
+}
+
+impl From<&Character> for Kanji {
+    fn from(character: &Character) -> Self {
+        Self {
+            literal: character.literal_str().into_owned(),
+            grade: character.grade.map(Grade::to_raw),
+            strokes: character.stroke_counts.accepted,
+            frequency: character.frequency,
+            jlpt: character.jlpt,
+            onyomi: character.onyomi().map(str::to_owned).collect(),
+            kunyomi: character.kunyomi().map(kunyomi::text).collect(),
+            meanings: character.meanings("en").unwrap_or_default().to_vec(),
+            nanori: character.nanori.clone(),
+        }
+    }
+}
+
+/// Conditions for [`KanjiDatabase::query`], mirroring [`QueryFilter`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, uniffi::Record)]
+pub struct KanjiFilter {
+    pub grade: Option<u8>,
+    pub jlpt: Option<u8>,
+    pub min_strokes: Option<u8>,
+    pub max_strokes: Option<u8>,
+    pub frequency_below: Option<u16>,
+    pub component: Option<String>,
+}
+
+impl From<KanjiFilter> for QueryFilter {
+    fn from(filter: KanjiFilter) -> Self {
+        Self {
+            grade: filter.grade,
+            jlpt: filter.jlpt,
+            min_strokes: filter.min_strokes,
+            max_strokes: filter.max_strokes,
+            frequency_below: filter.frequency_below,
+            component: filter.component.and_then(|s| s.chars().next()),
+        }
+    }
+}
+
+/// A database handle for Kotlin and Swift.
+#[derive(uniffi::Object)]
+pub struct KanjiDatabase {
+    db: KanjidicDb,
+}
+
+#[uniffi::export]
+impl KanjiDatabase {
+    /// Parses the KANJIDIC2 XML file at the given path.
+    #[uniffi::constructor]
+    pub fn open(path: String) -> Result<Arc<Self>, Error> {
+        Self::parse(fs::read_to_string(path)?)
+    }
+
+    /// Parses KANJIDIC2 XML from a string.
+    #[uniffi::constructor]
+    pub fn parse(xml: String) -> Result<Arc<Self>, Error> {
+        let kanjidic = Kanjidic::from_file_contents(&xml)?;
+        Ok(Arc::new(Self {
+            db: KanjidicDb::new(kanjidic.characters),
+        }))
+    }
+
+    /// The number of characters in the database.
+    pub fn len(&self) -> u64 {
+        self.db.len() as u64
+    }
+
+    /// Whether the database has no characters.
+    pub fn is_empty(&self) -> bool {
+        self.db.is_empty()
+    }
+
+    /// Gets the character with the given literal.
+    pub fn lookup(&self, literal: String) -> Option<Kanji> {
+        let literal = literal.chars().next()?;
+        self.db.get(literal).map(Kanji::from)
+    }
+
+    /// Gets the characters with the given on'yomi or kun'yomi.
+    pub fn by_reading(&self, reading: String) -> Vec<Kanji> {
+        convert(self.db.by_reading(&reading))
+    }
+
+    /// Gets the characters with a meaning matching the query, best matches first.
+    pub fn search_meanings(&self, query: String, language: String) -> Vec<Kanji> {
+        self.db
+            .search_meanings(&query, &Language::from(language))
+            .into_iter()
+            .map(|found| Kanji::from(found.character))
+            .collect()
+    }
+
+    /// Gets the characters matching all of the given conditions.
+    pub fn query(&self, filter: KanjiFilter) -> Vec<Kanji> {
+        convert(self.db.query_with(&filter.into()).collect())
+    }
+}
+
+fn convert(characters: Vec<&Character>) -> Vec<Kanji> {
+    characters.into_iter().map(Kanji::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Kanji, KanjiFilter};
+    use crate::{
+        db::KanjidicDb,
+        test_shared::{decomposed, CHARACTERS},
+    };
+
+    #[test]
+    fn flattened() {
+        let kanji = Kanji::from(&CHARACTERS[0]);
+        assert_eq!(kanji.literal, "亜");
+        assert_eq!(kanji.grade, Some(8));
+        assert_eq!(kanji.strokes, 7);
+        assert_eq!(kanji.onyomi, vec!["ア"]);
+        assert!(kanji.meanings.contains(&"Asia".to_owned()));
+    }
+
+    #[test]
+    fn filter() {
+        let db = KanjidicDb::new(decomposed());
+        let filter = KanjiFilter {
+            max_strokes: Some(7),
+            component: Some("口".to_owned()),
+            ..KanjiFilter::default()
+        };
+        let found = db.query_with(&filter.into()).collect();
+        assert!(found.iter().any(|character| character.literal == '亜'));
+    }
+}
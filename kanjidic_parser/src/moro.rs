@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{attr_uint, text, SharedError},
 };
@@ -16,6 +17,16 @@ pub enum Error {
     Format(PosError, NomErrorReason),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::IndexSuffix | Error::Format(_, _) => ErrorCode::E0117_INVALID_MORO,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Moro, Error> {
     let (_i, (index, suffix)) = parse_index(text(&node)?)
         .map_err(|err| Error::Format(PosError::from(&node), err.into()))?;
@@ -29,7 +40,7 @@ pub fn from(node: Node) -> Result<Moro, Error> {
     })
 }
 
-fn parse_index(s: &str) -> IResult<(u16, MoroSuffix)> {
+pub(crate) fn parse_index(s: &str) -> IResult<(u16, MoroSuffix)> {
     tuple((take_uint, index_suffix))(s)
 }
 
@@ -75,4 +86,25 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn citation() {
+        let moro = Moro {
+            volume: Some(1),
+            page: Some(525),
+            index: 272,
+            suffix: MoroSuffix::None,
+        };
+        assert_eq!(moro.to_string(), "v1 p525 #272");
+        assert_eq!(moro.compact(), "272");
+
+        let moro = Moro {
+            volume: None,
+            page: None,
+            index: 1234,
+            suffix: MoroSuffix::PX,
+        };
+        assert_eq!(moro.to_string(), "#1234PX");
+        assert_eq!(moro.compact(), "1234PX");
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{text, SharedError},
 };
@@ -14,6 +15,67 @@ pub enum Error {
     Parse(PosError, oneill::ParseError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Parse(_, _) => ErrorCode::E0119_INVALID_ONEILL,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Oneill, Error> {
     Oneill::try_from(text(&node)?).map_err(|err| Error::Parse(PosError::from(&node), err))
 }
+
+#[cfg(test)]
+mod tests {
+    use kanjidic_types::{oneill::OneillSuffix, Oneill};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn suffixes() {
+        let cases = [
+            ("1234", OneillSuffix::None),
+            ("1234A", OneillSuffix::A),
+            ("1234B", OneillSuffix::Other('B')),
+        ];
+        for (text, suffix) in cases.iter() {
+            let oneill = Oneill::try_from(*text).unwrap();
+            assert_eq!(
+                oneill,
+                Oneill {
+                    number: 1234,
+                    suffix: *suffix
+                }
+            );
+            assert_eq!(oneill.to_string(), *text);
+        }
+        assert!(Oneill::try_from("1234AB").is_err());
+        assert!(Oneill::try_from("1234a").is_err());
+    }
+
+    #[test]
+    fn json() {
+        let other = Oneill {
+            number: 1234,
+            suffix: OneillSuffix::Other('B'),
+        };
+        let json = serde_json::to_string(&other).unwrap();
+        assert_eq!(json, r#"{"number":1234,"suffix":"B"}"#);
+        assert_eq!(serde_json::from_str::<Oneill>(&json).unwrap(), other);
+        assert_eq!(
+            serde_json::from_str::<OneillSuffix>(r#""A""#).unwrap(),
+            OneillSuffix::A
+        );
+        assert!(serde_json::from_str::<OneillSuffix>(r#""b""#).is_err());
+        assert_eq!(
+            serde_json::from_str::<Oneill>(r#"{"number":1234}"#).unwrap(),
+            Oneill {
+                number: 1234,
+                suffix: OneillSuffix::None
+            }
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::kanjidic::Kanjidic;
+use kanjidic_types::{character::Translations, Language};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// User-supplied corrections and additions for a single character.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterPatch {
+    /// The character to patch, including any scalar values after the first.
+    pub literal: String,
+    /// Meanings to add, keyed by language.
+    #[serde(default)]
+    pub meanings: Translations,
+    /// A replacement for the accepted stroke count.
+    #[serde(default)]
+    pub stroke_count: Option<u8>,
+    /// Name readings to add.
+    #[serde(default)]
+    pub nanori: Vec<String>,
+    /// A custom keyword for the character, such as a replacement for its Heisig keyword.
+    #[serde(default)]
+    pub keyword: Option<String>,
+}
+
+/// A part of a patch that could not be applied as given.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "tag", content = "content")]
+pub enum Conflict {
+    /// The dictionary has no such character, so the patch was skipped.
+    UnknownCharacter(String),
+    /// An earlier patch already set a different stroke count, which was kept.
+    StrokeCount {
+        literal: String,
+        kept: u8,
+        rejected: u8,
+    },
+    /// An earlier patch already set a different keyword, which was kept.
+    Keyword {
+        literal: String,
+        kept: String,
+        rejected: String,
+    },
+    /// The character already had the meaning.
+    DuplicateMeaning {
+        literal: String,
+        language: Language,
+        meaning: String,
+    },
+    /// The character already had the name reading.
+    DuplicateNanori { literal: String, nanori: String },
+}
+
+impl Kanjidic {
+    /// Applies the patches to the matching characters in order,
+    /// returning whatever could not be applied cleanly.
+    pub fn apply_overrides(
+        &mut self,
+        overrides: impl IntoIterator<Item = CharacterPatch>,
+    ) -> Vec<Conflict> {
+        let positions: HashMap<String, usize> = self
+            .characters
+            .iter()
+            .enumerate()
+            .map(|(i, character)| (character.literal_str().into_owned(), i))
+            .collect();
+        let mut stroke_counts: HashMap<String, u8> = HashMap::new();
+        let mut conflicts = vec![];
+        for patch in overrides {
+            let literal = patch.literal;
+            let character = match positions.get(&literal) {
+                Some(&i) => &mut self.characters[i],
+                None => {
+                    conflicts.push(Conflict::UnknownCharacter(literal));
+                    continue;
+                }
+            };
+
+            if let Some(strokes) = patch.stroke_count {
+                match stroke_counts.get(&literal) {
+                    Some(&kept) if kept != strokes => conflicts.push(Conflict::StrokeCount {
+                        literal: literal.clone(),
+                        kept,
+                        rejected: strokes,
+                    }),
+                    _ => {
+                        character.stroke_counts.accepted = strokes;
+                        stroke_counts.insert(literal.clone(), strokes);
+                    }
+                }
+            }
+
+            if let Some(keyword) = patch.keyword {
+                match self.keywords.get(&literal) {
+                    Some(kept) if *kept != keyword => conflicts.push(Conflict::Keyword {
+                        literal: literal.clone(),
+                        kept: kept.clone(),
+                        rejected: keyword,
+                    }),
+                    _ => {
+                        self.keywords.insert(literal.clone(), keyword);
+                    }
+                }
+            }
+
+            for (language, meanings) in patch.meanings {
+                let existing = character.translations.entry(language.clone()).or_default();
+                for meaning in meanings {
+                    if existing.contains(&meaning) {
+                        conflicts.push(Conflict::DuplicateMeaning {
+                            literal: literal.clone(),
+                            language: language.clone(),
+                            meaning,
+                        });
+                    } else {
+                        existing.push(meaning);
+                    }
+                }
+            }
+
+            for nanori in patch.nanori {
+                if character.nanori.contains(&nanori) {
+                    conflicts.push(Conflict::DuplicateNanori {
+                        literal: literal.clone(),
+                        nanori,
+                    });
+                } else {
+                    character.nanori.push(nanori);
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// The custom keyword of the character, if a patch set one.
+    pub fn keyword(&self, literal: &str) -> Option<&str> {
+        self.keywords.get(literal).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharacterPatch, Conflict};
+    use crate::test_shared::KANJIDIC;
+    use kanjidic_types::{character::Translations, Language};
+
+    #[test]
+    fn applies_patch() {
+        let mut kanjidic = KANJIDIC.clone();
+        let mut meanings = Translations::new();
+        meanings.insert(Language::En, vec!["inferior".to_owned()]);
+        let conflicts = kanjidic.apply_overrides(vec![CharacterPatch {
+            literal: "亜".to_owned(),
+            meanings,
+            stroke_count: Some(8),
+            nanori: vec!["あ".to_owned()],
+            keyword: Some("second-rate".to_owned()),
+        }]);
+        assert_eq!(conflicts, vec![]);
+        let character = &kanjidic.characters[0];
+        assert_eq!(character.stroke_counts.accepted, 8);
+        assert!(character.translations["en"].contains(&"inferior".to_owned()));
+        assert!(character.nanori.contains(&"あ".to_owned()));
+        assert_eq!(kanjidic.keyword("亜"), Some("second-rate"));
+    }
+
+    #[test]
+    fn reports_conflicts() {
+        let mut kanjidic = KANJIDIC.clone();
+        let mut meanings = Translations::new();
+        meanings.insert(Language::En, vec!["Asia".to_owned()]);
+        let conflicts = kanjidic.apply_overrides(vec![
+            CharacterPatch {
+                literal: "a".to_owned(),
+                ..CharacterPatch::default()
+            },
+            CharacterPatch {
+                literal: "亜".to_owned(),
+                stroke_count: Some(8),
+                keyword: Some("Asia".to_owned()),
+                ..CharacterPatch::default()
+            },
+            CharacterPatch {
+                literal: "亜".to_owned(),
+                meanings,
+                stroke_count: Some(9),
+                keyword: Some("sub-".to_owned()),
+                ..CharacterPatch::default()
+            },
+        ]);
+        assert_eq!(
+            conflicts,
+            vec![
+                Conflict::UnknownCharacter("a".to_owned()),
+                Conflict::StrokeCount {
+                    literal: "亜".to_owned(),
+                    kept: 8,
+                    rejected: 9,
+                },
+                Conflict::Keyword {
+                    literal: "亜".to_owned(),
+                    kept: "Asia".to_owned(),
+                    rejected: "sub-".to_owned(),
+                },
+                Conflict::DuplicateMeaning {
+                    literal: "亜".to_owned(),
+                    language: Language::En,
+                    meaning: "Asia".to_owned(),
+                },
+            ]
+        );
+        assert_eq!(kanjidic.characters[0].stroke_counts.accepted, 8);
+        assert_eq!(kanjidic.keyword("亜"), Some("Asia"));
+    }
+}
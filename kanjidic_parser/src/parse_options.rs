@@ -0,0 +1,106 @@
+#[cfg(feature = "decomposition")]
+use crate::decomposition::Kradical;
+use crate::{decomposition::DecompositionProvider, kanjidic::Progress};
+use std::{fmt, sync::atomic::AtomicBool};
+
+/// Which parts of each character to parse.
+/// Skipped sections are left empty in the output.
+#[derive(Clone)]
+pub struct ParseOptions<'a> {
+    /// Alternate encodings for the character.
+    pub codepoints: bool,
+    /// Radical classifications.
+    pub radicals: bool,
+    /// Cross-references to other characters.
+    pub variants: bool,
+    /// Radical names.
+    pub radical_names: bool,
+    /// Dictionary references.
+    pub references: bool,
+    /// Query codes such as SKIP and Four Corner.
+    pub query_codes: bool,
+    /// Readings in all languages.
+    pub readings: bool,
+    /// The languages to keep meanings for.
+    /// Meanings in other languages are never allocated,
+    /// which saves a large share of the memory held by a parsed dictionary.
+    pub translations: LanguageFilter<'a>,
+    /// Name readings.
+    pub nanori: bool,
+    /// Where to look up the components that make up the character.
+    /// Defaults to the EDRDG radical decompositions with the `decomposition` feature
+    /// and to skipping decomposition without it.
+    pub decomposition: Option<&'a dyn DecompositionProvider>,
+    /// Called after each character of a whole dictionary is parsed,
+    /// so that a caller can report how far along it is.
+    pub on_progress: Option<&'a dyn Fn(Progress)>,
+    /// Parsing stops with [`Error::Cancelled`](crate::kanjidic::Error::Cancelled)
+    /// once another thread sets the flag.
+    pub cancel: Option<&'a AtomicBool>,
+}
+
+impl<'a> Default for ParseOptions<'a> {
+    fn default() -> Self {
+        Self {
+            codepoints: true,
+            radicals: true,
+            variants: true,
+            radical_names: true,
+            references: true,
+            query_codes: true,
+            readings: true,
+            translations: LanguageFilter::All,
+            nanori: true,
+            #[cfg(feature = "decomposition")]
+            decomposition: Some(&Kradical),
+            #[cfg(not(feature = "decomposition"))]
+            decomposition: None,
+            on_progress: None,
+            cancel: None,
+        }
+    }
+}
+
+impl<'a> fmt::Debug for ParseOptions<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseOptions")
+            .field("codepoints", &self.codepoints)
+            .field("radicals", &self.radicals)
+            .field("variants", &self.variants)
+            .field("radical_names", &self.radical_names)
+            .field("references", &self.references)
+            .field("query_codes", &self.query_codes)
+            .field("readings", &self.readings)
+            .field("translations", &self.translations)
+            .field("nanori", &self.nanori)
+            .field("decomposition", &self.decomposition)
+            .field("on_progress", &self.on_progress.map(|_| "Fn(Progress)"))
+            .field("cancel", &self.cancel)
+            .finish()
+    }
+}
+
+/// Selects meanings by language code, such as `en` or `fr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanguageFilter<'a> {
+    /// Keep meanings in every language.
+    All,
+    /// Keep meanings in only the given languages.
+    Only(&'a [&'a str]),
+    /// Skip meanings entirely.
+    None,
+}
+
+impl<'a> LanguageFilter<'a> {
+    /// Keep only English meanings, which is all that many applications display.
+    pub const ENGLISH: LanguageFilter<'static> = LanguageFilter::Only(&["en"]);
+
+    /// Whether meanings in the language should be kept.
+    pub fn includes(&self, language: &str) -> bool {
+        match self {
+            LanguageFilter::All => true,
+            LanguageFilter::Only(languages) => languages.contains(&language),
+            LanguageFilter::None => false,
+        }
+    }
+}
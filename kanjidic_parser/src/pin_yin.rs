@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{self, SharedError},
 };
@@ -14,6 +15,16 @@ pub enum Error {
     Parse(PosError, pin_yin::ParseError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Parse(_, _) => ErrorCode::E0115_INVALID_PIN_YIN,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<PinYin, Error> {
     let text = shared::text(&node)?;
     PinYin::try_from(text).map_err(|err| Error::Parse(PosError::from(&node), err))
@@ -24,6 +35,7 @@ mod tests {
     use super::from;
     use crate::test_shared::DOC;
     use kanjidic_types::{pin_yin::Tone, PinYin};
+    use std::convert::TryFrom;
 
     #[test]
     fn pin_yin() {
@@ -46,4 +58,24 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn tone_marks() {
+        let cases = [
+            ("ya4", "yà"),
+            ("hao3", "hǎo"),
+            ("gou3", "gǒu"),
+            ("gui4", "guì"),
+            ("lu:4", "lǜ"),
+            ("ma5", "ma"),
+        ];
+        for (numbered, marked) in cases.iter() {
+            let pin_yin = PinYin::try_from(*numbered).unwrap();
+            assert_eq!(pin_yin.to_marked(), *marked);
+            assert_eq!(marked.parse::<PinYin>(), Ok(pin_yin.clone()));
+            assert_eq!(numbered.parse::<PinYin>(), Ok(pin_yin));
+        }
+        assert!("yàà".parse::<PinYin>().is_err());
+        assert!("".parse::<PinYin>().is_err());
+    }
 }
@@ -4,6 +4,19 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PosError {
     position: TextPos,
+    offset: usize,
+}
+
+impl PosError {
+    /// The row and column of the node within the parsed text.
+    pub fn position(&self) -> TextPos {
+        self.position
+    }
+
+    /// The byte offset of the node within the parsed text.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
 }
 
 impl fmt::Display for PosError {
@@ -23,7 +36,8 @@ impl std::error::Error for PosError {}
 
 impl<'a, 'input> From<&Node<'a, 'input>> for PosError {
     fn from(node: &Node<'a, 'input>) -> Self {
-        let position = node.document().text_pos_at(node.range().start);
-        Self { position }
+        let offset = node.range().start;
+        let position = node.document().text_pos_at(offset);
+        Self { position, offset }
     }
 }
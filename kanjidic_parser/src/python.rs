@@ -0,0 +1,149 @@
+use crate::{
+    db::{KanjidicDb, QueryFilter},
+    kanjidic::Kanjidic,
+};
+use kanjidic_types::Language;
+use pyo3::{exceptions::PyValueError, prelude::*};
+use pythonize::pythonize;
+use serde::Serialize;
+use std::fs;
+
+/// A parsed dictionary for use from Python.
+/// Characters are returned as dicts in the same shape as the JSON output.
+#[pyclass(name = "Kanjidic")]
+pub struct PyKanjidic {
+    db: KanjidicDb,
+}
+
+#[pymethods]
+impl PyKanjidic {
+    /// Gets the character with the given literal, or `None`.
+    fn lookup(&self, py: Python, literal: char) -> PyResult<Option<PyObject>> {
+        self.db
+            .get(literal)
+            .map(|character| to_object(py, character))
+            .transpose()
+    }
+
+    /// Gets the characters with the given on'yomi or kun'yomi.
+    fn by_reading(&self, py: Python, reading: &str) -> PyResult<PyObject> {
+        to_object(py, &self.db.by_reading(reading))
+    }
+
+    /// Gets the characters with a meaning matching the query, best matches first.
+    #[pyo3(signature = (query, language = "en"))]
+    fn search_meanings(&self, py: Python, query: &str, language: &str) -> PyResult<PyObject> {
+        let characters: Vec<_> = self
+            .db
+            .search_meanings(query, &Language::from(language))
+            .into_iter()
+            .map(|found| found.character)
+            .collect();
+        to_object(py, &characters)
+    }
+
+    /// Gets the characters matching all of the given conditions.
+    #[pyo3(signature = (
+        grade = None,
+        jlpt = None,
+        min_strokes = None,
+        max_strokes = None,
+        frequency_below = None,
+        component = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn query(
+        &self,
+        py: Python,
+        grade: Option<u8>,
+        jlpt: Option<u8>,
+        min_strokes: Option<u8>,
+        max_strokes: Option<u8>,
+        frequency_below: Option<u16>,
+        component: Option<char>,
+    ) -> PyResult<PyObject> {
+        let filter = QueryFilter {
+            grade,
+            jlpt,
+            min_strokes,
+            max_strokes,
+            frequency_below,
+            component,
+        };
+        to_object(py, &self.db.query_with(&filter).collect())
+    }
+
+    fn __len__(&self) -> usize {
+        self.db.len()
+    }
+
+    fn __contains__(&self, literal: char) -> bool {
+        self.db.contains(literal)
+    }
+}
+
+/// Parses the KANJIDIC2 XML file at the given path.
+#[pyfunction]
+fn parse_file(path: &str) -> PyResult<PyKanjidic> {
+    parse(&fs::read_to_string(path)?)
+}
+
+/// Parses KANJIDIC2 XML from a string.
+#[pyfunction]
+fn parse(xml: &str) -> PyResult<PyKanjidic> {
+    let kanjidic =
+        Kanjidic::from_file_contents(xml).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(PyKanjidic {
+        db: KanjidicDb::new(kanjidic.characters),
+    })
+}
+
+/// The `kanjidic_parser` Python module.
+#[pymodule]
+fn kanjidic_parser(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyKanjidic>()?;
+    m.add_function(wrap_pyfunction!(parse_file, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    Ok(())
+}
+
+fn to_object<T: Serialize + ?Sized>(py: Python, value: &T) -> PyResult<PyObject> {
+    Ok(pythonize(py, value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PyKanjidic;
+    use crate::{db::KanjidicDb, test_shared::CHARACTERS};
+    use pyo3::{prelude::*, types::PyDict};
+
+    #[test]
+    fn from_python() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let kanjidic = Py::new(
+                py,
+                PyKanjidic {
+                    db: KanjidicDb::new(CHARACTERS.clone()),
+                },
+            )
+            .unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("kanjidic", kanjidic).unwrap();
+            py.run(
+                r#"
+assert len(kanjidic) == 200
+assert "亜" in kanjidic
+assert "猫" not in kanjidic
+assert kanjidic.lookup("亜")["literal"] == "亜"
+assert kanjidic.lookup("猫") is None
+assert any(c["literal"] == "亜" for c in kanjidic.by_reading("ア"))
+assert all(c["grade"]["tag"] == "Jouyou" for c in kanjidic.query(grade=8))
+"#,
+                None,
+                Some(locals),
+            )
+            .unwrap();
+        });
+    }
+}
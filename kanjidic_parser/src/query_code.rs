@@ -1,5 +1,7 @@
 use crate::{
-    de_roo, four_corner,
+    de_roo,
+    error_code::ErrorCode,
+    four_corner,
     pos_error::PosError,
     shared::{attr, SharedError},
     skip, spahn_hadamitzky,
@@ -28,30 +30,32 @@ pub enum Error {
     UnknownMisclassification(PosError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::UnknownType(_) => ErrorCode::E0113_UNKNOWN_QUERY_CODE,
+            Error::Skip(err) => err.code(),
+            Error::SpahnHadamitzky(err) => err.code(),
+            Error::FourCorner(err) => err.code(),
+            Error::DeRoo(err) => err.code(),
+            Error::UnknownMisclassification(_) => ErrorCode::E0114_UNKNOWN_MISCLASSIFICATION,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<QueryCode, Error> {
     let qc_type = attr(&node, "qc_type")?;
     match qc_type {
         "skip" => {
             if let Some(misclass_kind) = node.attribute("skip_misclass") {
-                Ok(QueryCode::Misclassification(match misclass_kind {
-                    "posn" => Ok(Misclassification {
-                        kind: MisclassificationKind::Position,
-                        skip: skip::from(node)?,
-                    }),
-                    "stroke_count" => Ok(Misclassification {
-                        kind: MisclassificationKind::StrokeCount,
-                        skip: skip::from(node)?,
-                    }),
-                    "stroke_and_posn" => Ok(Misclassification {
-                        kind: MisclassificationKind::StrokeAndPosition,
-                        skip: skip::from(node)?,
-                    }),
-                    "stroke_diff" => Ok(Misclassification {
-                        kind: MisclassificationKind::Ambiguous,
-                        skip: skip::from(node)?,
-                    }),
-                    _ => Err(Error::UnknownMisclassification(PosError::from(&node))),
-                }?))
+                let kind = MisclassificationKind::from_attribute(misclass_kind)
+                    .ok_or_else(|| Error::UnknownMisclassification(PosError::from(&node)))?;
+                Ok(QueryCode::Misclassification(Misclassification {
+                    kind,
+                    skip: skip::from(node)?,
+                }))
             } else {
                 Ok(QueryCode::Skip(skip::from(node)?))
             }
@@ -68,7 +72,8 @@ mod tests {
     use super::from;
     use crate::test_shared::DOC;
     use kanjidic_types::{
-        skip::{SkipSolid, SolidSubpattern},
+        query_code::{Misclassification, MisclassificationKind},
+        skip::{SkipSolid, SkipVertical, SolidSubpattern},
         QueryCode, Skip,
     };
 
@@ -87,4 +92,30 @@ mod tests {
             })))
         )
     }
+
+    #[test]
+    fn misclassification() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("q_code") && node.has_attribute("skip_misclass"))
+            .unwrap();
+        assert_eq!(
+            from(node),
+            Ok(QueryCode::Misclassification(Misclassification {
+                skip: Skip::Vertical(SkipVertical { top: 1, bottom: 12 }),
+                kind: MisclassificationKind::Position,
+            }))
+        );
+        for kind in [
+            MisclassificationKind::Position,
+            MisclassificationKind::StrokeCount,
+            MisclassificationKind::StrokeAndPosition,
+            MisclassificationKind::Ambiguous,
+        ] {
+            assert_eq!(
+                MisclassificationKind::from_attribute(kind.attribute()),
+                Some(kind)
+            );
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{attr, text_uint, SharedError},
 };
@@ -10,15 +11,27 @@ use std::convert::TryFrom;
 pub enum Error {
     #[error("(Radical) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Radical) Radical is not in a valid range: {0}")]
-    OutOfRange(#[from] TryFromPrimitiveError<KangXi>),
+    #[error("(Radical) Radical is not in a valid range: {0}, {1}")]
+    OutOfRange(PosError, TryFromPrimitiveError<KangXi>),
     #[error("(Radical) Not a recognized radical kind: {0}")]
     Kind(PosError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::OutOfRange(_, _) => ErrorCode::E0108_RADICAL_OUT_OF_RANGE,
+            Error::Kind(_) => ErrorCode::E0106_UNKNOWN_RADICAL,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Radical, Error> {
     let kang_xi_number: u8 = text_uint(&node)?;
-    let kang_xi = KangXi::try_from(kang_xi_number)?;
+    let kang_xi = KangXi::try_from(kang_xi_number)
+        .map_err(|err| Error::OutOfRange(PosError::from(&node), err))?;
     let tag = attr(&node, "rad_type")?;
     match tag {
         "classical" => Ok(Radical {
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("(Radkfile) Kanji listed before any radical on line {0}")]
+    MissingRadical(usize),
+    #[error("(Radkfile) Malformed radical header on line {0}")]
+    Header(usize),
+}
+
+/// A radical and the kanji that contain it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadicalEntry {
+    /// The radical itself.
+    pub radical: char,
+    /// The number of strokes in the radical.
+    pub strokes: u8,
+    /// The kanji containing the radical.
+    pub kanji: Vec<char>,
+}
+
+/// Parses the contents of a RADKFILE or RADKFILE2,
+/// where each radical is introduced by a line like `$ 一 1`
+/// and followed by lines listing the kanji that contain it.
+/// Comments start with `#`.
+pub fn parse(contents: &str) -> Result<Vec<RadicalEntry>, Error> {
+    let mut entries: Vec<RadicalEntry> = vec![];
+    for (i, line) in contents.lines().enumerate() {
+        let line_number = i + 1;
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        match line.strip_prefix('$') {
+            Some(header) => entries.push(header_entry(header).ok_or(Error::Header(line_number))?),
+            None => entries
+                .last_mut()
+                .ok_or(Error::MissingRadical(line_number))?
+                .kanji
+                .extend(line.chars().filter(|c| !c.is_whitespace())),
+        }
+    }
+    Ok(entries)
+}
+
+/// Reads a header like ` 化 2 js01`, whose trailing image or JIS code is optional.
+fn header_entry(header: &str) -> Option<RadicalEntry> {
+    let mut fields = header.split_whitespace();
+    let mut radical = fields.next()?.chars();
+    let strokes = fields.next()?.parse().ok()?;
+    match (radical.next(), radical.next()) {
+        (Some(radical), None) => Some(RadicalEntry {
+            radical,
+            strokes,
+            kanji: vec![],
+        }),
+        _ => None,
+    }
+}
+
+/// Inverts the listings into the components of each kanji,
+/// in the order the radicals appear in the file.
+/// The result can be used as a [`crate::decomposition::DecompositionProvider`].
+pub fn decompositions(entries: &[RadicalEntry]) -> HashMap<char, Vec<char>> {
+    let mut decompositions: HashMap<char, Vec<char>> = HashMap::new();
+    for entry in entries {
+        for &kanji in &entry.kanji {
+            decompositions.entry(kanji).or_default().push(entry.radical);
+        }
+    }
+    decompositions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decompositions, parse, Error};
+
+    const RADKFILE: &str = "#\n# RADKFILE\n#\n\
+        $ 一 1\n亜唖\n娃\n\
+        $ ｜ 1\n亜唖\n\
+        $ 口 3 js01\n唖\n";
+
+    #[test]
+    fn entries() {
+        let entries = parse(RADKFILE).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].radical, '一');
+        assert_eq!(entries[0].strokes, 1);
+        assert_eq!(entries[0].kanji, vec!['亜', '唖', '娃']);
+        assert_eq!(entries[2].strokes, 3);
+    }
+
+    #[test]
+    fn inverted() {
+        let decompositions = decompositions(&parse(RADKFILE).unwrap());
+        assert_eq!(decompositions[&'唖'], vec!['一', '｜', '口']);
+        assert_eq!(decompositions[&'娃'], vec!['一']);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("亜\n"), Err(Error::MissingRadical(1)));
+        assert_eq!(parse("$ 一\n"), Err(Error::Header(1)));
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
+    error_code::ErrorCode,
     kunyomi, pin_yin,
     pos_error::PosError,
     shared::{attr, text, SharedError},
 };
 use kanjidic_types::Reading;
 use roxmltree::Node;
+use std::borrow::Cow;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
 
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum Error {
@@ -18,24 +21,48 @@ pub enum Error {
     Kunyomi(#[from] kunyomi::Error),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::UnrecognizedType(_) => ErrorCode::E0112_UNKNOWN_READING,
+            Error::PinYin(err) => err.code(),
+            Error::Kunyomi(err) => err.code(),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Reading, Error> {
     let r_type = attr(&node, "r_type")?;
     match r_type {
         "pinyin" => Ok(Reading::PinYin(pin_yin::from(node)?)),
         "korean_r" => Ok(Reading::KoreanRomanized(text(&node)?.into())),
         "korean_h" => Ok(Reading::KoreanHangul(text(&node)?.into())),
-        "vietnam" => Ok(Reading::Vietnam(text(&node)?.into())),
+        "vietnam" => Ok(Reading::Vietnam(nfc(text(&node)?).into_owned())),
         "ja_on" => Ok(Reading::Onyomi(text(&node)?.into())),
         "ja_kun" => Ok(Reading::Kunyomi(kunyomi::from(node)?)),
         _ => Err(Error::UnrecognizedType(PosError::from(&node))),
     }
 }
 
+/// Normalizes the text to NFC, borrowing it if it is already normalized.
+/// Vietnamese readings mix precomposed and combining diacritics,
+/// so they are normalized for comparisons to work.
+pub(crate) fn nfc(text: &str) -> Cow<'_, str> {
+    match is_nfc_quick(text.chars()) {
+        IsNormalized::Yes => Cow::Borrowed(text),
+        _ => Cow::Owned(text.nfc().collect()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::from;
     use crate::test_shared::DOC;
-    use kanjidic_types::{pin_yin::Tone, PinYin, Reading};
+    use kanjidic_types::{pin_yin::Tone, Kunyomi, PinYin, Reading};
+    use std::convert::TryFrom;
+    use unicode_normalization::is_nfc;
 
     #[test]
     fn reading() {
@@ -52,4 +79,39 @@ mod tests {
             }))
         )
     }
+
+    #[test]
+    fn romaji() {
+        let romaji = |kana: &str| Reading::Onyomi(kana.to_owned()).to_romaji().unwrap();
+        assert_eq!(romaji("ア"), "a");
+        assert_eq!(romaji("コウ"), "kō");
+        assert_eq!(romaji("キュウ"), "kyū");
+        assert_eq!(romaji("シャ"), "sha");
+        assert_eq!(romaji("ジョウ"), "jō");
+        assert_eq!(romaji("ガッ"), "ga");
+        assert_eq!(romaji("ハッポウ"), "happō");
+        assert_eq!(romaji("マッチャ"), "matcha");
+        assert_eq!(romaji("キンエン"), "kin'en");
+        assert_eq!(romaji("ケイ"), "kei");
+
+        let kunyomi = Reading::Kunyomi(Kunyomi::try_from("つ.ぐ").unwrap());
+        assert_eq!(kunyomi.to_romaji().as_deref(), Some("tsu.gu"));
+        let kunyomi = Reading::Kunyomi(Kunyomi::try_from("おお.きい").unwrap());
+        assert_eq!(kunyomi.to_romaji().as_deref(), Some("ō.kii"));
+        assert_eq!(Reading::Vietnam("A".to_owned()).to_romaji(), None);
+    }
+
+    #[test]
+    fn vietnam_nfc() {
+        let readings = DOC
+            .descendants()
+            .filter(|node| node.attribute("r_type") == Some("vietnam"))
+            .map(|node| from(node).unwrap());
+        for reading in readings {
+            match reading {
+                Reading::Vietnam(text) => assert!(is_nfc(&text), "{} is not NFC", text),
+                _ => unreachable!(),
+            }
+        }
+    }
 }
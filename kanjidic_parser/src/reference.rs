@@ -1,9 +1,11 @@
 use crate::{
-    busy_people, moro, oneill,
+    busy_people,
+    error_code::ErrorCode,
+    moro, oneill,
     pos_error::PosError,
     shared::{attr, text_uint, SharedError},
 };
-use kanjidic_types::Reference;
+use kanjidic_types::{Reference, ReferenceKind};
 use roxmltree::Node;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
@@ -20,33 +22,84 @@ pub enum Error {
     Oneill(#[from] oneill::Error),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::UnknownType(_) => ErrorCode::E0107_UNKNOWN_DIC_REF,
+            Error::Moro(err) => err.code(),
+            Error::BusyPeople(err) => err.code(),
+            Error::Oneill(err) => err.code(),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Reference, Error> {
-    match attr(&node, "dr_type")? {
-        "nelson_c" => Ok(Reference::NelsonClassic(text_uint(&node)?)),
-        "nelson_n" => Ok(Reference::NelsonNew(text_uint(&node)?)),
-        "halpern_njecd" => Ok(Reference::Njecd(text_uint(&node)?)),
-        "halpern_kkd" => Ok(Reference::Kkd(text_uint(&node)?)),
-        "halpern_kkld" => Ok(Reference::Kkld(text_uint(&node)?)),
-        "halpern_kkld_2ed" => Ok(Reference::Kkld2ed(text_uint(&node)?)),
-        "heisig" => Ok(Reference::Heisig(text_uint(&node)?)),
-        "heisig6" => Ok(Reference::Heisig6(text_uint(&node)?)),
-        "gakken" => Ok(Reference::Gakken(text_uint(&node)?)),
-        "oneill_names" => Ok(Reference::OneillNames(oneill::from(node)?)),
-        "oneill_kk" => Ok(Reference::OneillKk(text_uint(&node)?)),
-        "moro" => Ok(Reference::Moro(moro::from(node)?)),
-        "henshall" => Ok(Reference::Henshall(text_uint(&node)?)),
-        "sh_kk" => Ok(Reference::ShKk(text_uint(&node)?)),
-        "sh_kk2" => Ok(Reference::ShKk2(text_uint(&node)?)),
-        "sakade" => Ok(Reference::Sakade(text_uint(&node)?)),
-        "jf_cards" => Ok(Reference::Jfcards(text_uint(&node)?)),
-        "henshall3" => Ok(Reference::Henshall3(text_uint(&node)?)),
-        "tutt_cards" => Ok(Reference::TuttleCards(text_uint(&node)?)),
-        "crowley" => Ok(Reference::Crowley(text_uint(&node)?)),
-        "kanji_in_context" => Ok(Reference::KanjiInContext(text_uint(&node)?)),
-        "busy_people" => Ok(Reference::BusyPeople(busy_people::from(node)?)),
-        "kodansha_compact" => Ok(Reference::KodanshaCompact(text_uint(&node)?)),
-        "maniette" => Ok(Reference::Maniette(text_uint(&node)?)),
-        _ => Err(Error::UnknownType(PosError::from(&node))),
+    let kind: ReferenceKind = attr(&node, "dr_type")?
+        .parse()
+        .map_err(|_| Error::UnknownType(PosError::from(&node)))?;
+    Ok(match kind {
+        ReferenceKind::NelsonClassic => Reference::NelsonClassic(text_uint(&node)?),
+        ReferenceKind::NelsonNew => Reference::NelsonNew(text_uint(&node)?),
+        ReferenceKind::Njecd => Reference::Njecd(text_uint(&node)?),
+        ReferenceKind::Kkd => Reference::Kkd(text_uint(&node)?),
+        ReferenceKind::Kkld => Reference::Kkld(text_uint(&node)?),
+        ReferenceKind::Kkld2ed => Reference::Kkld2ed(text_uint(&node)?),
+        ReferenceKind::Heisig => Reference::Heisig(text_uint(&node)?),
+        ReferenceKind::Heisig6 => Reference::Heisig6(text_uint(&node)?),
+        ReferenceKind::Gakken => Reference::Gakken(text_uint(&node)?),
+        ReferenceKind::OneillNames => Reference::OneillNames(oneill::from(node)?),
+        ReferenceKind::OneillKk => Reference::OneillKk(text_uint(&node)?),
+        ReferenceKind::Moro => Reference::Moro(moro::from(node)?),
+        ReferenceKind::Henshall => Reference::Henshall(text_uint(&node)?),
+        ReferenceKind::ShKk => Reference::ShKk(text_uint(&node)?),
+        ReferenceKind::ShKk2 => Reference::ShKk2(text_uint(&node)?),
+        ReferenceKind::Sakade => Reference::Sakade(text_uint(&node)?),
+        ReferenceKind::Jfcards => Reference::Jfcards(text_uint(&node)?),
+        ReferenceKind::Henshall3 => Reference::Henshall3(text_uint(&node)?),
+        ReferenceKind::TuttleCards => Reference::TuttleCards(text_uint(&node)?),
+        ReferenceKind::Crowley => Reference::Crowley(text_uint(&node)?),
+        ReferenceKind::KanjiInContext => Reference::KanjiInContext(text_uint(&node)?),
+        ReferenceKind::BusyPeople => Reference::BusyPeople(busy_people::from(node)?),
+        ReferenceKind::KodanshaCompact => Reference::KodanshaCompact(text_uint(&node)?),
+        ReferenceKind::Maniette => Reference::Maniette(text_uint(&node)?),
+    })
+}
+
+/// The dr_type attribute identifying the kind of reference.
+pub fn dr_type(reference: &Reference) -> &'static str {
+    reference.kind().dr_type()
+}
+
+/// The text content of the dic_ref element for the reference.
+/// Morohashi volume and page numbers are attributes and so are not included.
+pub fn text(reference: &Reference) -> String {
+    match reference {
+        Reference::OneillNames(oneill) => oneill.to_string(),
+        Reference::Moro(moro) => moro.compact(),
+        Reference::BusyPeople(busy_people) => busy_people.to_string(),
+        Reference::NelsonClassic(n)
+        | Reference::NelsonNew(n)
+        | Reference::Njecd(n)
+        | Reference::Kkd(n)
+        | Reference::Kkld(n)
+        | Reference::Kkld2ed(n)
+        | Reference::Heisig(n)
+        | Reference::Heisig6(n)
+        | Reference::Gakken(n)
+        | Reference::OneillKk(n)
+        | Reference::Henshall(n)
+        | Reference::ShKk(n)
+        | Reference::ShKk2(n)
+        | Reference::Sakade(n)
+        | Reference::Jfcards(n)
+        | Reference::Henshall3(n)
+        | Reference::TuttleCards(n)
+        | Reference::Crowley(n)
+        | Reference::KanjiInContext(n)
+        | Reference::KodanshaCompact(n)
+        | Reference::Maniette(n) => n.to_string(),
     }
 }
 
@@ -54,7 +107,10 @@ pub fn from(node: Node) -> Result<Reference, Error> {
 mod tests {
     use super::from;
     use crate::test_shared::DOC;
-    use kanjidic_types::Reference;
+    use kanjidic_types::{
+        busy_people::BusyPeople, moro::MoroSuffix, Moro, Reference, ReferenceKind,
+    };
+    use std::convert::TryFrom;
 
     #[test]
     fn dictionary_reference() {
@@ -65,4 +121,37 @@ mod tests {
         let dictionary_reference = from(node);
         assert_eq!(dictionary_reference, Ok(Reference::NelsonClassic(43)))
     }
+
+    #[test]
+    fn book_title() {
+        let heisig = Reference::Heisig6(1234);
+        assert_eq!(heisig.book_title(), "Remembering the Kanji, 6th edition");
+        assert_eq!(
+            heisig.to_string(),
+            "Remembering the Kanji, 6th edition: 1234"
+        );
+
+        let moro = Reference::Moro(Moro {
+            volume: Some(1),
+            page: Some(525),
+            index: 272,
+            suffix: MoroSuffix::None,
+        });
+        assert_eq!(moro.to_string(), "Dai Kan-Wa Jiten: v1 p525 #272");
+
+        let busy_people = Reference::BusyPeople(BusyPeople::try_from("2.15").unwrap());
+        assert_eq!(busy_people.to_string(), "Japanese for Busy People: 2.15");
+    }
+
+    #[test]
+    fn kind() {
+        for kind in ReferenceKind::ALL.iter() {
+            assert_eq!(kind.dr_type().parse(), Ok(*kind));
+        }
+        assert_eq!(
+            Reference::NelsonClassic(43).kind(),
+            ReferenceKind::NelsonClassic
+        );
+        assert!("nelson".parse::<ReferenceKind>().is_err());
+    }
 }
@@ -0,0 +1,38 @@
+use crate::list;
+use std::collections::HashMap;
+
+pub use list::Error;
+
+/// The edition of Remembering the Kanji that a keyword list follows,
+/// which decides the dictionary reference its frame numbers are matched against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edition {
+    /// Earlier editions, numbered by `Reference::Heisig`.
+    Classic,
+    /// The sixth edition, numbered by `Reference::Heisig6`.
+    Sixth,
+}
+
+/// Parses a Heisig keyword list into the keyword for each frame number,
+/// in the format read by [`list::parse`], such as `1809,Asia`.
+/// Keywords may contain commas.
+pub fn parse(contents: &str) -> Result<HashMap<u16, String>, Error> {
+    list::parse(
+        contents,
+        |frame| frame.parse().ok(),
+        |keyword| Some(keyword.to_owned()).filter(|keyword| !keyword.is_empty()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Error};
+
+    #[test]
+    fn keywords() {
+        let keywords = parse("frame,keyword\n1809\tAsia\n2000,ditto, again\n").unwrap();
+        assert_eq!(keywords[&1809], "Asia");
+        assert_eq!(keywords[&2000], "ditto, again");
+        assert_eq!(parse("1,one\n2,\n"), Err(Error::Value(2)));
+    }
+}
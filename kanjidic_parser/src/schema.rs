@@ -0,0 +1,152 @@
+use roxmltree::{Document, Node, TextPos};
+
+/// Part of the document that the parser does not know about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unrecognized {
+    /// An element that is not expected inside its parent.
+    Element {
+        position: TextPos,
+        parent: String,
+        name: String,
+    },
+    /// An attribute that is not expected on its element.
+    Attribute {
+        position: TextPos,
+        element: String,
+        name: String,
+    },
+}
+
+/// The child elements and attributes expected for each element in KANJIDIC2.
+fn expected(tag: &str) -> Option<(&'static [&'static str], &'static [&'static str])> {
+    let expected: (&[&str], &[&str]) = match tag {
+        "kanjidic2" => (&["header", "character"], &[]),
+        "header" => (
+            &["file_version", "database_version", "date_of_creation"],
+            &[],
+        ),
+        "character" => (
+            &[
+                "literal",
+                "codepoint",
+                "radical",
+                "misc",
+                "dic_number",
+                "query_code",
+                "reading_meaning",
+            ],
+            &[],
+        ),
+        "codepoint" => (&["cp_value"], &[]),
+        "cp_value" => (&[], &["cp_type"]),
+        "radical" => (&["rad_value"], &[]),
+        "rad_value" => (&[], &["rad_type"]),
+        "misc" => (
+            &[
+                "grade",
+                "stroke_count",
+                "variant",
+                "freq",
+                "rad_name",
+                "jlpt",
+            ],
+            &[],
+        ),
+        "variant" => (&[], &["var_type"]),
+        "dic_number" => (&["dic_ref"], &[]),
+        "dic_ref" => (&[], &["dr_type", "m_vol", "m_page"]),
+        "query_code" => (&["q_code"], &[]),
+        "q_code" => (&[], &["qc_type", "skip_misclass"]),
+        "reading_meaning" => (&["rmgroup", "nanori"], &[]),
+        "rmgroup" => (&["reading", "meaning"], &[]),
+        "reading" => (&[], &["r_type", "on_type", "r_status"]),
+        "meaning" => (&[], &["m_lang"]),
+        "literal" | "file_version" | "database_version" | "date_of_creation" | "grade"
+        | "stroke_count" | "freq" | "rad_name" | "jlpt" | "nanori" => (&[], &[]),
+        _ => return None,
+    };
+    Some(expected)
+}
+
+/// Finds the elements and attributes in the document that the parser would ignore.
+pub fn unrecognized_in(doc: &Document) -> Vec<Unrecognized> {
+    let mut out = vec![];
+    check(doc.root_element(), &mut out);
+    out
+}
+
+/// Finds the elements and attributes within a `<character>` node that the parser would ignore.
+pub fn unrecognized(character: Node) -> Vec<Unrecognized> {
+    let mut out = vec![];
+    check(character, &mut out);
+    out
+}
+
+fn check(node: Node, out: &mut Vec<Unrecognized>) {
+    let tag = node.tag_name().name();
+    let (children, attributes) = match expected(tag) {
+        Some(expected) => expected,
+        None => return,
+    };
+    for attribute in node.attributes() {
+        if !attributes.contains(&attribute.name()) {
+            out.push(Unrecognized::Attribute {
+                position: position(&node),
+                element: tag.to_owned(),
+                name: attribute.name().to_owned(),
+            });
+        }
+    }
+    for child in node.children().filter(|child| child.is_element()) {
+        let name = child.tag_name().name();
+        if children.contains(&name) {
+            check(child, out);
+        } else {
+            out.push(Unrecognized::Element {
+                position: position(&child),
+                parent: tag.to_owned(),
+                name: name.to_owned(),
+            });
+        }
+    }
+}
+
+fn position(node: &Node) -> TextPos {
+    node.document().text_pos_at(node.range().start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unrecognized, unrecognized_in, Unrecognized};
+    use crate::test_shared::DOC;
+    use roxmltree::{Document, TextPos};
+
+    #[test]
+    fn release_is_recognized() {
+        assert_eq!(unrecognized_in(&DOC), vec![]);
+    }
+
+    #[test]
+    fn reports_unknown_data() {
+        let xml = "<character>\n\
+            <literal>亜</literal>\n\
+            <misc><stroke_count extra=\"1\">7</stroke_count><mystery/></misc>\n\
+            </character>";
+        let doc = Document::parse(xml).unwrap();
+        assert_eq!(
+            unrecognized(doc.root_element()),
+            vec![
+                Unrecognized::Attribute {
+                    position: TextPos::new(3, 7),
+                    element: "stroke_count".to_owned(),
+                    name: "extra".to_owned(),
+                },
+                Unrecognized::Element {
+                    position: TextPos::new(3, 47),
+                    parent: "misc".to_owned(),
+                    name: "mystery".to_owned(),
+                },
+            ]
+        );
+    }
+}
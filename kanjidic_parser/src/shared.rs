@@ -1,4 +1,4 @@
-use crate::pos_error::PosError;
+use crate::{error_code::ErrorCode, pos_error::PosError};
 use roxmltree::Node;
 use std::str::FromStr;
 
@@ -18,6 +18,32 @@ pub enum SharedError {
     Hex(PosError),
 }
 
+impl SharedError {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            SharedError::MissingChild(_, _) => ErrorCode::E0010_MISSING_CHILD,
+            SharedError::NoText(_) => ErrorCode::E0011_MISSING_TEXT,
+            SharedError::TextUint(_) => ErrorCode::E0012_INVALID_TEXT_UINT,
+            SharedError::AttrUint(_) => ErrorCode::E0013_INVALID_ATTRIBUTE_UINT,
+            SharedError::MissingAttribute(_, _) => ErrorCode::E0014_MISSING_ATTRIBUTE,
+            SharedError::Hex(_) => ErrorCode::E0015_INVALID_HEX,
+        }
+    }
+
+    /// Where in the document the failure happened.
+    pub fn position(&self) -> &PosError {
+        match self {
+            SharedError::MissingChild(pos, _)
+            | SharedError::NoText(pos)
+            | SharedError::TextUint(pos)
+            | SharedError::AttrUint(pos)
+            | SharedError::MissingAttribute(pos, _)
+            | SharedError::Hex(pos) => pos,
+        }
+    }
+}
+
 pub fn children<'a, 'input, T, E, F>(
     node: &Node<'a, 'input>,
     tag: &'static str,
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{text, SharedError},
 };
@@ -14,6 +15,16 @@ pub enum SkipError {
     Parse(PosError, skip::ParseError),
 }
 
+impl SkipError {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            SkipError::Shared(err) => err.code(),
+            SkipError::Parse(_, _) => ErrorCode::E0120_INVALID_SKIP,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Skip, SkipError> {
     let text = text(&node)?;
     Skip::try_from(text).map_err(|err| SkipError::Parse(PosError::from(&node), err))
@@ -24,7 +35,7 @@ mod tests {
     use super::from;
     use crate::test_shared::DOC;
     use kanjidic_types::{
-        skip::{SkipSolid, SolidSubpattern},
+        skip::{SkipHorizontal, SkipSolid, SolidSubpattern},
         Skip,
     };
 
@@ -49,4 +60,20 @@ mod tests {
             }))
         )
     }
+
+    #[test]
+    fn notation() {
+        let skip: Skip = "1-4-3".parse().unwrap();
+        assert_eq!(skip, Skip::Horizontal(SkipHorizontal { left: 4, right: 3 }));
+        assert_eq!(skip.to_string(), "1-4-3");
+        let solid = Skip::Solid(SkipSolid {
+            total_stroke_count: 7,
+            solid_subpattern: SolidSubpattern::TopLine,
+        });
+        assert_eq!(solid.to_string(), "4-7-1");
+        assert_eq!(" 4-7-1 ".parse(), Ok(solid));
+        assert!("5-1-1".parse::<Skip>().is_err());
+        assert!("4-7-5".parse::<Skip>().is_err());
+        assert!("1-4-3-2".parse::<Skip>().is_err());
+    }
 }
@@ -0,0 +1,104 @@
+use crate::kanjidic::Kanjidic;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+/// Identifies a file as a dictionary snapshot.
+pub const MAGIC: [u8; 8] = *b"KANJIDIC";
+
+/// The version of the snapshot layout. This changes whenever
+/// the encoded types change in an incompatible way.
+pub const SCHEMA_VERSION: u32 = 7;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("(Snapshot) Io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("(Snapshot) Encoding: {0}")]
+    Encode(#[from] bincode::error::EncodeError),
+    #[error("(Snapshot) Decoding: {0}")]
+    Decode(#[from] bincode::error::DecodeError),
+    #[error("(Snapshot) Not a dictionary snapshot")]
+    NotASnapshot,
+    #[error(
+        "(Snapshot) Snapshot has schema version {0} but {required} is required",
+        required = SCHEMA_VERSION
+    )]
+    IncompatibleVersion(u32),
+}
+
+impl Kanjidic {
+    /// Saves the dictionary to a snapshot file that can be read back with [`Kanjidic::load_snapshot`].
+    pub fn save_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        write(self, BufWriter::new(File::create(path)?))
+    }
+
+    /// Loads a dictionary from a snapshot file,
+    /// refusing snapshots written with a different schema version.
+    pub fn load_snapshot<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        read(BufReader::new(File::open(path)?))
+    }
+}
+
+/// Writes a dictionary snapshot.
+pub fn write<W: Write>(kanjidic: &Kanjidic, mut writer: W) -> Result<(), Error> {
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&SCHEMA_VERSION.to_le_bytes())?;
+    bincode::encode_into_std_write(kanjidic, &mut writer, bincode::config::standard())?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads a dictionary snapshot.
+pub fn read<R: Read>(mut reader: R) -> Result<Kanjidic, Error> {
+    let mut magic = [0u8; 8];
+    reader
+        .read_exact(&mut magic)
+        .map_err(|_| Error::NotASnapshot)?;
+    if magic != MAGIC {
+        return Err(Error::NotASnapshot);
+    }
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    let version = u32::from_le_bytes(version);
+    if version != SCHEMA_VERSION {
+        return Err(Error::IncompatibleVersion(version));
+    }
+    Ok(bincode::decode_from_std_read(
+        &mut reader,
+        bincode::config::standard(),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, write, Error, MAGIC};
+    use crate::test_shared::KANJIDIC;
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = vec![];
+        write(&KANJIDIC, &mut bytes).unwrap();
+        assert_eq!(read(bytes.as_slice()).unwrap(), *KANJIDIC);
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        assert!(matches!(
+            read(bytes.as_slice()),
+            Err(Error::IncompatibleVersion(0))
+        ));
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(matches!(
+            read(&b"<kanjidic2>"[..]),
+            Err(Error::NotASnapshot)
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{self, SharedError},
 };
@@ -14,6 +15,16 @@ pub enum ShError {
     Parse(PosError, spahn_hadamitzky::descriptor::ParseError),
 }
 
+impl ShError {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ShError::Shared(err) => err.code(),
+            ShError::Parse(_, _) => ErrorCode::E0121_INVALID_SPAHN_HADAMITZKY,
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<ShDesc, ShError> {
     let text = shared::text(&node)?;
     ShDesc::try_from(text).map_err(|err| ShError::Parse(PosError::from(&node), err))
@@ -23,7 +34,7 @@ pub fn from(node: Node) -> Result<ShDesc, ShError> {
 mod tests {
     use super::from;
     use crate::test_shared::DOC;
-    use kanjidic_types::ShDesc;
+    use kanjidic_types::{ShDesc, ShRadical};
 
     #[test]
     fn spahn_hadamitzky() {
@@ -42,10 +53,27 @@ mod tests {
             sh,
             Ok(ShDesc {
                 radical_strokes: 2,
-                radical: kanjidic_types::ShRadical::K,
+                radical: ShRadical::K,
                 other_strokes: 4,
                 sequence: 6,
             })
         )
     }
+
+    #[test]
+    fn notation() {
+        let descriptor: ShDesc = " 0a7.14 ".parse().unwrap();
+        assert_eq!(
+            descriptor,
+            ShDesc {
+                radical_strokes: 0,
+                radical: ShRadical::A,
+                other_strokes: 7,
+                sequence: 14,
+            }
+        );
+        assert_eq!(descriptor.to_string(), "0a7.14");
+        assert!("0a7.14x".parse::<ShDesc>().is_err());
+        assert!("0A7.14".parse::<ShDesc>().is_err());
+    }
 }
@@ -0,0 +1,122 @@
+use crate::{kanjidic::Kanjidic, reference};
+use kanjidic_types::{Character, Reading};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// A summary of the contents of the dictionary.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct KanjidicStats {
+    /// The total number of characters.
+    pub characters: usize,
+    /// The number of characters in each grade, keyed by the grade's number in KANJIDIC2
+    /// as given by [`kanjidic_types::Grade::to_raw`].
+    pub by_grade: BTreeMap<u8, usize>,
+    /// The number of characters at each old JLPT level.
+    pub by_jlpt: BTreeMap<u8, usize>,
+    /// The number of characters with each accepted stroke count.
+    pub by_stroke_count: BTreeMap<u8, usize>,
+    /// The number of characters with at least one reference of each type,
+    /// keyed by the KANJIDIC2 dr_type name.
+    pub reference_coverage: BTreeMap<&'static str, usize>,
+    /// The number of characters with no onyomi or kunyomi.
+    pub missing_readings: usize,
+    /// The number of characters with no frequency ranking.
+    pub missing_frequency: usize,
+}
+
+impl KanjidicStats {
+    /// Summarizes the given characters.
+    pub fn new(characters: &[Character]) -> Self {
+        let mut stats = Self {
+            characters: characters.len(),
+            ..Self::default()
+        };
+        for character in characters {
+            if let Some(grade) = character.grade {
+                *stats.by_grade.entry(grade.to_raw()).or_insert(0) += 1;
+            }
+            if let Some(jlpt) = character.jlpt {
+                *stats.by_jlpt.entry(jlpt).or_insert(0) += 1;
+            }
+            *stats
+                .by_stroke_count
+                .entry(character.stroke_counts.accepted)
+                .or_insert(0) += 1;
+            let mut seen: Vec<&'static str> = character
+                .references
+                .iter()
+                .map(reference::dr_type)
+                .collect();
+            seen.sort_unstable();
+            seen.dedup();
+            for name in seen {
+                *stats.reference_coverage.entry(name).or_insert(0) += 1;
+            }
+            if !character.readings.iter().any(is_japanese) {
+                stats.missing_readings += 1;
+            }
+            if character.frequency.is_none() {
+                stats.missing_frequency += 1;
+            }
+        }
+        stats
+    }
+}
+
+impl Kanjidic {
+    /// Summarizes the dictionary's characters.
+    pub fn stats(&self) -> KanjidicStats {
+        KanjidicStats::new(&self.characters)
+    }
+}
+
+fn is_japanese(reading: &Reading) -> bool {
+    matches!(reading, Reading::Onyomi(_) | Reading::Kunyomi(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KanjidicStats;
+    use crate::test_shared::{CHARACTERS, KANJIDIC};
+    use kanjidic_types::Grade;
+
+    #[test]
+    fn counts() {
+        let stats = KANJIDIC.stats();
+        assert_eq!(stats.characters, CHARACTERS.len());
+        assert_eq!(
+            stats.by_stroke_count.values().sum::<usize>(),
+            CHARACTERS.len()
+        );
+        assert_eq!(
+            stats.by_grade.get(&Grade::Jouyou.to_raw()).copied(),
+            Some(
+                CHARACTERS
+                    .iter()
+                    .filter(|character| character.grade == Some(Grade::Jouyou))
+                    .count()
+            )
+        );
+        assert_eq!(
+            stats.missing_frequency,
+            CHARACTERS
+                .iter()
+                .filter(|character| character.frequency.is_none())
+                .count()
+        );
+        assert!(stats.reference_coverage["heisig"] > 0);
+    }
+
+    #[test]
+    fn json() {
+        let stats = KANJIDIC.stats();
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["by_grade"]["8"], stats.by_grade[&8]);
+        assert_eq!(json["characters"], CHARACTERS.len());
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(KanjidicStats::new(&[]), KanjidicStats::default());
+    }
+}
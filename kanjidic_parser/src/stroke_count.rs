@@ -1,4 +1,5 @@
 use crate::{
+    error_code::ErrorCode,
     pos_error::PosError,
     shared::{text_uint, SharedError},
 };
@@ -11,8 +12,18 @@ pub enum Error {
     Shared(#[from] SharedError),
     #[error("(Stroke count) Expected at least one entry: {0}")]
     Accepted(PosError),
-    #[error("(Stroke count) Not all fields specified")]
-    Incomplete,
+    #[error("(Stroke count) Not all fields specified: {0}")]
+    Incomplete(PosError),
+}
+
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+            Error::Accepted(_) | Error::Incomplete(_) => ErrorCode::E0110_MISSING_STROKE_COUNT,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -35,8 +46,11 @@ impl StrokeCountBuilder {
         Ok(())
     }
 
-    pub fn build(self) -> Result<StrokeCount, Error> {
-        let accepted = self.accepted.ok_or(Error::Incomplete)?;
+    /// Finishes the stroke count, given the node that contained the entries.
+    pub fn build(self, parent: &Node) -> Result<StrokeCount, Error> {
+        let accepted = self
+            .accepted
+            .ok_or_else(|| Error::Incomplete(PosError::from(parent)))?;
         Ok(StrokeCount {
             accepted,
             miscounts: self.miscounts,
@@ -83,4 +97,24 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn miscounts() {
+        let count = StrokeCount {
+            accepted: 7,
+            miscounts: vec![6, 8],
+        };
+        assert!(count.is_disputed());
+        assert_eq!(count.all_counts().collect::<Vec<_>>(), vec![7, 6, 8]);
+        assert!(count.matches(7));
+        assert!(count.matches(8));
+        assert!(!count.matches(9));
+
+        let count = StrokeCount {
+            accepted: 7,
+            miscounts: vec![],
+        };
+        assert!(!count.is_disputed());
+        assert!(!count.matches(6));
+    }
 }
@@ -0,0 +1,110 @@
+use crate::kanjidic::Kanjidic;
+use kanjidic_types::{Character, Grade};
+use std::str::FromStr;
+
+/// A commonly studied part of the dictionary,
+/// for applications that only need to ship a fraction of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Subset {
+    /// The kyouiku and other jouyou kanji.
+    Jouyou,
+    /// Characters with an old JLPT level.
+    Jlpt,
+    /// Characters ranked within the given number of the most frequent.
+    TopFrequency(u16),
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("(Subset) Expected jouyou, jlpt, or top-N: {0}")]
+pub struct ParseError(String);
+
+impl Subset {
+    /// Whether the character belongs to the subset.
+    pub fn contains(&self, character: &Character) -> bool {
+        match self {
+            Subset::Jouyou => matches!(
+                character.grade,
+                Some(Grade::Kyouiku(_)) | Some(Grade::Jouyou)
+            ),
+            Subset::Jlpt => character.jlpt.is_some(),
+            Subset::TopFrequency(count) => character
+                .frequency
+                .is_some_and(|frequency| frequency <= *count),
+        }
+    }
+}
+
+impl FromStr for Subset {
+    type Err = ParseError;
+
+    /// Reads a subset written as `jouyou`, `jlpt`, or `top-2500`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jouyou" => Ok(Subset::Jouyou),
+            "jlpt" => Ok(Subset::Jlpt),
+            _ => s
+                .strip_prefix("top-")
+                .and_then(|count| count.parse().ok())
+                .map(Subset::TopFrequency)
+                .ok_or_else(|| ParseError(s.to_owned())),
+        }
+    }
+}
+
+impl Kanjidic {
+    /// A copy of the dictionary holding only the characters in any of the given subsets,
+    /// such as for passing to [`crate::snapshot::write`] or `embed::embed`.
+    pub fn subset(&self, subsets: &[Subset]) -> Kanjidic {
+        let characters: Vec<Character> = self
+            .characters
+            .iter()
+            .filter(|character| subsets.iter().any(|subset| subset.contains(character)))
+            .cloned()
+            .collect();
+        let supplementary = self
+            .supplementary
+            .iter()
+            .copied()
+            .filter(|literal| characters.iter().any(|c| c.literal == *literal))
+            .collect();
+        let keywords = self
+            .keywords
+            .iter()
+            .filter(|(literal, _)| characters.iter().any(|c| c.literal_str() == **literal))
+            .map(|(literal, keyword)| (literal.clone(), keyword.clone()))
+            .collect();
+        Kanjidic {
+            header: self.header.clone(),
+            characters,
+            supplementary,
+            keywords,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Subset;
+    use crate::test_shared::KANJIDIC;
+
+    #[test]
+    fn parsing() {
+        assert_eq!("jouyou".parse::<Subset>(), Ok(Subset::Jouyou));
+        assert_eq!("top-500".parse::<Subset>(), Ok(Subset::TopFrequency(500)));
+        assert!("top-".parse::<Subset>().is_err());
+    }
+
+    #[test]
+    fn filtered() {
+        let subset = KANJIDIC.subset(&[Subset::Jouyou]);
+        assert!(!subset.characters.is_empty());
+        assert!(subset.characters.len() < KANJIDIC.characters.len());
+        assert!(subset.characters.iter().all(|c| Subset::Jouyou.contains(c)));
+        assert_eq!(subset.header, KANJIDIC.header);
+        let frequent = KANJIDIC.subset(&[Subset::TopFrequency(500)]);
+        assert!(frequent
+            .characters
+            .iter()
+            .all(|c| c.frequency.is_some_and(|f| f <= 500)));
+    }
+}
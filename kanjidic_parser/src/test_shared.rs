@@ -1,3 +1,5 @@
+use crate::{character, header, kanjidic::Kanjidic};
+use kanjidic_types::Character;
 use roxmltree::Document;
 
 lazy_static! {
@@ -8,4 +10,49 @@ lazy_static! {
         let skipped = std::str::from_utf8(&xml.as_bytes()[start..]).unwrap();
         roxmltree::Document::parse(skipped).unwrap()
     };
+
+    /// The first few hundred characters of the dictionary, for tests
+    /// that need more than a single node but not the whole file.
+    pub static ref CHARACTERS: Vec<Character> = DOC
+        .root_element()
+        .children()
+        .filter(|child| child.has_tag_name("character"))
+        .take(200)
+        .map(|node| character::from(node).unwrap())
+        .collect();
+
+    /// A dictionary made up of the real header and the test characters.
+    pub static ref KANJIDIC: Kanjidic = {
+        let header = DOC
+            .root_element()
+            .children()
+            .find(|child| child.has_tag_name("header"))
+            .unwrap();
+        Kanjidic {
+            header: header::from(header).unwrap(),
+            characters: CHARACTERS.clone(),
+            supplementary: vec![],
+            keywords: Default::default(),
+        }
+    };
+}
+
+/// The test characters with the decompositions of 亜 and 唖 written out,
+/// so that tests of component lookups pass without the `decomposition` feature.
+pub fn decomposed() -> Vec<Character> {
+    let mut characters = CHARACTERS.clone();
+    characters[0].decomposition = vec!['｜', '一', '口'];
+    characters[1].decomposition = vec!['｜', '一', '口'];
+    characters
+}
+
+/// An empty directory for a single test to write files in.
+/// The process ID keeps concurrent test runs apart.
+#[cfg(any(feature = "chise", feature = "embed", feature = "unihan"))]
+pub fn temp_dir(test: &str) -> std::path::PathBuf {
+    let name = format!("kanjidic_parser_{}_{}", std::process::id(), test);
+    let dir = std::env::temp_dir().join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
 }
@@ -1,5 +1,8 @@
-use crate::shared::{self, SharedError};
-use kanjidic_types::character::Translations;
+use crate::{
+    error_code::ErrorCode,
+    shared::{self, SharedError},
+};
+use kanjidic_types::{character::Translations, Language};
 use roxmltree::Node;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
@@ -8,14 +11,34 @@ pub enum Error {
     Shared(#[from] SharedError),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::Shared(err) => err.code(),
+        }
+    }
+}
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// The language code of a meaning node, from `m_lang` or `xml:lang`,
+/// which defaults to English.
+pub fn language<'a>(meaning: &Node<'a, '_>) -> &'a str {
+    meaning
+        .attribute("m_lang")
+        .or_else(|| meaning.attribute((XML_NAMESPACE, "lang")))
+        .unwrap_or("en")
+}
+
 pub fn add_meaning(translations: &mut Translations, meaning: &Node) -> Result<(), Error> {
     let text = shared::text(meaning)?.to_owned();
-    let language = meaning.attribute("m_lang").unwrap_or("en").to_owned();
+    let language = Language::from(language(meaning));
     match translations.entry(language) {
-        std::collections::hash_map::Entry::Occupied(mut entry) => {
+        std::collections::btree_map::Entry::Occupied(mut entry) => {
             entry.get_mut().push(text);
         }
-        std::collections::hash_map::Entry::Vacant(entry) => {
+        std::collections::btree_map::Entry::Vacant(entry) => {
             entry.insert(vec![text]);
         }
     }
@@ -26,9 +49,9 @@ pub fn add_meaning(translations: &mut Translations, meaning: &Node) -> Result<()
 mod tests {
     use super::{add_meaning, Error};
     use crate::test_shared::DOC;
-    use kanjidic_types::character::Translations;
+    use kanjidic_types::{character::Translations, Language};
     use roxmltree::Node;
-    use std::{collections::HashMap, iter::FromIterator};
+    use std::iter::FromIterator;
 
     // Just keeping this around for now for the test
     pub fn from(node: Node) -> Result<Translations, Error> {
@@ -42,6 +65,35 @@ mod tests {
         Ok(translations)
     }
 
+    #[test]
+    fn ordered_by_language() {
+        let node = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("rmgroup"))
+            .unwrap();
+        let json = serde_json::to_string(&from(node).unwrap()).unwrap();
+        let positions: Vec<_> = ["\"en\"", "\"es\"", "\"fr\"", "\"pt\""]
+            .iter()
+            .map(|key| json.find(key).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn xml_lang() {
+        let doc = roxmltree::Document::parse(
+            r#"<rmgroup><meaning xml:lang="de">Asien</meaning><meaning>Asia</meaning></rmgroup>"#,
+        )
+        .unwrap();
+        let translations = from(doc.root_element()).unwrap();
+        assert_eq!(translations["de"], vec!["Asien"]);
+        assert_eq!(translations[&Language::En], vec!["Asia"]);
+        assert_eq!(
+            translations.keys().find(|language| *language == "de"),
+            Some(&Language::Other("de".to_owned()))
+        );
+    }
+
     #[test]
     fn translation() {
         let node = DOC
@@ -51,9 +103,9 @@ mod tests {
         let translation = from(node);
         assert_eq!(
             translation,
-            Ok(HashMap::from_iter([
+            Ok(Translations::from_iter([
                 (
-                    "en".to_owned(),
+                    Language::En,
                     vec![
                         "Asia".to_owned(),
                         "rank next".to_owned(),
@@ -62,7 +114,7 @@ mod tests {
                     ]
                 ),
                 (
-                    "fr".to_owned(),
+                    Language::Fr,
                     vec![
                         "Asie".to_owned(),
                         "suivant".to_owned(),
@@ -71,7 +123,7 @@ mod tests {
                     ]
                 ),
                 (
-                    "pt".to_owned(),
+                    Language::Pt,
                     vec![
                         "Ásia".to_owned(),
                         "próxima".to_owned(),
@@ -80,7 +132,7 @@ mod tests {
                     ]
                 ),
                 (
-                    "es".to_owned(),
+                    Language::Es,
                     vec![
                         "pref. para indicar".to_owned(),
                         "venir después de".to_owned(),
@@ -0,0 +1,189 @@
+use kanjidic_types::{Character, Language};
+use std::{collections::HashMap, fs, io, path::Path};
+
+/// A radical and stroke count from the `kRSUnicode` field, such as `7.5` for 亜.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RadicalStrokes {
+    /// The Kangxi radical number.
+    pub radical: u8,
+    /// Whether the character is classified under the simplified form of the radical.
+    pub simplified: bool,
+    /// The strokes outside of the radical, which may be negative.
+    pub residual: i8,
+}
+
+/// The fields of the Unicode Han Database that KANJIDIC2 tends to lack.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnihanEntry {
+    /// English definitions from `kDefinition`, split on semicolons.
+    pub definitions: Vec<String>,
+    /// Total stroke counts from `kTotalStrokes`, the first being the preferred count.
+    pub total_strokes: Vec<u8>,
+    /// Mandarin readings from `kMandarin`, written with tone marks.
+    pub mandarin: Vec<String>,
+    /// Radical and stroke classifications from `kRSUnicode`.
+    pub radical_strokes: Vec<RadicalStrokes>,
+}
+
+const FIELDS: [&str; 4] = ["kDefinition", "kTotalStrokes", "kMandarin", "kRSUnicode"];
+
+/// Reads the tab-separated Unihan text format, where each line holds a codepoint,
+/// a field name, and a value, such as `U+4E9C\tkDefinition\tAsia; second`.
+/// Fields other than `kDefinition`, `kTotalStrokes`, `kMandarin`, and `kRSUnicode` are skipped.
+pub fn from_txt(contents: &str) -> HashMap<char, UnihanEntry> {
+    let mut entries: HashMap<char, UnihanEntry> = HashMap::new();
+    let lines = contents.lines().filter(|line| !line.starts_with('#'));
+    for line in lines {
+        let mut fields = line.splitn(3, '\t');
+        let (codepoint, field, value) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(codepoint), Some(field), Some(value)) => (codepoint, field, value),
+            _ => continue,
+        };
+        if !FIELDS.contains(&field) {
+            continue;
+        }
+        let literal = match codepoint_to_char(codepoint) {
+            Some(literal) => literal,
+            None => continue,
+        };
+        let entry = entries.entry(literal).or_default();
+        match field {
+            "kDefinition" => entry.definitions.extend(
+                value
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|definition| !definition.is_empty())
+                    .map(str::to_owned),
+            ),
+            "kTotalStrokes" => entry.total_strokes.extend(
+                value
+                    .split(' ')
+                    .filter_map(|count| count.parse::<u8>().ok()),
+            ),
+            "kMandarin" => entry.mandarin.extend(value.split(' ').map(str::to_owned)),
+            "kRSUnicode" => entry
+                .radical_strokes
+                .extend(value.split(' ').filter_map(radical_strokes)),
+            _ => unreachable!(),
+        }
+    }
+    entries
+}
+
+/// Reads a Unihan text file, or every `.txt` file in a directory
+/// such as an extracted `Unihan.zip`.
+pub fn load<P: AsRef<Path>>(path: P) -> io::Result<HashMap<char, UnihanEntry>> {
+    let path = path.as_ref();
+    if !path.is_dir() {
+        return Ok(from_txt(&fs::read_to_string(path)?));
+    }
+    let mut paths = vec![];
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    let mut entries: HashMap<char, UnihanEntry> = HashMap::new();
+    for path in paths {
+        for (literal, other) in from_txt(&fs::read_to_string(path)?) {
+            let entry = entries.entry(literal).or_default();
+            entry.definitions.extend(other.definitions);
+            entry.total_strokes.extend(other.total_strokes);
+            entry.mandarin.extend(other.mandarin);
+            entry.radical_strokes.extend(other.radical_strokes);
+        }
+    }
+    Ok(entries)
+}
+
+/// Fills in English meanings from the Unihan definitions
+/// for characters that have none of their own.
+pub fn apply(entries: &HashMap<char, UnihanEntry>, characters: &mut [Character]) {
+    for character in characters {
+        let has_english = character
+            .translations
+            .get("en")
+            .is_some_and(|meanings| !meanings.is_empty());
+        if has_english {
+            continue;
+        }
+        if let Some(entry) = entries.get(&character.literal) {
+            if !entry.definitions.is_empty() {
+                character
+                    .translations
+                    .insert(Language::En, entry.definitions.clone());
+            }
+        }
+    }
+}
+
+fn codepoint_to_char(codepoint: &str) -> Option<char> {
+    let hex = codepoint.strip_prefix("U+")?;
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+fn radical_strokes(value: &str) -> Option<RadicalStrokes> {
+    let (radical, residual) = value.split_once('.')?;
+    let trimmed = radical.trim_end_matches(['\'', '"']);
+    Some(RadicalStrokes {
+        radical: trimmed.parse().ok()?,
+        simplified: trimmed.len() != radical.len(),
+        residual: residual.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, from_txt, RadicalStrokes};
+    use crate::test_shared::CHARACTERS;
+
+    const UNIHAN: &str = "# Unihan_Readings.txt
+U+4E9C\tkDefinition\tAsia; second, inferior
+U+4E9C\tkMandarin\tyà
+U+4E9C\tkTotalStrokes\t7 8
+U+4E9C\tkRSUnicode\t7.5 1'.6
+U+4E9C\tkJapaneseOn\tA
+";
+
+    #[test]
+    fn fields() {
+        let entries = from_txt(UNIHAN);
+        let entry = &entries[&'亜'];
+        assert_eq!(entry.definitions, vec!["Asia", "second, inferior"]);
+        assert_eq!(entry.mandarin, vec!["yà"]);
+        assert_eq!(entry.total_strokes, vec![7, 8]);
+        assert_eq!(
+            entry.radical_strokes,
+            vec![
+                RadicalStrokes {
+                    radical: 7,
+                    simplified: false,
+                    residual: 5
+                },
+                RadicalStrokes {
+                    radical: 1,
+                    simplified: true,
+                    residual: 6
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fills_missing_meanings() {
+        let entries = from_txt(UNIHAN);
+        let mut characters = CHARACTERS.clone();
+        let original = characters[0].translations.clone();
+        apply(&entries, &mut characters);
+        assert_eq!(characters[0].translations, original);
+
+        characters[0].translations.remove("en");
+        apply(&entries, &mut characters);
+        assert_eq!(
+            characters[0].translations["en"],
+            vec!["Asia", "second, inferior"]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    de_roo, kuten, oneill,
+    de_roo,
+    error_code::ErrorCode,
+    kuten, oneill,
     pos_error::PosError,
     shared::{attr, text_hex, text_uint, SharedError},
     spahn_hadamitzky::{self, ShError},
@@ -23,6 +25,20 @@ pub enum Error {
     ONeill(#[from] oneill::Error),
 }
 
+impl Error {
+    /// The stable code identifying the kind of failure.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::UnknownVariant(_) => ErrorCode::E0111_UNKNOWN_VARIANT,
+            Error::Shared(err) => err.code(),
+            Error::Kuten(err) => err.code(),
+            Error::DeRoo(err) => err.code(),
+            Error::SpahnHadamitzky(err) => err.code(),
+            Error::ONeill(err) => err.code(),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Variant, Error> {
     let variant_type = attr(&node, "var_type")?;
     match variant_type {
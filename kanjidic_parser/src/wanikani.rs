@@ -0,0 +1,31 @@
+use crate::list;
+use std::collections::HashMap;
+
+pub use list::Error;
+
+/// The highest WaniKani level.
+pub const MAX_LEVEL: u8 = 60;
+
+/// Parses a mapping of kanji to the WaniKani level that teaches them,
+/// in the format read by [`list::parse`], such as `亜,53`.
+/// The level data itself is not bundled, since WaniKani's content is under its own terms.
+pub fn parse(contents: &str) -> Result<HashMap<char, u8>, Error> {
+    list::parse(contents, list::kanji, |level| {
+        level
+            .parse()
+            .ok()
+            .filter(|level| (1..=MAX_LEVEL).contains(level))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Error};
+
+    #[test]
+    fn levels() {
+        assert_eq!(parse("亜,53\n").unwrap().get(&'亜'), Some(&53));
+        assert_eq!(parse("亜,53\n亜,61\n"), Err(Error::Value(2)));
+        assert_eq!(parse("亜,53\n亜,0\n"), Err(Error::Value(2)));
+    }
+}
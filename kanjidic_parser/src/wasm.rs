@@ -0,0 +1,74 @@
+use crate::{
+    db::{KanjidicDb, QueryFilter},
+    kanjidic::Kanjidic,
+};
+use kanjidic_types::Language;
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::prelude::*;
+
+/// A parsed dictionary for use from JavaScript.
+/// Characters are returned as plain objects in the same shape as the JSON output.
+#[wasm_bindgen]
+pub struct Dictionary {
+    db: KanjidicDb,
+}
+
+#[wasm_bindgen]
+impl Dictionary {
+    /// Parses the contents of a KANJIDIC2 XML file.
+    #[wasm_bindgen(constructor)]
+    pub fn parse(xml: &str) -> Result<Dictionary, JsError> {
+        let kanjidic = Kanjidic::from_file_contents(xml)?;
+        Ok(Self {
+            db: KanjidicDb::new(kanjidic.characters),
+        })
+    }
+
+    /// The number of characters in the dictionary.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.db.len()
+    }
+
+    /// Gets the character with the given literal, or `null`.
+    pub fn lookup(&self, literal: &str) -> Result<JsValue, JsError> {
+        match literal.chars().next().and_then(|c| self.db.get(c)) {
+            Some(character) => to_value(character),
+            None => Ok(JsValue::NULL),
+        }
+    }
+
+    /// Gets the characters with the given on'yomi or kun'yomi.
+    #[wasm_bindgen(js_name = byReading)]
+    pub fn by_reading(&self, reading: &str) -> Result<JsValue, JsError> {
+        to_value(&self.db.by_reading(reading))
+    }
+
+    /// Gets the characters with a meaning matching the query, best matches first.
+    #[wasm_bindgen(js_name = searchMeanings)]
+    pub fn search_meanings(&self, query: &str, language: &str) -> Result<JsValue, JsError> {
+        let characters: Vec<_> = self
+            .db
+            .search_meanings(query, &Language::from(language))
+            .into_iter()
+            .map(|found| found.character)
+            .collect();
+        to_value(&characters)
+    }
+
+    /// Gets the characters matching a filter object such as
+    /// `{ grade: 1, jlpt: 4, minStrokes: 2, maxStrokes: 8, component: "口" }`.
+    pub fn query(&self, filter: JsValue) -> Result<JsValue, JsError> {
+        let filter: QueryFilter = if filter.is_undefined() || filter.is_null() {
+            QueryFilter::default()
+        } else {
+            serde_wasm_bindgen::from_value(filter)?
+        };
+        to_value(&self.db.query_with(&filter).collect())
+    }
+}
+
+fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, JsError> {
+    Ok(value.serialize(&Serializer::json_compatible())?)
+}
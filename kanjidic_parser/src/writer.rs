@@ -0,0 +1,323 @@
+use crate::{header::Header, kanjidic::Kanjidic, kunyomi, reference};
+use kanjidic_types::{
+    radical::RadicalKind, Character, Codepoint, Language, PinYin, QueryCode, Reading, Reference,
+    Variant,
+};
+use std::io::{self, Write};
+
+/// Languages in the order KANJIDIC2 lists their meanings.
+/// Any other languages follow in alphabetical order.
+const LANGUAGES: [&str; 4] = ["en", "fr", "es", "pt"];
+
+/// Writes the dictionary as KANJIDIC2 XML, with elements in the order given by the DTD.
+/// The DTD itself is not included.
+pub fn write<W: Write>(kanjidic: &Kanjidic, mut writer: W) -> io::Result<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, "<kanjidic2>")?;
+    header(&kanjidic.header, &mut writer)?;
+    for character in &kanjidic.characters {
+        writeln!(writer, "<!-- Entry for Kanji: {} -->", character.literal)?;
+        self::character(character, &mut writer)?;
+    }
+    writeln!(writer, "</kanjidic2>")?;
+    writer.flush()
+}
+
+/// Writes the dictionary as a KANJIDIC2 XML string.
+pub fn to_string(kanjidic: &Kanjidic) -> String {
+    let mut out = vec![];
+    write(kanjidic, &mut out).expect("Writing to a Vec should not fail");
+    String::from_utf8(out).expect("Written XML should be UTF-8")
+}
+
+fn header<W: Write>(header: &Header, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "<header>")?;
+    leaf(
+        writer,
+        1,
+        "file_version",
+        &[],
+        &header.file_version.to_string(),
+    )?;
+    let version = &header.database_version;
+    let version = format!("{}-{}", version.year, version.version);
+    leaf(writer, 1, "database_version", &[], &version)?;
+    let date = &header.date_of_creation;
+    let date = format!("{}-{:02}-{:02}", date.year, date.month, date.day);
+    leaf(writer, 1, "date_of_creation", &[], &date)?;
+    writeln!(writer, "</header>")
+}
+
+/// Writes a single `<character>` element.
+pub(crate) fn character<W: Write>(character: &Character, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "<character>")?;
+    leaf(writer, 1, "literal", &[], &character.literal_str())?;
+
+    if !character.codepoints.is_empty() {
+        open(writer, 1, "codepoint")?;
+        for codepoint in &character.codepoints {
+            let (cp_type, value) = match codepoint {
+                Codepoint::Jis208(kuten) => ("jis208", kuten.to_string()),
+                Codepoint::Jis212(kuten) => ("jis212", kuten.to_string()),
+                Codepoint::Jis213(kuten) => ("jis213", kuten.to_string()),
+                Codepoint::Unicode(ucs) => ("ucs", format!("{:x}", ucs)),
+            };
+            leaf(writer, 2, "cp_value", &[("cp_type", cp_type)], &value)?;
+        }
+        close(writer, 1, "codepoint")?;
+    }
+
+    if !character.radicals.is_empty() {
+        open(writer, 1, "radical")?;
+        for radical in &character.radicals {
+            let rad_type = match radical.kind {
+                RadicalKind::Classical => "classical",
+                RadicalKind::Nelson => "nelson_c",
+            };
+            let value = u8::from(radical.radical).to_string();
+            leaf(writer, 2, "rad_value", &[("rad_type", rad_type)], &value)?;
+        }
+        close(writer, 1, "radical")?;
+    }
+
+    open(writer, 1, "misc")?;
+    if let Some(grade) = character.grade {
+        leaf(writer, 2, "grade", &[], &grade.to_raw().to_string())?;
+    }
+    for count in character.stroke_counts.all_counts() {
+        leaf(writer, 2, "stroke_count", &[], &count.to_string())?;
+    }
+    for variant in &character.variants {
+        let (var_type, value) = variant_parts(variant);
+        leaf(writer, 2, "variant", &[("var_type", var_type)], &value)?;
+    }
+    if let Some(frequency) = character.frequency {
+        leaf(writer, 2, "freq", &[], &frequency.to_string())?;
+    }
+    for name in &character.radical_names {
+        leaf(writer, 2, "rad_name", &[], name)?;
+    }
+    if let Some(jlpt) = character.jlpt {
+        leaf(writer, 2, "jlpt", &[], &jlpt.to_string())?;
+    }
+    close(writer, 1, "misc")?;
+
+    if !character.references.is_empty() {
+        open(writer, 1, "dic_number")?;
+        for reference in &character.references {
+            dic_ref(writer, reference)?;
+        }
+        close(writer, 1, "dic_number")?;
+    }
+
+    if !character.query_codes.is_empty() {
+        open(writer, 1, "query_code")?;
+        for query_code in &character.query_codes {
+            q_code(writer, query_code)?;
+        }
+        close(writer, 1, "query_code")?;
+    }
+
+    let has_rmgroup = !character.readings.is_empty() || !character.translations.is_empty();
+    if has_rmgroup || !character.nanori.is_empty() {
+        open(writer, 1, "reading_meaning")?;
+        if has_rmgroup {
+            open(writer, 2, "rmgroup")?;
+            for reading in &character.readings {
+                let (r_type, value) = reading_parts(reading);
+                leaf(writer, 3, "reading", &[("r_type", r_type)], &value)?;
+            }
+            let mut languages: Vec<&Language> = character.translations.keys().collect();
+            languages.sort_by_key(|&language| {
+                let position = LANGUAGES.iter().position(|known| language == known);
+                (position.unwrap_or(LANGUAGES.len()), language)
+            });
+            for language in languages {
+                let attributes = match language.code() {
+                    "en" => vec![],
+                    language => vec![("m_lang", language)],
+                };
+                for meaning in &character.translations[language] {
+                    leaf(writer, 3, "meaning", &attributes, meaning)?;
+                }
+            }
+            close(writer, 2, "rmgroup")?;
+        }
+        for nanori in &character.nanori {
+            leaf(writer, 2, "nanori", &[], nanori)?;
+        }
+        close(writer, 1, "reading_meaning")?;
+    }
+
+    writeln!(writer, "</character>")
+}
+
+fn dic_ref<W: Write>(writer: &mut W, reference: &Reference) -> io::Result<()> {
+    let dr_type = reference::dr_type(reference);
+    let text = reference::text(reference);
+    match reference {
+        Reference::Moro(moro) => {
+            let volume = moro.volume.map(|volume| volume.to_string());
+            let page = moro.page.map(|page| format!("{:04}", page));
+            let mut attributes = vec![("dr_type", dr_type)];
+            if let Some(volume) = &volume {
+                attributes.push(("m_vol", volume.as_str()));
+            }
+            if let Some(page) = &page {
+                attributes.push(("m_page", page.as_str()));
+            }
+            leaf(writer, 2, "dic_ref", &attributes, &text)
+        }
+        _ => leaf(writer, 2, "dic_ref", &[("dr_type", dr_type)], &text),
+    }
+}
+
+fn q_code<W: Write>(writer: &mut W, query_code: &QueryCode) -> io::Result<()> {
+    match query_code {
+        QueryCode::Skip(skip) => leaf(
+            writer,
+            2,
+            "q_code",
+            &[("qc_type", "skip")],
+            &skip.to_string(),
+        ),
+        QueryCode::SpahnHadamitzky(descriptor) => leaf(
+            writer,
+            2,
+            "q_code",
+            &[("qc_type", "sh_desc")],
+            &descriptor.to_string(),
+        ),
+        QueryCode::FourCorner(four_corner) => leaf(
+            writer,
+            2,
+            "q_code",
+            &[("qc_type", "four_corner")],
+            &four_corner.to_string(),
+        ),
+        QueryCode::DeRoo(de_roo) => leaf(
+            writer,
+            2,
+            "q_code",
+            &[("qc_type", "deroo")],
+            &de_roo.to_string(),
+        ),
+        QueryCode::Misclassification(misclassification) => {
+            let kind = misclassification.kind.attribute();
+            leaf(
+                writer,
+                2,
+                "q_code",
+                &[("qc_type", "skip"), ("skip_misclass", kind)],
+                &misclassification.skip.to_string(),
+            )
+        }
+    }
+}
+
+fn variant_parts(variant: &Variant) -> (&'static str, String) {
+    match variant {
+        Variant::Jis208(kuten) => ("jis208", kuten.to_string()),
+        Variant::Jis212(kuten) => ("jis212", kuten.to_string()),
+        Variant::Jis213(kuten) => ("jis213", kuten.to_string()),
+        Variant::Unicode(ucs) => ("ucs", format!("{:x}", ucs)),
+        Variant::DeRoo(de_roo) => ("deroo", de_roo.to_string()),
+        Variant::Halpern(index) => ("njecd", index.to_string()),
+        Variant::SpahnHadamitzky(descriptor) => ("s_h", descriptor.to_string()),
+        Variant::Nelson(index) => ("nelson_c", index.to_string()),
+        Variant::ONeill(oneill) => ("oneill", reference::text(&Reference::OneillNames(*oneill))),
+    }
+}
+
+fn reading_parts(reading: &Reading) -> (&'static str, String) {
+    match reading {
+        Reading::PinYin(pin_yin) => ("pinyin", pin_yin_text(pin_yin)),
+        Reading::KoreanRomanized(text) => ("korean_r", text.clone()),
+        Reading::KoreanHangul(text) => ("korean_h", text.clone()),
+        Reading::Vietnam(text) => ("vietnam", text.clone()),
+        Reading::Onyomi(text) => ("ja_on", text.clone()),
+        Reading::Kunyomi(kunyomi) => ("ja_kun", kunyomi::text(kunyomi)),
+    }
+}
+
+fn pin_yin_text(pin_yin: &PinYin) -> String {
+    let romanization = pin_yin.romanization.replace('ü', "u:").replace('ê', "e^");
+    format!("{}{}", romanization, pin_yin.tone as u8)
+}
+
+fn open<W: Write>(writer: &mut W, depth: usize, tag: &str) -> io::Result<()> {
+    writeln!(writer, "{}<{}>", indent(depth), tag)
+}
+
+fn close<W: Write>(writer: &mut W, depth: usize, tag: &str) -> io::Result<()> {
+    writeln!(writer, "{}</{}>", indent(depth), tag)
+}
+
+fn leaf<W: Write>(
+    writer: &mut W,
+    depth: usize,
+    tag: &str,
+    attributes: &[(&str, &str)],
+    text: &str,
+) -> io::Result<()> {
+    write!(writer, "{}<{}", indent(depth), tag)?;
+    for (name, value) in attributes {
+        write!(writer, r#" {}="{}""#, name, escape(value))?;
+    }
+    writeln!(writer, ">{}</{}>", escape(text), tag)
+}
+
+fn indent(depth: usize) -> String {
+    "\t".repeat(depth)
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_string;
+    use crate::{kanjidic::Kanjidic, test_shared::KANJIDIC};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn round_trip() {
+        let xml = to_string(&KANJIDIC);
+        let parsed = Kanjidic::from_file_contents(&xml).unwrap();
+        assert_eq!(parsed, *KANJIDIC);
+    }
+
+    #[test]
+    fn first_character() {
+        let xml = to_string(&KANJIDIC);
+        assert!(xml.contains(r#"<cp_value cp_type="jis208">1-16-01</cp_value>"#));
+        assert!(xml.contains(r#"<dic_ref dr_type="moro" m_vol="1" m_page="0525">272</dic_ref>"#));
+        assert!(xml.contains(r#"<q_code qc_type="sh_desc">0a7.14</q_code>"#));
+        assert!(xml.contains(r#"<q_code qc_type="four_corner">1010.6</q_code>"#));
+        assert!(xml.contains(r#"<q_code qc_type="deroo">3273</q_code>"#));
+        assert!(xml.contains(r#"<reading r_type="pinyin">ya4</reading>"#));
+        assert!(xml.contains(r#"<meaning m_lang="fr">Asie</meaning>"#));
+    }
+
+    #[test]
+    #[ignore]
+    fn full_release_round_trip() {
+        let file = include_str!("../../assets/kanjidic2.xml");
+        let original = Kanjidic::from_file_contents(file).unwrap();
+        let xml = to_string(&original);
+        let parsed = Kanjidic::try_from(xml.as_str()).unwrap();
+        assert_eq!(parsed, original);
+    }
+}
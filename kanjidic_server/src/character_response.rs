@@ -3,7 +3,7 @@ use kanjidic_types::{
     StrokeCount, Variant,
 };
 use serde::Serialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
 use crate::field::Field;
 
@@ -49,7 +49,7 @@ fn none_or_empty(value: &Option<&[impl std::any::Any]>) -> bool {
     }
 }
 
-type FilteredTranslations<'a> = HashMap<&'a str, &'a [String]>;
+type FilteredTranslations<'a> = BTreeMap<&'a str, &'a [String]>;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(untagged)]
@@ -144,8 +144,8 @@ impl<'a, 'b> CharacterResponse<'a> {
     ) -> FilteredTranslations<'a> {
         let out: FilteredTranslations = translations
             .iter()
-            .filter(|(k, _)| languages.contains(*k))
-            .map(|(k, v)| (k.as_str(), v.as_slice()))
+            .filter(|(k, _)| languages.contains(k.code()))
+            .map(|(k, v)| (k.code(), v.as_slice()))
             .collect();
         out
     }
@@ -1,28 +1,118 @@
-use crate::{IResult, NomErr, NomErrorReason};
+use crate::{IResult, NomErr, NomErrorReason, TryFromPrimitiveError};
 use nom::{
     branch::alt,
     bytes::complete::take_while1,
     character::complete::char,
-    combinator::{map, map_res, value},
+    combinator::{all_consuming, map, map_res, value},
     sequence::tuple,
 };
+use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use serde_repr::*;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
 use thiserror::Error;
 
 /// A location in Japanese for Busy People.
+/// [`Display`] gives the location as written in KANJIDIC2, such as `2.15` or `1.A`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BusyPeople {
     /// The volume
-    pub volume: u8,
+    pub volume: Volume,
     /// The chapter
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub chapter: Option<u8>,
+    pub chapter: Chapter,
+}
+
+/// A volume of Japanese for Busy People.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    TryFromPrimitive,
+    Serialize_repr,
+    Deserialize_repr,
+)]
+#[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+pub enum Volume {
+    /// Japanese for Busy People I
+    One = 1,
+    /// Japanese for Busy People II
+    Two,
+    /// Japanese for Busy People III
+    Three,
+}
+
+impl Volume {
+    /// The volume number.
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+}
+
+impl Display for Volume {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Where in a volume a kanji is introduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(tag = "tag", content = "content")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Chapter {
+    /// A numbered chapter
+    Numbered(u8),
+    /// The appendix at the end of the volume
+    Appendix,
+}
+
+impl Display for Chapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Chapter::Numbered(chapter) => write!(f, "{}", chapter),
+            Chapter::Appendix => f.write_str("A"),
+        }
+    }
+}
+
+impl Display for BusyPeople {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.volume, self.chapter)
+    }
 }
 
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
 pub enum ParseError {
+    #[error("(Busy people) Unknown volume: {0}")]
+    Volume(#[from] TryFromPrimitiveError<Volume>),
     #[error("(Busy people) Format: {0}")]
     Format(NomErrorReason),
 }
@@ -39,16 +129,20 @@ impl TryFrom<&str> for BusyPeople {
     fn try_from(text: &str) -> Result<Self, Self::Error> {
         let (_i, o) = parts(text)?;
         let (volume, _, chapter) = o;
+        let volume = Volume::try_from(volume)?;
         Ok(Self { volume, chapter })
     }
 }
 
-fn parts(s: &str) -> IResult<(u8, char, Option<u8>)> {
-    tuple((number, char('.'), chapter))(s)
+fn parts(s: &str) -> IResult<(u8, char, Chapter)> {
+    all_consuming(tuple((number, char('.'), chapter)))(s)
 }
 
-fn chapter(s: &str) -> IResult<Option<u8>> {
-    alt((value(None, char('A')), map(number, Some)))(s)
+fn chapter(s: &str) -> IResult<Chapter> {
+    alt((
+        value(Chapter::Appendix, char('A')),
+        map(number, Chapter::Numbered),
+    ))(s)
 }
 
 fn number(s: &str) -> IResult<u8> {
@@ -1,15 +1,38 @@
-use crate::{Codepoint, Grade, QueryCode, Radical, Reading, Reference, StrokeCount, Variant};
+use crate::{
+    radical::{RadicalDiscrepancy, RadicalKind},
+    Codepoint, Grade, Kunyomi, Language, PinYin, QueryCode, Radical, Reading, Reference,
+    StrokeCount, Variant,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+use thiserror::Error;
 
-pub type Translations = HashMap<String, Vec<String>>;
+/// Meanings grouped by language, ordered by language code
+/// so that serialized output is deterministic.
+pub type Translations = BTreeMap<Language, Vec<String>>;
 
 /// Information about a kanji.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Character {
     /// The character itself.
+    /// For a literal made of several scalar values, this is the first of them.
     pub literal: char,
+    /// The whole literal when it is more than one scalar value,
+    /// such as an ideograph followed by a variation selector.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub literal_sequence: Option<String>,
     /// Alternate encodings for the character.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub codepoints: Vec<Codepoint>,
@@ -51,4 +74,339 @@ pub struct Character {
     /// The constituent radicals in the kanji
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub decomposition: Vec<char>,
+    /// The Ideographic Description Sequence giving the positions of the components,
+    /// when merged in from an IDS dataset.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ids: Option<String>,
+}
+
+impl Character {
+    /// Starts building a character field by field.
+    pub fn builder() -> CharacterBuilder {
+        CharacterBuilder::default()
+    }
+
+    /// The literal as it appears in the dictionary,
+    /// including any scalar values after the first.
+    pub fn literal_str(&self) -> Cow<'_, str> {
+        match &self.literal_sequence {
+            Some(sequence) => Cow::Borrowed(sequence),
+            None => Cow::Owned(self.literal.to_string()),
+        }
+    }
+
+    /// The onyomi in katakana.
+    pub fn onyomi(&self) -> impl Iterator<Item = &str> + '_ {
+        self.readings.iter().filter_map(|reading| match reading {
+            Reading::Onyomi(onyomi) => Some(onyomi.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The kunyomi.
+    pub fn kunyomi(&self) -> impl Iterator<Item = &Kunyomi> + '_ {
+        self.readings.iter().filter_map(|reading| match reading {
+            Reading::Kunyomi(kunyomi) => Some(kunyomi),
+            _ => None,
+        })
+    }
+
+    /// The Mandarin readings in pinyin.
+    pub fn pinyin(&self) -> impl Iterator<Item = &PinYin> + '_ {
+        self.readings.iter().filter_map(|reading| match reading {
+            Reading::PinYin(pin_yin) => Some(pin_yin),
+            _ => None,
+        })
+    }
+
+    /// The meanings of the character in the given language.
+    pub fn meanings(&self, language: &str) -> Option<&[String]> {
+        self.translations.get(language).map(Vec::as_slice)
+    }
+
+    /// The first English meaning, which KANJIDIC2 lists as the main one.
+    pub fn primary_meaning(&self) -> Option<&str> {
+        self.meanings("en")?.first().map(String::as_str)
+    }
+
+    /// The classical and Nelson radicals, if the character has a Nelson radical
+    /// that differs from the classical one.
+    pub fn radical_discrepancy(&self) -> Option<RadicalDiscrepancy> {
+        let radical = |kind| {
+            self.radicals
+                .iter()
+                .find(|radical| radical.kind == kind)
+                .map(|radical| radical.radical)
+        };
+        let classical = radical(RadicalKind::Classical)?;
+        let nelson = radical(RadicalKind::Nelson)?;
+        if classical == nelson {
+            None
+        } else {
+            Some(RadicalDiscrepancy { classical, nelson })
+        }
+    }
+
+    /// Whether the reading of a word, covering this character and the kana written after it,
+    /// plausibly uses one of its kunyomi, as decided by [`Kunyomi::matches_word`].
+    pub fn kunyomi_matches(&self, word_reading: &str) -> bool {
+        self.kunyomi()
+            .any(|kunyomi| kunyomi.matches_word(word_reading))
+    }
+}
+
+/// Assembles a [`Character`] field by field, created with [`Character::builder`].
+/// The literal and stroke count are required and everything else starts out empty.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CharacterBuilder {
+    /// The character itself.
+    literal: Option<char>,
+    /// The whole literal when it is more than one scalar value.
+    literal_sequence: Option<String>,
+    /// Alternate encodings for the character.
+    codepoints: Vec<Codepoint>,
+    /// Alternate classifications for the character by radical.
+    radicals: Vec<Radical>,
+    /// The kanji grade level.
+    grade: Option<Grade>,
+    /// The stroke count of the character.
+    stroke_counts: Option<StrokeCount>,
+    /// Cross-references to other characters or alternative indexings.
+    variants: Vec<Variant>,
+    /// A ranking of how often the character appears in newspapers.
+    frequency: Option<u16>,
+    /// The kanji's name as a radical if it is one.
+    radical_names: Vec<String>,
+    /// Old JLPT level of the kanji.
+    jlpt: Option<u8>,
+    /// Indexes into dictionaries and other instructional books
+    references: Vec<Reference>,
+    /// Codes used to identify the kanji
+    query_codes: Vec<QueryCode>,
+    /// Different ways the kanji can be read.
+    readings: Vec<Reading>,
+    /// Translations of the kanji into different languages.
+    translations: Translations,
+    /// Japanese readings associated with names.
+    nanori: Vec<String>,
+    /// The constituent radicals in the kanji
+    decomposition: Vec<char>,
+    /// The Ideographic Description Sequence giving the positions of the components.
+    ids: Option<String>,
+}
+
+/// A reason a [`CharacterBuilder`] could not build a character.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum BuildError {
+    #[error("(Character) Missing the literal")]
+    MissingLiteral,
+    #[error("(Character) Missing the stroke count")]
+    MissingStrokeCount,
+    #[error("(Character) Literal sequence does not start with the literal: {0}")]
+    LiteralSequence(String),
+}
+
+impl CharacterBuilder {
+    /// Sets the character itself.
+    pub fn literal(mut self, literal: char) -> Self {
+        self.literal = Some(literal);
+        self
+    }
+
+    /// Sets the whole literal when it is more than one scalar value.
+    pub fn literal_sequence(mut self, sequence: impl Into<String>) -> Self {
+        self.literal_sequence = Some(sequence.into());
+        self
+    }
+
+    /// Sets the alternate encodings.
+    pub fn codepoints(mut self, codepoints: Vec<Codepoint>) -> Self {
+        self.codepoints = codepoints;
+        self
+    }
+
+    /// Sets the radical classifications.
+    pub fn radicals(mut self, radicals: Vec<Radical>) -> Self {
+        self.radicals = radicals;
+        self
+    }
+
+    /// Sets the grade level.
+    pub fn grade(mut self, grade: Grade) -> Self {
+        self.grade = Some(grade);
+        self
+    }
+
+    /// Sets the stroke count.
+    pub fn stroke_counts(mut self, stroke_counts: StrokeCount) -> Self {
+        self.stroke_counts = Some(stroke_counts);
+        self
+    }
+
+    /// Sets the cross-references to other characters.
+    pub fn variants(mut self, variants: Vec<Variant>) -> Self {
+        self.variants = variants;
+        self
+    }
+
+    /// Sets the newspaper frequency ranking.
+    pub fn frequency(mut self, frequency: u16) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Sets the names of the kanji as a radical.
+    pub fn radical_names(mut self, radical_names: Vec<String>) -> Self {
+        self.radical_names = radical_names;
+        self
+    }
+
+    /// Sets the old JLPT level.
+    pub fn jlpt(mut self, jlpt: u8) -> Self {
+        self.jlpt = Some(jlpt);
+        self
+    }
+
+    /// Sets the dictionary references.
+    pub fn references(mut self, references: Vec<Reference>) -> Self {
+        self.references = references;
+        self
+    }
+
+    /// Sets the query codes.
+    pub fn query_codes(mut self, query_codes: Vec<QueryCode>) -> Self {
+        self.query_codes = query_codes;
+        self
+    }
+
+    /// Sets the readings.
+    pub fn readings(mut self, readings: Vec<Reading>) -> Self {
+        self.readings = readings;
+        self
+    }
+
+    /// Adds a reading after those already set.
+    pub fn reading(mut self, reading: Reading) -> Self {
+        self.readings.push(reading);
+        self
+    }
+
+    /// Sets the meanings in every language.
+    pub fn translations(mut self, translations: Translations) -> Self {
+        self.translations = translations;
+        self
+    }
+
+    /// Adds a meaning in the given language after those already set.
+    pub fn meaning(mut self, language: impl Into<Language>, meaning: impl Into<String>) -> Self {
+        self.translations
+            .entry(language.into())
+            .or_default()
+            .push(meaning.into());
+        self
+    }
+
+    /// Sets the readings used in names.
+    pub fn nanori(mut self, nanori: Vec<String>) -> Self {
+        self.nanori = nanori;
+        self
+    }
+
+    /// Sets the constituent radicals.
+    pub fn decomposition(mut self, decomposition: Vec<char>) -> Self {
+        self.decomposition = decomposition;
+        self
+    }
+
+    /// Sets the Ideographic Description Sequence.
+    pub fn ids(mut self, ids: impl Into<String>) -> Self {
+        self.ids = Some(ids.into());
+        self
+    }
+
+    /// Finishes the character, checking that the required fields are present
+    /// and that a literal sequence starts with the literal.
+    pub fn build(self) -> Result<Character, BuildError> {
+        let literal = self.literal.ok_or(BuildError::MissingLiteral)?;
+        let stroke_counts = self.stroke_counts.ok_or(BuildError::MissingStrokeCount)?;
+        if let Some(sequence) = &self.literal_sequence {
+            if !sequence.starts_with(literal) {
+                return Err(BuildError::LiteralSequence(sequence.clone()));
+            }
+        }
+        Ok(Character {
+            literal,
+            literal_sequence: self.literal_sequence,
+            codepoints: self.codepoints,
+            radicals: self.radicals,
+            grade: self.grade,
+            stroke_counts,
+            variants: self.variants,
+            frequency: self.frequency,
+            radical_names: self.radical_names,
+            jlpt: self.jlpt,
+            references: self.references,
+            query_codes: self.query_codes,
+            readings: self.readings,
+            translations: self.translations,
+            nanori: self.nanori,
+            decomposition: self.decomposition,
+            ids: self.ids,
+        })
+    }
+}
+
+/// The number of English meanings shown in a character's summary.
+const SUMMARY_MEANINGS: usize = 5;
+
+/// A short summary of the character for people to read:
+/// the literal with its stroke count, grade, and frequency on the first line,
+/// then a line for each kind of reading it has and its first few English meanings.
+impl Display for Character {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} strokes",
+            self.literal_str(),
+            self.stroke_counts.accepted
+        )?;
+        if let Some(grade) = self.grade {
+            write!(f, ", {}", grade)?;
+        }
+        if let Some(frequency) = self.frequency {
+            write!(f, ", frequency {}", frequency)?;
+        }
+        if let Some(jlpt) = self.jlpt {
+            write!(f, ", JLPT {}", jlpt)?;
+        }
+        f.write_str(")")?;
+
+        let line = |f: &mut Formatter<'_>, label: &str, values: Vec<String>| {
+            if values.is_empty() {
+                Ok(())
+            } else {
+                write!(f, "\n{}: {}", label, values.join(", "))
+            }
+        };
+        line(f, "On", self.onyomi().map(str::to_owned).collect())?;
+        line(f, "Kun", self.kunyomi().map(Kunyomi::to_string).collect())?;
+        line(f, "Nanori", self.nanori.clone())?;
+        line(f, "Pinyin", self.pinyin().map(PinYin::to_marked).collect())?;
+        let korean = self.readings.iter().filter_map(|reading| match reading {
+            Reading::KoreanHangul(text) | Reading::KoreanRomanized(text) => Some(text.clone()),
+            _ => None,
+        });
+        line(f, "Korean", korean.collect())?;
+        let vietnamese = self.readings.iter().filter_map(|reading| match reading {
+            Reading::Vietnam(text) => Some(text.clone()),
+            _ => None,
+        });
+        line(f, "Vietnamese", vietnamese.collect())?;
+        let meanings = self.meanings("en").unwrap_or_default();
+        line(
+            f,
+            "Meanings",
+            meanings.iter().take(SUMMARY_MEANINGS).cloned().collect(),
+        )
+    }
 }
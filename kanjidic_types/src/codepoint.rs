@@ -1,9 +1,16 @@
-use crate::Kuten;
+use crate::{JisStandard, Kuten};
 use serde::{Deserialize, Serialize};
 
 /// The code of a kanji in a given character set standard.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Codepoint {
     /// Encoding in JIS X 0208-1997
     Jis208(Kuten),
@@ -14,3 +21,56 @@ pub enum Codepoint {
     /// Unicode character
     Unicode(u32),
 }
+
+impl Codepoint {
+    /// The Unicode character that the codepoint stands for,
+    /// looking JIS kuten positions up in the embedded tables.
+    pub fn to_unicode(self) -> Option<char> {
+        match self {
+            Codepoint::Jis208(kuten) => kuten.to_unicode(JisStandard::X0208),
+            Codepoint::Jis212(kuten) => kuten.to_unicode(JisStandard::X0212),
+            Codepoint::Jis213(kuten) => kuten.to_unicode(JisStandard::X0213),
+            Codepoint::Unicode(unicode) => std::char::from_u32(unicode),
+        }
+    }
+
+    /// Every codepoint of the character: its Unicode scalar value
+    /// followed by its position in each JIS standard that includes it.
+    pub fn all_for(c: char) -> Vec<Self> {
+        let mut codepoints = vec![Codepoint::Unicode(c as u32)];
+        codepoints.extend(Kuten::from_unicode(c, JisStandard::X0208).map(Codepoint::Jis208));
+        codepoints.extend(Kuten::from_unicode(c, JisStandard::X0212).map(Codepoint::Jis212));
+        codepoints.extend(Kuten::from_unicode(c, JisStandard::X0213).map(Codepoint::Jis213));
+        codepoints
+    }
+
+    /// Encodes a JIS codepoint as EUC-JP bytes, as described in [`Kuten::to_euc_jp`].
+    /// Unicode codepoints give `None`.
+    pub fn to_euc_jp(self) -> Option<Vec<u8>> {
+        let (kuten, standard) = self.jis()?;
+        kuten.to_euc_jp(standard)
+    }
+
+    /// Encodes a JIS codepoint as Shift_JIS bytes, as described in [`Kuten::to_shift_jis`].
+    /// Unicode and JIS X 0212 codepoints give `None`.
+    pub fn to_shift_jis(self) -> Option<Vec<u8>> {
+        let (kuten, standard) = self.jis()?;
+        kuten.to_shift_jis(standard)
+    }
+
+    /// Encodes a JIS codepoint as an escaped ISO-2022-JP character,
+    /// as described in [`Kuten::to_iso_2022_jp`]. Unicode codepoints give `None`.
+    pub fn to_iso_2022_jp(self) -> Option<Vec<u8>> {
+        let (kuten, standard) = self.jis()?;
+        kuten.to_iso_2022_jp(standard)
+    }
+
+    fn jis(self) -> Option<(Kuten, JisStandard)> {
+        match self {
+            Codepoint::Jis208(kuten) => Some((kuten, JisStandard::X0208)),
+            Codepoint::Jis212(kuten) => Some((kuten, JisStandard::X0212)),
+            Codepoint::Jis213(kuten) => Some((kuten, JisStandard::X0213)),
+            Codepoint::Unicode(_) => None,
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{
+    kunyomi::KunyomiKind, Character, Codepoint, Grade, Kunyomi, PinYin, QueryCode, Radical,
+    Reading, Reference, StrokeCount, Variant,
+};
+
+/// Meanings grouped by language, sorted by language code.
+pub type CompactTranslations = Box<[(Box<str>, Box<[Box<str>]>)]>;
+
+/// A [`Character`] that stores its lists and text without spare capacity.
+/// Holding the whole dictionary this way takes considerably less memory
+/// than the equivalent `Vec`s and `String`s, at the cost of being immutable in length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactCharacter {
+    /// The character itself, or the first scalar value of a longer literal.
+    pub literal: char,
+    /// The whole literal when it is more than one scalar value.
+    pub literal_sequence: Option<Box<str>>,
+    /// Alternate encodings for the character.
+    pub codepoints: Box<[Codepoint]>,
+    /// Alternate classifications for the character by radical.
+    pub radicals: Box<[Radical]>,
+    /// The kanji grade level.
+    pub grade: Option<Grade>,
+    /// The stroke count of the character.
+    pub stroke_counts: StrokeCount,
+    /// Cross-references to other characters or alternative indexings.
+    pub variants: Box<[Variant]>,
+    /// A ranking of how often the character appears in newspapers.
+    pub frequency: Option<u16>,
+    /// The kanji's name as a radical if it is one.
+    pub radical_names: Box<[Box<str>]>,
+    /// Old JLPT level of the kanji.
+    pub jlpt: Option<u8>,
+    /// Indexes into dictionaries and other instructional books
+    pub references: Box<[Reference]>,
+    /// Codes used to identify the kanji
+    pub query_codes: Box<[QueryCode]>,
+    /// Different ways the kanji can be read.
+    pub readings: Box<[CompactReading]>,
+    /// Translations of the kanji into different languages.
+    pub translations: CompactTranslations,
+    /// Japanese readings associated with names.
+    pub nanori: Box<[Box<str>]>,
+    /// The constituent radicals in the kanji
+    pub decomposition: Box<[char]>,
+    /// The Ideographic Description Sequence giving the positions of the components.
+    pub ids: Option<Box<str>>,
+}
+
+impl CompactCharacter {
+    /// The meanings of the character in the given language.
+    pub fn meanings(&self, language: &str) -> Option<&[Box<str>]> {
+        self.translations
+            .binary_search_by(|(key, _)| (**key).cmp(language))
+            .ok()
+            .map(|i| &*self.translations[i].1)
+    }
+}
+
+/// A [`Reading`] with boxed text.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompactReading {
+    /// The modern romanization of the Chinese reading.
+    PinYin(PinYin),
+    /// The romanized form of the Korean reading.
+    KoreanRomanized(Box<str>),
+    /// The Korean reading of the kanji in Hangul.
+    KoreanHangul(Box<str>),
+    /// The Vietnamese reading supplied by Minh Chau Pham.
+    Vietnam(Box<str>),
+    /// The onyomi reading of the kanji in katakana.
+    Onyomi(Box<str>),
+    /// The kunyomi reading of the kanji in hiragana or katakana.
+    Kunyomi(CompactKunyomi),
+}
+
+/// A [`Kunyomi`] with boxed text.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompactKunyomi {
+    /// The kunyomi reading
+    pub reading: Box<str>,
+    /// The okurigana if relevant
+    pub okurigana: Option<Box<str>>,
+    /// Whether the reading is as a prefix or suffix.
+    pub kind: KunyomiKind,
+}
+
+fn boxed(strings: Vec<String>) -> Box<[Box<str>]> {
+    strings.into_iter().map(String::into_boxed_str).collect()
+}
+
+fn unboxed(strings: Box<[Box<str>]>) -> Vec<String> {
+    strings.into_vec().into_iter().map(String::from).collect()
+}
+
+impl From<Character> for CompactCharacter {
+    fn from(character: Character) -> Self {
+        let translations: CompactTranslations = character
+            .translations
+            .into_iter()
+            .map(|(language, meanings)| (language.code().into(), boxed(meanings)))
+            .collect();
+        let mut stroke_counts = character.stroke_counts;
+        stroke_counts.miscounts.shrink_to_fit();
+        Self {
+            literal: character.literal,
+            literal_sequence: character.literal_sequence.map(Into::into),
+            codepoints: character.codepoints.into(),
+            radicals: character.radicals.into(),
+            grade: character.grade,
+            stroke_counts,
+            variants: character.variants.into(),
+            frequency: character.frequency,
+            radical_names: boxed(character.radical_names),
+            jlpt: character.jlpt,
+            references: character.references.into(),
+            query_codes: character.query_codes.into(),
+            readings: character.readings.into_iter().map(Into::into).collect(),
+            translations,
+            nanori: boxed(character.nanori),
+            decomposition: character.decomposition.into(),
+            ids: character.ids.map(Into::into),
+        }
+    }
+}
+
+impl From<CompactCharacter> for Character {
+    fn from(character: CompactCharacter) -> Self {
+        Self {
+            literal: character.literal,
+            literal_sequence: character.literal_sequence.map(Into::into),
+            codepoints: character.codepoints.into(),
+            radicals: character.radicals.into(),
+            grade: character.grade,
+            stroke_counts: character.stroke_counts,
+            variants: character.variants.into(),
+            frequency: character.frequency,
+            radical_names: unboxed(character.radical_names),
+            jlpt: character.jlpt,
+            references: character.references.into(),
+            query_codes: character.query_codes.into(),
+            readings: character
+                .readings
+                .into_vec()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            translations: character
+                .translations
+                .into_vec()
+                .into_iter()
+                .map(|(language, meanings)| (String::from(language).into(), unboxed(meanings)))
+                .collect(),
+            nanori: unboxed(character.nanori),
+            decomposition: character.decomposition.into(),
+            ids: character.ids.map(Into::into),
+        }
+    }
+}
+
+impl From<Reading> for CompactReading {
+    fn from(reading: Reading) -> Self {
+        match reading {
+            Reading::PinYin(pin_yin) => Self::PinYin(pin_yin),
+            Reading::KoreanRomanized(text) => Self::KoreanRomanized(text.into()),
+            Reading::KoreanHangul(text) => Self::KoreanHangul(text.into()),
+            Reading::Vietnam(text) => Self::Vietnam(text.into()),
+            Reading::Onyomi(text) => Self::Onyomi(text.into()),
+            Reading::Kunyomi(kunyomi) => Self::Kunyomi(kunyomi.into()),
+        }
+    }
+}
+
+impl From<CompactReading> for Reading {
+    fn from(reading: CompactReading) -> Self {
+        match reading {
+            CompactReading::PinYin(pin_yin) => Self::PinYin(pin_yin),
+            CompactReading::KoreanRomanized(text) => Self::KoreanRomanized(text.into()),
+            CompactReading::KoreanHangul(text) => Self::KoreanHangul(text.into()),
+            CompactReading::Vietnam(text) => Self::Vietnam(text.into()),
+            CompactReading::Onyomi(text) => Self::Onyomi(text.into()),
+            CompactReading::Kunyomi(kunyomi) => Self::Kunyomi(kunyomi.into()),
+        }
+    }
+}
+
+impl From<Kunyomi> for CompactKunyomi {
+    fn from(kunyomi: Kunyomi) -> Self {
+        Self {
+            reading: kunyomi.reading.into(),
+            okurigana: kunyomi.okurigana.map(Into::into),
+            kind: kunyomi.kind,
+        }
+    }
+}
+
+impl From<CompactKunyomi> for Kunyomi {
+    fn from(kunyomi: CompactKunyomi) -> Self {
+        Self {
+            reading: kunyomi.reading.into(),
+            okurigana: kunyomi.okurigana.map(Into::into),
+            kind: kunyomi.kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompactCharacter;
+    use crate::{kunyomi::KunyomiKind, Character, Grade, Kunyomi, Reading, StrokeCount};
+
+    #[test]
+    fn round_trip() {
+        let character = Character::builder()
+            .literal('亜')
+            .stroke_counts(StrokeCount {
+                accepted: 7,
+                miscounts: vec![8],
+            })
+            .grade(Grade::Jouyou)
+            .reading(Reading::Onyomi("ア".into()))
+            .reading(Reading::Kunyomi(Kunyomi {
+                reading: "つ".into(),
+                okurigana: Some("ぐ".into()),
+                kind: KunyomiKind::Normal,
+            }))
+            .meaning("fr", "Asie")
+            .meaning("en", "Asia")
+            .meaning("en", "rank next")
+            .meaning("es", "prefijo para Asia")
+            .nanori(vec!["や".into()])
+            .build()
+            .unwrap();
+        let compact = CompactCharacter::from(character.clone());
+        let languages: Vec<_> = compact
+            .translations
+            .iter()
+            .map(|(language, _)| &**language)
+            .collect();
+        assert_eq!(languages, vec!["en", "es", "fr"]);
+        assert_eq!(
+            compact.meanings("en"),
+            Some(&["Asia".into(), "rank next".into()][..])
+        );
+        assert_eq!(compact.meanings("pt"), None);
+        assert_eq!(Character::from(compact), character);
+    }
+}
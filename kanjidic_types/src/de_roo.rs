@@ -1,13 +1,23 @@
 use num_enum::{TryFromPrimitive, TryFromPrimitiveError};
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
 use thiserror::Error;
 
 /// Identification of a kanji in the De Roo system.
 /// <http://www.edrdg.org/wwwjdic/deroo.html>
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DeRoo {
     /// The graphic element that appears at the top of the kanji.
     pub top: ExtremeTop,
@@ -29,6 +39,13 @@ pub struct DeRoo {
     Deserialize_repr,
 )]
 #[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 pub enum ExtremeTop {
     // Dot
     Dot = 3,
@@ -90,6 +107,13 @@ pub enum ExtremeTop {
     Deserialize_repr,
 )]
 #[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 pub enum ExtremeBottom {
     // Dot
     FourDots = 40,
@@ -164,6 +188,32 @@ pub enum ParseError {
     ExtremeBottom(#[from] TryFromPrimitiveError<ExtremeBottom>),
 }
 
+impl DeRoo {
+    /// The code as numbered in the book, such as 3273,
+    /// with the extreme top in the hundreds and the extreme bottom in the last two digits.
+    pub fn code(self) -> u16 {
+        self.top as u16 * 100 + self.bottom as u16
+    }
+}
+
+impl TryFrom<u16> for DeRoo {
+    type Error = ParseError;
+
+    fn try_from(code: u16) -> Result<Self, Self::Error> {
+        let top = u8::try_from(code / 100).map_err(|_| ParseError::Number)?;
+        Ok(DeRoo {
+            top: ExtremeTop::try_from(top)?,
+            bottom: ExtremeBottom::try_from((code % 100) as u8)?,
+        })
+    }
+}
+
+impl Display for DeRoo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
 impl TryFrom<&str> for DeRoo {
     type Error = ParseError;
 
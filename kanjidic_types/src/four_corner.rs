@@ -2,13 +2,26 @@ use crate::TryFromPrimitiveError;
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::{convert::TryFrom, str::Chars};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    str::{Chars, FromStr},
+};
 use thiserror::Error;
 
 /// A kanji classification using the Four Corner system.
 /// <http://www.edrdg.org/wwwjdic/FOURCORNER.html>
+/// Shown as the four corner digits followed by the fifth corner after a period, as in `1060.7`.
+/// When parsing, the period and fifth corner may be left off.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FourCorner {
     /// The stroke at the top left corner.
     pub top_left: Stroke,
@@ -44,6 +57,13 @@ pub struct FourCorner {
     Deserialize_repr,
 )]
 #[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 pub enum Stroke {
     /// 亠
     Lid,
@@ -77,6 +97,8 @@ pub enum ParseError {
     Digit,
     #[error("(Four corner) Expected a period delimiting the fifth corner")]
     Pattern,
+    #[error("(Four corner) Unexpected characters after the code")]
+    Trailing,
 }
 
 impl TryFrom<&str> for FourCorner {
@@ -88,20 +110,49 @@ impl TryFrom<&str> for FourCorner {
         let top_right = take_stroke(&mut iter)?;
         let bottom_left = take_stroke(&mut iter)?;
         let bottom_right = take_stroke(&mut iter)?;
-        if iter.next() != Some('.') {
-            return Err(ParseError::Pattern);
+        let fifth_corner = match iter.next() {
+            None => None,
+            Some('.') => Some(take_stroke(&mut iter)?),
+            Some(_) => return Err(ParseError::Pattern),
+        };
+        if iter.next().is_some() {
+            return Err(ParseError::Trailing);
         }
-        let fifth_corner = take_stroke(&mut iter)?;
         Ok(FourCorner {
             top_left,
             top_right,
             bottom_left,
             bottom_right,
-            fifth_corner: Some(fifth_corner),
+            fifth_corner,
         })
     }
 }
 
+impl FromStr for FourCorner {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.trim())
+    }
+}
+
+impl Display for FourCorner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            self.top_left as u8,
+            self.top_right as u8,
+            self.bottom_left as u8,
+            self.bottom_right as u8
+        )?;
+        if let Some(fifth_corner) = self.fifth_corner {
+            write!(f, ".{}", fifth_corner as u8)?;
+        }
+        Ok(())
+    }
+}
+
 fn take_stroke(chars: &mut Chars) -> Result<Stroke, ParseError> {
     let int: u8 = char_to_u8(chars.next().ok_or(ParseError::ToFewCharacters)?)?;
     let stroke = Stroke::try_from(int)?;
@@ -1,8 +1,18 @@
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
 
 /// The grade level in which the kanji is learned.
+/// Grades are ordered by curriculum stage, from the first elementary school year
+/// through the remaining Jouyou kanji to the Jinmeiyou kanji.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Grade {
     /// A Kyouiku kanji learned in grades 1-6.
     Kyouiku(u8),
@@ -14,3 +24,62 @@ pub enum Grade {
     /// A Jinmeiyou kanji that is a variant of a Jouyou kanji.
     JinmeiyouJouyouVariant,
 }
+
+impl Grade {
+    /// The grade from its number in KANJIDIC2:
+    /// 1 through 6 for Kyouiku kanji, 8 for Jouyou, 9 for Jinmeiyou,
+    /// and 10 for Jinmeiyou variants of Jouyou kanji.
+    pub fn from_raw(n: u8) -> Option<Grade> {
+        match n {
+            1..=6 => Some(Grade::Kyouiku(n)),
+            8 => Some(Grade::Jouyou),
+            9 => Some(Grade::Jinmeiyou),
+            10 => Some(Grade::JinmeiyouJouyouVariant),
+            _ => None,
+        }
+    }
+
+    /// The number of the grade in KANJIDIC2.
+    pub fn to_raw(self) -> u8 {
+        match self {
+            Grade::Kyouiku(year) => year,
+            Grade::Jouyou => 8,
+            Grade::Jinmeiyou => 9,
+            Grade::JinmeiyouJouyouVariant => 10,
+        }
+    }
+
+    /// The elementary school year in which a Kyouiku kanji is taught.
+    pub fn school_year(self) -> Option<u8> {
+        match self {
+            Grade::Kyouiku(year) => Some(year),
+            _ => None,
+        }
+    }
+
+    /// Whether the kanji is taught in elementary school.
+    pub fn is_kyouiku(self) -> bool {
+        matches!(self, Grade::Kyouiku(_))
+    }
+
+    /// Whether the kanji is on the Jouyou list, including the Kyouiku kanji.
+    pub fn is_jouyou(self) -> bool {
+        matches!(self, Grade::Kyouiku(_) | Grade::Jouyou)
+    }
+
+    /// Whether the kanji is approved only for use in names.
+    pub fn is_jinmeiyou(self) -> bool {
+        matches!(self, Grade::Jinmeiyou | Grade::JinmeiyouJouyouVariant)
+    }
+}
+
+impl Display for Grade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Grade::Kyouiku(year) => write!(f, "Kyouiku grade {}", year),
+            Grade::Jouyou => f.write_str("Jouyou"),
+            Grade::Jinmeiyou => f.write_str("Jinmeiyou"),
+            Grade::JinmeiyouJouyouVariant => f.write_str("Jinmeiyou (Jouyou variant)"),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+/// The Revised Romanization of the initial consonants, in Unicode order.
+const INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+
+/// The Revised Romanization of the vowels, in Unicode order.
+const MEDIALS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+
+/// The Revised Romanization of the final consonants, transliterated letter by letter,
+/// in Unicode order.
+const FINALS: [&str; 28] = [
+    "", "g", "kk", "gs", "n", "nj", "nh", "d", "l", "lg", "lm", "lb", "ls", "lt", "lp", "lh", "m",
+    "b", "bs", "s", "ss", "ng", "j", "ch", "k", "t", "p", "h",
+];
+
+/// Older spellings of initial consonants found in KANJIDIC2, with the initial they stand for.
+const OTHER_INITIALS: [(&str, usize); 3] = [("gg", 1), ("dd", 4), ("bb", 8)];
+
+/// Older spellings of vowels found in KANJIDIC2, with the vowel they stand for.
+const OTHER_MEDIALS: [(&str, usize); 2] = [("weo", 14), ("eui", 19)];
+
+const FIRST_SYLLABLE: u32 = 0xAC00;
+const SYLLABLES: u32 = 11172;
+
+/// Romanizes Hangul syllables with the transliteration form of the Revised Romanization,
+/// which spells each syllable the same way wherever it appears, as in `og` for 옥.
+/// Returns `None` if the text has anything other than Hangul syllables.
+pub fn romanize(hangul: &str) -> Option<String> {
+    let mut out = String::new();
+    for c in hangul.chars() {
+        let index = (c as u32).checked_sub(FIRST_SYLLABLE)?;
+        if index >= SYLLABLES {
+            return None;
+        }
+        let index = index as usize;
+        out.push_str(INITIALS[index / 588]);
+        out.push_str(MEDIALS[index % 588 / 28]);
+        out.push_str(FINALS[index % 28]);
+    }
+    Some(out)
+}
+
+/// Converts a romanization back to Hangul syllables, the reverse of [`romanize`].
+/// The older spellings that KANJIDIC2 uses, such as `weon` for 원 and `eui` for 의, are accepted.
+/// Returns `None` if the text cannot be split into syllables.
+pub fn from_romanized(romanized: &str) -> Option<String> {
+    let initials = by_length(&INITIALS, &OTHER_INITIALS);
+    let medials = by_length(&MEDIALS, &OTHER_MEDIALS);
+    let finals = by_length(&FINALS, &[]);
+    syllables(&romanized.to_lowercase(), &initials, &medials, &finals)
+}
+
+/// Whether the romanization spells the Hangul, allowing the older spellings
+/// accepted by [`from_romanized`].
+pub fn romanization_matches(romanized: &str, hangul: &str) -> bool {
+    from_romanized(romanized).as_deref() == Some(hangul)
+}
+
+/// The spellings with the jamo they stand for, longest first, so that matching them in order
+/// prefers fewer syllables.
+fn by_length(
+    table: &[&'static str],
+    other: &[(&'static str, usize)],
+) -> Vec<(&'static str, usize)> {
+    let mut spellings: Vec<_> = table
+        .iter()
+        .enumerate()
+        .map(|(i, spelling)| (*spelling, i))
+        .chain(other.iter().copied())
+        .collect();
+    spellings.sort_by_key(|(spelling, _)| std::cmp::Reverse(spelling.len()));
+    spellings
+}
+
+fn syllables(
+    text: &str,
+    initials: &[(&str, usize)],
+    medials: &[(&str, usize)],
+    finals: &[(&str, usize)],
+) -> Option<String> {
+    if text.is_empty() {
+        return Some(String::new());
+    }
+    for &(initial, i) in initials {
+        let after_initial = match text.strip_prefix(initial) {
+            Some(rest) => rest,
+            None => continue,
+        };
+        for &(medial, m) in medials {
+            let after_medial = match after_initial.strip_prefix(medial) {
+                Some(rest) => rest,
+                None => continue,
+            };
+            for &(final_, f) in finals {
+                let rest = match after_medial.strip_prefix(final_) {
+                    Some(rest) => rest,
+                    None => continue,
+                };
+                if let Some(tail) = syllables(rest, initials, medials, finals) {
+                    let code = FIRST_SYLLABLE + ((i * 21 + m) * 28 + f) as u32;
+                    let mut out: String = std::char::from_u32(code).into_iter().collect();
+                    out.push_str(&tail);
+                    return Some(out);
+                }
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,85 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+/// A JIS character set that kuten positions index into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JisStandard {
+    /// JIS X 0208, the main set of the EUC-JP and Shift_JIS encodings.
+    X0208,
+    /// JIS X 0212, the supplementary set of EUC-JP.
+    X0212,
+    /// JIS X 0213, which extends JIS X 0208 across two planes.
+    X0213,
+}
+
+/// The number of rows and of cells in each row of a plane.
+const SIZE: usize = 94;
+
+/// Stands in for positions with no single Unicode character in the embedded tables.
+const UNMAPPED: char = '\u{FFFD}';
+
+/// A 94 by 94 plane of characters, one row per line.
+pub(crate) struct Plane {
+    characters: Vec<char>,
+    positions: HashMap<char, (u8, u8)>,
+}
+
+impl Plane {
+    fn new(table: &str) -> Self {
+        let characters: Vec<char> = table.lines().flat_map(str::chars).collect();
+        debug_assert_eq!(characters.len(), SIZE * SIZE);
+        let mut positions = HashMap::new();
+        for (i, &c) in characters.iter().enumerate() {
+            if c != UNMAPPED {
+                let ku = (i / SIZE + 1) as u8;
+                let ten = (i % SIZE + 1) as u8;
+                positions.entry(c).or_insert((ku, ten));
+            }
+        }
+        Self {
+            characters,
+            positions,
+        }
+    }
+
+    /// The character at the row and cell, both numbered from one.
+    pub(crate) fn get(&self, ku: u8, ten: u8) -> Option<char> {
+        let (ku, ten) = (ku as usize, ten as usize);
+        if !(1..=SIZE).contains(&ku) || !(1..=SIZE).contains(&ten) {
+            return None;
+        }
+        Some(self.characters[(ku - 1) * SIZE + ten - 1]).filter(|&c| c != UNMAPPED)
+    }
+
+    /// The row and cell of the character, preferring the first position for duplicates.
+    pub(crate) fn position(&self, c: char) -> Option<(u8, u8)> {
+        self.positions.get(&c).copied()
+    }
+}
+
+/// The plane of the standard, numbered from one.
+pub(crate) fn plane(standard: JisStandard, plane: u8) -> Option<&'static Plane> {
+    static X0208: OnceLock<Plane> = OnceLock::new();
+    static X0212: OnceLock<Plane> = OnceLock::new();
+    static X0213_1: OnceLock<Plane> = OnceLock::new();
+    static X0213_2: OnceLock<Plane> = OnceLock::new();
+    match (standard, plane) {
+        (JisStandard::X0208, 1) => {
+            Some(X0208.get_or_init(|| Plane::new(include_str!("jis/jis0208.txt"))))
+        }
+        (JisStandard::X0212, 1) => {
+            Some(X0212.get_or_init(|| Plane::new(include_str!("jis/jis0212.txt"))))
+        }
+        (JisStandard::X0213, 1) => {
+            Some(X0213_1.get_or_init(|| Plane::new(include_str!("jis/jis0213-1.txt"))))
+        }
+        (JisStandard::X0213, 2) => {
+            Some(X0213_2.get_or_init(|| Plane::new(include_str!("jis/jis0213-2.txt"))))
+        }
+        _ => None,
+    }
+}
+
+/// The planes of the standard and their numbers.
+pub(crate) fn planes(standard: JisStandard) -> impl Iterator<Item = (u8, &'static Plane)> {
+    (1..=2).filter_map(move |number| Some((number, plane(standard, number)?)))
+}
@@ -0,0 +1,495 @@
+/// Converts kana to modified Hepburn romaji.
+/// Hiragana and katakana are both accepted, and long o and u sounds are written with macrons,
+/// so that コウ becomes `kō`. Characters other than kana are kept as they are.
+pub fn to_romaji(kana: &str) -> String {
+    let kana: Vec<char> = kana.chars().map(hiragana).collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < kana.len() {
+        match kana[i] {
+            'っ' => match syllable(&kana, i + 1) {
+                Some((next, _)) if next.starts_with("ch") => out.push('t'),
+                Some((next, _)) if !next.starts_with(is_vowel) => {
+                    out.extend(next.chars().next());
+                }
+                _ => {}
+            },
+            'ん' => {
+                out.push('n');
+                if let Some((next, _)) = syllable(&kana, i + 1) {
+                    if next.starts_with(|c: char| is_vowel(c) || c == 'y') {
+                        out.push('\'');
+                    }
+                }
+            }
+            'ー' => lengthen(&mut out),
+            'う' if out.ends_with('o') || out.ends_with('u') => lengthen(&mut out),
+            'お' if out.ends_with('o') => lengthen(&mut out),
+            c => match syllable(&kana, i) {
+                Some((romaji, len)) => {
+                    out.push_str(&romaji);
+                    i += len;
+                    continue;
+                }
+                None => out.push(c),
+            },
+        }
+        i += 1;
+    }
+    out
+}
+
+pub(crate) fn hiragana(c: char) -> char {
+    match c {
+        'ァ'..='ヶ' => std::char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+/// Replaces the last vowel with the same vowel written with a macron.
+fn lengthen(out: &mut String) {
+    let long = match out.chars().last() {
+        Some('a') => 'ā',
+        Some('i') => 'ī',
+        Some('u') => 'ū',
+        Some('e') => 'ē',
+        Some('o') => 'ō',
+        _ => return,
+    };
+    out.pop();
+    out.push(long);
+}
+
+/// The romaji for the syllable starting at the index,
+/// together with the number of kana it spans.
+fn syllable(kana: &[char], i: usize) -> Option<(String, usize)> {
+    let first = *kana.get(i)?;
+    let base = single(first)?;
+    let small = kana.get(i + 1).copied();
+    if let Some(vowel) = small.and_then(small_y) {
+        if let Some(stem) = base.strip_suffix('i').filter(|stem| !stem.is_empty()) {
+            let glide = if stem.ends_with("sh") || stem.ends_with("ch") || stem == "j" {
+                ""
+            } else {
+                "y"
+            };
+            return Some((format!("{}{}{}", stem, glide, vowel), 2));
+        }
+    }
+    if let Some(vowel) = small.and_then(small_vowel) {
+        let consonant = match first {
+            'ふ' => Some("f"),
+            'ゔ' => Some("v"),
+            'て' => Some("t"),
+            'で' => Some("d"),
+            'う' => Some("w"),
+            'し' => Some("sh"),
+            'じ' => Some("j"),
+            'ち' => Some("ch"),
+            _ => None,
+        };
+        if let Some(consonant) = consonant {
+            return Some((format!("{}{}", consonant, vowel), 2));
+        }
+    }
+    Some((base.to_owned(), 1))
+}
+
+fn small_y(kana: char) -> Option<char> {
+    match kana {
+        'ゃ' => Some('a'),
+        'ゅ' => Some('u'),
+        'ょ' => Some('o'),
+        _ => None,
+    }
+}
+
+fn small_vowel(kana: char) -> Option<char> {
+    match kana {
+        'ぁ' => Some('a'),
+        'ぃ' => Some('i'),
+        'ぅ' => Some('u'),
+        'ぇ' => Some('e'),
+        'ぉ' => Some('o'),
+        _ => None,
+    }
+}
+
+fn single(kana: char) -> Option<&'static str> {
+    let romaji = match kana {
+        'あ' | 'ぁ' => "a",
+        'い' | 'ぃ' | 'ゐ' => "i",
+        'う' | 'ぅ' => "u",
+        'え' | 'ぇ' | 'ゑ' => "e",
+        'お' | 'ぉ' | 'を' => "o",
+        'か' => "ka",
+        'き' => "ki",
+        'く' => "ku",
+        'け' => "ke",
+        'こ' => "ko",
+        'が' => "ga",
+        'ぎ' => "gi",
+        'ぐ' => "gu",
+        'げ' => "ge",
+        'ご' => "go",
+        'さ' => "sa",
+        'し' => "shi",
+        'す' => "su",
+        'せ' => "se",
+        'そ' => "so",
+        'ざ' => "za",
+        'じ' | 'ぢ' => "ji",
+        'ず' | 'づ' => "zu",
+        'ぜ' => "ze",
+        'ぞ' => "zo",
+        'た' => "ta",
+        'ち' => "chi",
+        'つ' => "tsu",
+        'て' => "te",
+        'と' => "to",
+        'だ' => "da",
+        'で' => "de",
+        'ど' => "do",
+        'な' => "na",
+        'に' => "ni",
+        'ぬ' => "nu",
+        'ね' => "ne",
+        'の' => "no",
+        'は' => "ha",
+        'ひ' => "hi",
+        'ふ' => "fu",
+        'へ' => "he",
+        'ほ' => "ho",
+        'ば' => "ba",
+        'び' => "bi",
+        'ぶ' => "bu",
+        'べ' => "be",
+        'ぼ' => "bo",
+        'ぱ' => "pa",
+        'ぴ' => "pi",
+        'ぷ' => "pu",
+        'ぺ' => "pe",
+        'ぽ' => "po",
+        'ま' => "ma",
+        'み' => "mi",
+        'む' => "mu",
+        'め' => "me",
+        'も' => "mo",
+        'や' | 'ゃ' => "ya",
+        'ゆ' | 'ゅ' => "yu",
+        'よ' | 'ょ' => "yo",
+        'ら' => "ra",
+        'り' => "ri",
+        'る' => "ru",
+        'れ' => "re",
+        'ろ' => "ro",
+        'わ' | 'ゎ' => "wa",
+        'ゔ' => "vu",
+        'ん' => "n",
+        _ => return None,
+    };
+    Some(romaji)
+}
+
+/// Converts katakana to the matching hiragana, leaving other characters as they are.
+pub fn katakana_to_hiragana(text: &str) -> String {
+    text.chars().map(hiragana).collect()
+}
+
+/// Folds a reading into a form for comparison.
+/// Katakana become hiragana, and a long vowel mark becomes the kana it stands for,
+/// so that コー and こう fold to the same text.
+/// Okurigana dots and prefix or suffix dashes are removed.
+pub fn fold(reading: &str) -> String {
+    let mut out = String::with_capacity(reading.len());
+    for c in katakana_to_hiragana(reading).chars() {
+        match c {
+            '.' | '-' => {}
+            'ー' => {
+                let vowel = out.chars().last().and_then(long_vowel).unwrap_or(c);
+                out.push(vowel);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Whether two readings are the same once [folded](fold),
+/// ignoring the difference between hiragana and katakana and how long vowels are written.
+pub fn reading_matches(a: &str, b: &str) -> bool {
+    fold(a) == fold(b)
+}
+
+/// The kana that lengthens the vowel of the given hiragana.
+/// Long e and o sounds are written with い and う in readings.
+fn long_vowel(kana: char) -> Option<char> {
+    match kana {
+        'あ' | 'ぁ' | 'か' | 'が' | 'さ' | 'ざ' | 'た' | 'だ' | 'な' | 'は' | 'ば' | 'ぱ'
+        | 'ま' | 'や' | 'ゃ' | 'ら' | 'わ' | 'ゎ' => Some('あ'),
+        'い' | 'ぃ' | 'き' | 'ぎ' | 'し' | 'じ' | 'ち' | 'ぢ' | 'に' | 'ひ' | 'び' | 'ぴ'
+        | 'み' | 'り' | 'ゐ' => Some('い'),
+        'う' | 'ぅ' | 'く' | 'ぐ' | 'す' | 'ず' | 'つ' | 'づ' | 'ぬ' | 'ふ' | 'ぶ' | 'ぷ'
+        | 'む' | 'ゆ' | 'ゅ' | 'る' | 'ゔ' => Some('う'),
+        'え' | 'ぇ' | 'け' | 'げ' | 'せ' | 'ぜ' | 'て' | 'で' | 'ね' | 'へ' | 'べ' | 'ぺ'
+        | 'め' | 'れ' | 'ゑ' => Some('い'),
+        'お' | 'ぉ' | 'こ' | 'ご' | 'そ' | 'ぞ' | 'と' | 'ど' | 'の' | 'ほ' | 'ぼ' | 'ぽ'
+        | 'も' | 'よ' | 'ょ' | 'ろ' | 'を' => Some('う'),
+        _ => None,
+    }
+}
+
+/// Whether the text is written in romaji rather than kana.
+pub fn is_romaji(text: &str) -> bool {
+    text.chars()
+        .any(|c| c.is_ascii_alphabetic() || expand_macron(c).is_some())
+}
+
+/// Converts romaji to hiragana, accepting Hepburn and Kunrei-shiki spellings.
+/// A doubled consonant becomes a small っ, and `n'` or `nn` writes ん where `n` would be ambiguous.
+/// Vowels with macrons are written out, so that both `kou` and `kō` give こう.
+/// Since a macron does not say which kana lengthens the vowel,
+/// `ō` gives both おう and おお, and `ē` both えい and ええ.
+/// Dots and dashes are kept. Returns every spelling, or none if some of the text is not romaji.
+pub fn romaji_to_hiragana(romaji: &str) -> Vec<String> {
+    let mut spellings: Vec<Vec<char>> = vec![vec![]];
+    for c in romaji.to_lowercase().chars() {
+        spellings = match expand_macron(c) {
+            Some((vowel, longs)) => spellings
+                .iter()
+                .flat_map(|spelling| {
+                    longs.iter().map(move |&long| {
+                        let mut spelling = spelling.clone();
+                        spelling.extend([vowel, long]);
+                        spelling
+                    })
+                })
+                .collect(),
+            None => {
+                for spelling in spellings.iter_mut() {
+                    spelling.push(c);
+                }
+                spellings
+            }
+        };
+    }
+    spellings
+        .iter()
+        .map(|text| spell(text))
+        .collect::<Option<_>>()
+        .unwrap_or_default()
+}
+
+/// Converts romaji with any macrons already written out to hiragana.
+fn spell(text: &[char]) -> Option<String> {
+    let vowel_at = |i: usize| text.get(i).is_some_and(|&c| is_vowel(c));
+    let mut out = String::new();
+    let mut i = 0;
+    while i < text.len() {
+        let c = text[i];
+        let next = text.get(i + 1);
+        if c == '.' || c == '-' {
+            out.push(c);
+            i += 1;
+            continue;
+        }
+        if c == 'n' && !vowel_at(i + 1) && next != Some(&'y') {
+            out.push('ん');
+            let doubled = next == Some(&'n') && !vowel_at(i + 2);
+            i += if next == Some(&'\'') || doubled { 2 } else { 1 };
+            continue;
+        }
+        let geminate = next == Some(&c) || (c == 't' && next == Some(&'c'));
+        if geminate && c.is_ascii_alphabetic() && !is_vowel(c) {
+            out.push('っ');
+            i += 1;
+            continue;
+        }
+        let (kana, len) = (1..=3)
+            .rev()
+            .filter(|len| i + len <= text.len())
+            .find_map(|len| {
+                let syllable: String = text[i..i + len].iter().collect();
+                romaji_syllable(&syllable).map(|kana| (kana, len))
+            })?;
+        out.push_str(kana);
+        i += len;
+    }
+    Some(out)
+}
+
+/// Splits a vowel with a macron into the vowel and the kana letters that may lengthen it.
+fn expand_macron(c: char) -> Option<(char, &'static [char])> {
+    match c {
+        'ā' | 'Ā' => Some(('a', &['a'])),
+        'ī' | 'Ī' => Some(('i', &['i'])),
+        'ū' | 'Ū' => Some(('u', &['u'])),
+        'ē' | 'Ē' => Some(('e', &['i', 'e'])),
+        'ō' | 'Ō' => Some(('o', &['u', 'o'])),
+        _ => None,
+    }
+}
+
+fn romaji_syllable(syllable: &str) -> Option<&'static str> {
+    let kana = match syllable {
+        "a" => "あ",
+        "i" => "い",
+        "u" => "う",
+        "e" => "え",
+        "o" => "お",
+        "ka" => "か",
+        "ki" => "き",
+        "ku" => "く",
+        "ke" => "け",
+        "ko" => "こ",
+        "kya" => "きゃ",
+        "kyu" => "きゅ",
+        "kyo" => "きょ",
+        "ga" => "が",
+        "gi" => "ぎ",
+        "gu" => "ぐ",
+        "ge" => "げ",
+        "go" => "ご",
+        "gya" => "ぎゃ",
+        "gyu" => "ぎゅ",
+        "gyo" => "ぎょ",
+        "sa" => "さ",
+        "shi" | "si" => "し",
+        "su" => "す",
+        "se" => "せ",
+        "so" => "そ",
+        "sha" | "sya" => "しゃ",
+        "shu" | "syu" => "しゅ",
+        "sho" | "syo" => "しょ",
+        "she" => "しぇ",
+        "za" => "ざ",
+        "ji" | "zi" => "じ",
+        "zu" => "ず",
+        "ze" => "ぜ",
+        "zo" => "ぞ",
+        "ja" | "jya" | "zya" => "じゃ",
+        "ju" | "jyu" | "zyu" => "じゅ",
+        "jo" | "jyo" | "zyo" => "じょ",
+        "je" => "じぇ",
+        "ta" => "た",
+        "chi" | "ti" => "ち",
+        "tsu" | "tu" => "つ",
+        "te" => "て",
+        "to" => "と",
+        "cha" | "tya" => "ちゃ",
+        "chu" | "tyu" => "ちゅ",
+        "cho" | "tyo" => "ちょ",
+        "che" => "ちぇ",
+        "da" => "だ",
+        "di" => "ぢ",
+        "du" => "づ",
+        "de" => "で",
+        "do" => "ど",
+        "na" => "な",
+        "ni" => "に",
+        "nu" => "ぬ",
+        "ne" => "ね",
+        "no" => "の",
+        "nya" => "にゃ",
+        "nyu" => "にゅ",
+        "nyo" => "にょ",
+        "ha" => "は",
+        "hi" => "ひ",
+        "fu" | "hu" => "ふ",
+        "he" => "へ",
+        "ho" => "ほ",
+        "hya" => "ひゃ",
+        "hyu" => "ひゅ",
+        "hyo" => "ひょ",
+        "fa" => "ふぁ",
+        "fi" => "ふぃ",
+        "fe" => "ふぇ",
+        "fo" => "ふぉ",
+        "ba" => "ば",
+        "bi" => "び",
+        "bu" => "ぶ",
+        "be" => "べ",
+        "bo" => "ぼ",
+        "bya" => "びゃ",
+        "byu" => "びゅ",
+        "byo" => "びょ",
+        "pa" => "ぱ",
+        "pi" => "ぴ",
+        "pu" => "ぷ",
+        "pe" => "ぺ",
+        "po" => "ぽ",
+        "pya" => "ぴゃ",
+        "pyu" => "ぴゅ",
+        "pyo" => "ぴょ",
+        "ma" => "ま",
+        "mi" => "み",
+        "mu" => "む",
+        "me" => "め",
+        "mo" => "も",
+        "mya" => "みゃ",
+        "myu" => "みゅ",
+        "myo" => "みょ",
+        "ya" => "や",
+        "yu" => "ゆ",
+        "yo" => "よ",
+        "ra" => "ら",
+        "ri" => "り",
+        "ru" => "る",
+        "re" => "れ",
+        "ro" => "ろ",
+        "rya" => "りゃ",
+        "ryu" => "りゅ",
+        "ryo" => "りょ",
+        "wa" => "わ",
+        "wo" => "を",
+        "vu" => "ゔ",
+        _ => return None,
+    };
+    Some(kana)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold, katakana_to_hiragana, reading_matches, romaji_to_hiragana};
+
+    #[test]
+    fn folded() {
+        assert_eq!(katakana_to_hiragana("コウ"), "こう");
+        assert_eq!(fold("コー"), "こう");
+        assert_eq!(fold("ケー"), "けい");
+        assert_eq!(fold("カー"), "かあ");
+        assert_eq!(fold("つ.ぐ"), "つぐ");
+        assert_eq!(fold("ー"), "ー");
+    }
+
+    #[test]
+    fn matches() {
+        assert!(reading_matches("コウ", "こう"));
+        assert!(reading_matches("キュー", "きゅう"));
+        assert!(!reading_matches("コウ", "こ"));
+    }
+
+    #[test]
+    fn romaji() {
+        let kana = |romaji| romaji_to_hiragana(romaji).remove(0);
+        assert_eq!(kana("kou"), "こう");
+        assert_eq!(romaji_to_hiragana("kō"), vec!["こう", "こお"]);
+        assert_eq!(
+            romaji_to_hiragana("ōkē"),
+            vec!["おうけい", "おうけえ", "おおけい", "おおけえ"]
+        );
+        assert_eq!(kana("Tsu.gu"), "つ.ぐ");
+        assert_eq!(kana("shinbun"), "しんぶん");
+        assert_eq!(kana("kin'en"), "きんえん");
+        assert_eq!(kana("onna"), "おんな");
+        assert_eq!(kana("kann"), "かん");
+        assert_eq!(kana("gakkou"), "がっこう");
+        assert_eq!(kana("matcha"), "まっちゃ");
+        assert_eq!(kana("kyuu"), "きゅう");
+        assert!(romaji_to_hiragana("kq").is_empty());
+    }
+}
@@ -16,6 +16,13 @@ use serde_repr::*;
     Deserialize_repr,
 )]
 #[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 pub enum KangXi {
     One = 1,
     Line,
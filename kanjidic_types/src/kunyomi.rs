@@ -1,4 +1,7 @@
-use crate::shared::{IResult, NomErr, NomErrorReason};
+use crate::{
+    kana::hiragana,
+    shared::{IResult, NomErr, NomErrorReason},
+};
 use nom::{
     bytes::complete::is_not,
     character::complete::char,
@@ -7,12 +10,22 @@ use nom::{
     sequence::tuple,
 };
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
 use thiserror::Error;
 
 /// A kunyomi kanji reading.
 #[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Kunyomi {
     /// The kunyomi reading
     pub reading: String,
@@ -25,6 +38,13 @@ pub struct Kunyomi {
 
 /// The kind of kunyomi reading.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum KunyomiKind {
     /// A normal reading
     Normal,
@@ -52,36 +72,128 @@ impl TryFrom<&str> for Kunyomi {
     type Error = ParseError;
 
     fn try_from(text: &str) -> Result<Self, Self::Error> {
-        let (_i, (pre, pieces, post)) = parts(text)?;
-
-        let kind = if post {
-            KunyomiKind::Prefix
-        } else if pre {
-            KunyomiKind::Suffix
-        } else {
-            KunyomiKind::Normal
-        };
-
-        let mut iter = pieces.into_iter();
-        let reading = iter.next().ok_or(ParseError::IncorrectPieces)?;
-        let okurigana = iter.next();
-        if iter.next().is_some() {
-            return Err(ParseError::IncorrectPieces);
-        }
+        let (kind, reading, okurigana) = split(text)?;
         Ok(Kunyomi {
-            reading,
-            okurigana,
+            reading: reading.to_owned(),
+            okurigana: okurigana.map(str::to_owned),
             kind,
         })
     }
 }
 
-fn parts(s: &str) -> IResult<(bool, Vec<String>, bool)> {
+/// Splits kunyomi notation such as `つ.ぐ` or `-つ` into its kind, reading, and okurigana
+/// without copying.
+pub fn split(text: &str) -> Result<(KunyomiKind, &str, Option<&str>), ParseError> {
+    let (_i, (pre, pieces, post)) = parts(text)?;
+
+    let kind = if post {
+        KunyomiKind::Prefix
+    } else if pre {
+        KunyomiKind::Suffix
+    } else {
+        KunyomiKind::Normal
+    };
+
+    let mut iter = pieces.into_iter();
+    let reading = iter.next().ok_or(ParseError::IncorrectPieces)?;
+    let okurigana = iter.next();
+    if iter.next().is_some() {
+        return Err(ParseError::IncorrectPieces);
+    }
+    Ok((kind, reading, okurigana))
+}
+
+impl Kunyomi {
+    /// Whether the reading of a word, covering this kanji and the kana written after it,
+    /// plausibly uses this kunyomi. The okurigana may be inflected,
+    /// so `ついで` matches `つ.ぐ` and `たべない` matches `た.べる`,
+    /// or left off, as in `うけ` for the 受 of 受付.
+    /// Readings without okurigana only match exactly.
+    pub fn matches_word(&self, word: &str) -> bool {
+        let word: String = word.chars().map(hiragana).collect();
+        let reading: String = self.reading.chars().map(hiragana).collect();
+        let rest = match word.strip_prefix(reading.as_str()) {
+            Some(rest) => rest,
+            None => return false,
+        };
+        let okurigana: Vec<char> = match &self.okurigana {
+            Some(okurigana) => okurigana.chars().map(hiragana).collect(),
+            None => return rest.is_empty(),
+        };
+        let rest: Vec<char> = rest.chars().collect();
+        let (&last, fixed) = match okurigana.split_last() {
+            Some(split) => split,
+            None => return rest.is_empty(),
+        };
+        if rest.len() <= fixed.len() {
+            return fixed.starts_with(&rest);
+        }
+        if !rest.starts_with(fixed) {
+            return false;
+        }
+        let next = rest[fixed.len()];
+        if next == last {
+            return true;
+        }
+        let before = fixed.last().copied().or_else(|| reading.chars().last());
+        match last {
+            'い' => "かくけさそすみ".contains(next),
+            'る' if before.is_some_and(|c| ICHIDAN_STEMS.contains(c))
+                && ICHIDAN_FORMS.contains(next) =>
+            {
+                true
+            }
+            _ => godan_forms(last).is_some_and(|forms| forms.contains(next)),
+        }
+    }
+}
+
+/// Kana in the i and e rows, which end the stem of an ichidan verb.
+const ICHIDAN_STEMS: &str = "いきぎしじちぢにひびぴみりえけげせぜてでねへべぺめれ";
+
+/// The kana that can follow the stem of an ichidan verb when inflected,
+/// as in `ない`, `ます`, `た`, `て`, `ろ`, `よう`, `れば`, `られる`, `させる`, and `ず`.
+const ICHIDAN_FORMS: &str = "なまたてろよれらさず";
+
+/// The kana a godan verb ending can change to when inflected,
+/// including the sound changes before `て` and `た`.
+fn godan_forms(ending: char) -> Option<&'static str> {
+    match ending {
+        'う' => Some("わいえおっ"),
+        'く' => Some("かきけこいっ"),
+        'ぐ' => Some("がぎげごい"),
+        'す' => Some("さしせそ"),
+        'つ' => Some("たちてとっ"),
+        'ぬ' => Some("なにねのん"),
+        'ぶ' => Some("ばびべぼん"),
+        'む' => Some("まみめもん"),
+        'る' => Some("らりれろっ"),
+        _ => None,
+    }
+}
+
+impl Display for Kunyomi {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.kind == KunyomiKind::Suffix {
+            f.write_str("-")?;
+        }
+        f.write_str(&self.reading)?;
+        if let Some(okurigana) = &self.okurigana {
+            write!(f, ".{}", okurigana)?;
+        }
+        if self.kind == KunyomiKind::Prefix {
+            f.write_str("-")?;
+        }
+        Ok(())
+    }
+}
+
+fn parts(s: &str) -> IResult<(bool, Vec<&str>, bool)> {
     tuple((fix, okurigana, fix))(s)
 }
 
-fn okurigana(s: &str) -> IResult<Vec<String>> {
-    separated_list1(char('.'), map(is_not("-."), |s: &str| s.into()))(s)
+fn okurigana(s: &str) -> IResult<Vec<&str>> {
+    separated_list1(char('.'), is_not("-."))(s)
 }
 
 fn fix(s: &str) -> IResult<bool> {
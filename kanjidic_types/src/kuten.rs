@@ -1,14 +1,30 @@
-use crate::{shared::NomErrorReason, take_uint, IResult, NomErr};
+use crate::{
+    jis::{self, JisStandard},
+    shared::NomErrorReason,
+    take_uint, IResult, NomErr,
+};
 use nom::character::complete::char;
 use nom::sequence::tuple;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
 use thiserror::Error;
 
 /// A kuten representation of a JIS X 0213 character.
 /// <http://unicode-iphone.blogspot.com/2010/05/kuten-code-to-unicode.html>
+/// Printed as the plane, ku, and ten joined by dashes,
+/// with ku and ten padded to two digits, as in `1-16-01`.
 #[derive(Debug, PartialEq, Clone, Copy, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Kuten {
     /// The plane on which a kuten representation is found.
     pub plane: u8,
@@ -40,6 +56,163 @@ impl TryFrom<&str> for Kuten {
     }
 }
 
+impl Display for Kuten {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.plane, self.ku, self.ten)
+    }
+}
+
 fn kuten_parts(s: &str) -> IResult<(u8, char, u8, char, u8)> {
     tuple((take_uint, char('-'), take_uint, char('-'), take_uint))(s)
 }
+
+impl Kuten {
+    /// The Unicode character at this position in the given JIS standard.
+    /// JIS X 0208 and JIS X 0212 positions are expected on plane 1.
+    /// Positions holding a sequence of several Unicode characters give `None`.
+    pub fn to_unicode(self, standard: JisStandard) -> Option<char> {
+        jis::plane(standard, self.plane)?.get(self.ku, self.ten)
+    }
+
+    /// The position of the Unicode character in the given JIS standard.
+    pub fn from_unicode(c: char, standard: JisStandard) -> Option<Self> {
+        jis::planes(standard).find_map(|(plane, table)| {
+            let (ku, ten) = table.position(c)?;
+            Some(Self { plane, ku, ten })
+        })
+    }
+}
+
+impl Kuten {
+    /// Encodes the position in the given JIS standard as EUC-JP bytes.
+    /// JIS X 0212 and the second plane of JIS X 0213 are prefixed with `0x8F`,
+    /// following EUC-JP and EUC-JIS-2004 respectively.
+    pub fn to_euc_jp(self, standard: JisStandard) -> Option<Vec<u8>> {
+        let pair = self.checked()?.map(|part| part + 0xA0);
+        match (standard, self.plane) {
+            (JisStandard::X0208, 1) | (JisStandard::X0213, 1) => Some(pair.to_vec()),
+            (JisStandard::X0212, 1) | (JisStandard::X0213, 2) => Some(vec![0x8F, pair[0], pair[1]]),
+            _ => None,
+        }
+    }
+
+    /// Decodes a single EUC-JP character as a position in the given JIS standard,
+    /// the inverse of [`Kuten::to_euc_jp`].
+    pub fn from_euc_jp(bytes: &[u8], standard: JisStandard) -> Option<Self> {
+        let (plane, pair) = match (standard, bytes) {
+            (JisStandard::X0208, [a, b]) | (JisStandard::X0213, [a, b]) => (1, [*a, *b]),
+            (JisStandard::X0212, [0x8F, a, b]) => (1, [*a, *b]),
+            (JisStandard::X0213, [0x8F, a, b]) => (2, [*a, *b]),
+            _ => return None,
+        };
+        if !pair.iter().all(|byte| (0xA1..=0xFE).contains(byte)) {
+            return None;
+        }
+        Some(Self {
+            plane,
+            ku: pair[0] - 0xA0,
+            ten: pair[1] - 0xA0,
+        })
+    }
+
+    /// Encodes the position in the given JIS standard as Shift_JIS bytes,
+    /// using Shift_JIS-2004 for JIS X 0213.
+    /// JIS X 0212 has no Shift_JIS form.
+    pub fn to_shift_jis(self, standard: JisStandard) -> Option<Vec<u8>> {
+        let [ku, ten] = self.checked()?;
+        let row = ku as u16;
+        let lead = match (standard, self.plane) {
+            (JisStandard::X0208, 1) | (JisStandard::X0213, 1) => {
+                if ku <= 62 {
+                    (row + 0x101) / 2
+                } else {
+                    (row + 0x181) / 2
+                }
+            }
+            (JisStandard::X0213, 2) => match ku {
+                1 | 3 | 4 | 5 | 8 | 12..=15 => (row + 0x1DF) / 2 - row / 8 * 3,
+                78..=94 => (row + 0x19B) / 2,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let trail = if ku % 2 == 1 {
+            ten + if ten < 64 { 0x3F } else { 0x40 }
+        } else {
+            ten + 0x9E
+        };
+        Some(vec![lead as u8, trail])
+    }
+
+    /// Decodes a single Shift_JIS character as a position in the given JIS standard,
+    /// the inverse of [`Kuten::to_shift_jis`].
+    pub fn from_shift_jis(bytes: &[u8], standard: JisStandard) -> Option<Self> {
+        let (lead, trail) = match bytes {
+            [lead, trail] => (*lead, *trail),
+            _ => return None,
+        };
+        let (odd, ten) = match trail {
+            0x40..=0x7E => (true, trail - 0x3F),
+            0x80..=0x9E => (true, trail - 0x40),
+            0x9F..=0xFC => (false, trail - 0x9E),
+            _ => return None,
+        };
+        let (plane, rows) = match (standard, lead) {
+            (JisStandard::X0208, 0x81..=0x9F) | (JisStandard::X0213, 0x81..=0x9F) => {
+                let first = (lead - 0x81) * 2 + 1;
+                (1, (first, first + 1))
+            }
+            (JisStandard::X0208, 0xE0..=0xEF) | (JisStandard::X0213, 0xE0..=0xEF) => {
+                let first = (lead - 0xE0) * 2 + 63;
+                (1, (first, first + 1))
+            }
+            (JisStandard::X0213, 0xF0..=0xFC) => {
+                let rows = match lead {
+                    0xF0 => (1, 8),
+                    0xF1 => (3, 4),
+                    0xF2 => (5, 12),
+                    0xF3 => (13, 14),
+                    0xF4 => (15, 78),
+                    _ => {
+                        let first = (lead - 0xF5) * 2 + 79;
+                        (first, first + 1)
+                    }
+                };
+                (2, rows)
+            }
+            _ => return None,
+        };
+        let ku = if odd { rows.0 } else { rows.1 };
+        Some(Self { plane, ku, ten })
+    }
+
+    /// Encodes the position in the given JIS standard as an ISO-2022-JP character:
+    /// the escape sequence designating the character set, the two bytes of the position,
+    /// and the escape sequence switching back to ASCII.
+    /// JIS X 0212 uses the ISO-2022-JP-1 designation
+    /// and JIS X 0213 uses the ISO-2022-JP-2004 designations for each plane.
+    pub fn to_iso_2022_jp(self, standard: JisStandard) -> Option<Vec<u8>> {
+        let [ku, ten] = self.checked()?;
+        let designation: &[u8] = match (standard, self.plane) {
+            (JisStandard::X0208, 1) => b"\x1B$B",
+            (JisStandard::X0212, 1) => b"\x1B$(D",
+            (JisStandard::X0213, 1) => b"\x1B$(Q",
+            (JisStandard::X0213, 2) => b"\x1B$(P",
+            _ => return None,
+        };
+        let mut bytes = designation.to_vec();
+        bytes.extend([ku + 0x20, ten + 0x20]);
+        bytes.extend(b"\x1B(B");
+        Some(bytes)
+    }
+
+    /// The row and cell, if both are within the 94 by 94 grid.
+    fn checked(self) -> Option<[u8; 2]> {
+        let valid = |part: u8| (1..=94).contains(&part);
+        if valid(self.ku) && valid(self.ten) {
+            Some([self.ku, self.ten])
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// The language of a meaning, identified in KANJIDIC2 by its ISO 639-1 code.
+/// Languages are compared, hashed, and serialized by their code,
+/// so a map keyed by `Language` can be indexed with a `&str` such as `"en"`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub enum Language {
+    /// English, which meanings without a language attribute are in.
+    En,
+    /// French.
+    Fr,
+    /// Spanish.
+    Es,
+    /// Portuguese.
+    Pt,
+    /// Any other language code.
+    Other(String),
+}
+
+impl Language {
+    /// The language code, such as `en`.
+    pub fn code(&self) -> &str {
+        match self {
+            Language::En => "en",
+            Language::Fr => "fr",
+            Language::Es => "es",
+            Language::Pt => "pt",
+            Language::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for Language {
+    fn from(code: &str) -> Self {
+        match code {
+            "en" => Language::En,
+            "fr" => Language::Fr,
+            "es" => Language::Es,
+            "pt" => Language::Pt,
+            code => Language::Other(code.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Language {
+    fn from(code: String) -> Self {
+        match Language::from(code.as_str()) {
+            Language::Other(_) => Language::Other(code),
+            known => known,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Borrow<str> for Language {
+    fn borrow(&self) -> &str {
+        self.code()
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Language {}
+
+impl PartialEq<str> for Language {
+    fn eq(&self, other: &str) -> bool {
+        self.code() == other
+    }
+}
+
+impl PartialEq<&str> for Language {
+    fn eq(&self, other: &&str) -> bool {
+        self.code() == *other
+    }
+}
+
+impl PartialOrd for Language {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Language {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code().cmp(other.code())
+    }
+}
+
+impl Hash for Language {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state)
+    }
+}
+
+impl Serialize for Language {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Language::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Language {
+    fn schema_name() -> String {
+        "Language".to_owned()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedLanguage {
+    /// The language code, such as `en`.
+    pub fn code(&self) -> &str {
+        match self {
+            ArchivedLanguage::En => "en",
+            ArchivedLanguage::Fr => "fr",
+            ArchivedLanguage::Es => "es",
+            ArchivedLanguage::Pt => "pt",
+            ArchivedLanguage::Other(code) => code,
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl Borrow<str> for ArchivedLanguage {
+    fn borrow(&self) -> &str {
+        self.code()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl PartialEq for ArchivedLanguage {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl Eq for ArchivedLanguage {}
+
+#[cfg(feature = "rkyv")]
+impl PartialOrd for ArchivedLanguage {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl Ord for ArchivedLanguage {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code().cmp(other.code())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl Hash for ArchivedLanguage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state)
+    }
+}
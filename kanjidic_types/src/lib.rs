@@ -7,6 +7,11 @@ pub use character::Character;
 pub mod codepoint;
 pub use codepoint::Codepoint;
 
+#[cfg(feature = "compact")]
+pub mod compact;
+#[cfg(feature = "compact")]
+pub use compact::CompactCharacter;
+
 pub mod de_roo;
 pub use de_roo::DeRoo;
 
@@ -16,6 +21,13 @@ pub use four_corner::FourCorner;
 pub mod grade;
 pub use grade::Grade;
 
+pub mod hangul;
+
+pub mod jis;
+pub use jis::JisStandard;
+
+pub mod kana;
+
 pub mod kangxi;
 pub use kangxi::KangXi;
 
@@ -25,6 +37,9 @@ pub use kunyomi::Kunyomi;
 pub mod kuten;
 pub use kuten::Kuten;
 
+pub mod language;
+pub use language::Language;
+
 pub mod moro;
 pub use moro::Moro;
 
@@ -44,11 +59,13 @@ pub mod reading;
 pub use reading::Reading;
 
 pub mod reference;
-pub use reference::Reference;
+pub use reference::{Reference, ReferenceKind};
 
 pub mod skip;
 pub use skip::Skip;
 
+pub mod sort;
+
 pub mod spahn_hadamitzky;
 pub use spahn_hadamitzky::{Descriptor as ShDesc, Radical as ShRadical};
 
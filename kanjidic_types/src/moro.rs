@@ -1,8 +1,18 @@
 use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
 
 /// An entry in the dictionary Daikanwajiten.
+/// [`Display`] gives the citation form, such as `v1 p525 #272`,
+/// and [`Moro::compact`] gives the index as it appears in KANJIDIC2, such as `272`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Moro {
     /// The volume
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -19,6 +29,13 @@ pub struct Moro {
 
 /// A letter that appears at the end of the index
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum MoroSuffix {
     /// No suffix
     None,
@@ -30,6 +47,25 @@ pub enum MoroSuffix {
     PX,
 }
 
+impl Moro {
+    /// The index and suffix as written in KANJIDIC2, such as `272` or `1234P`.
+    pub fn compact(&self) -> String {
+        format!("{}{}", self.index, self.suffix)
+    }
+}
+
+impl Display for Moro {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(volume) = self.volume {
+            write!(f, "v{} ", volume)?;
+        }
+        if let Some(page) = self.page {
+            write!(f, "p{} ", page)?;
+        }
+        write!(f, "#{}", self.compact())
+    }
+}
+
 impl Default for MoroSuffix {
     fn default() -> Self {
         Self::None
@@ -40,4 +76,20 @@ impl MoroSuffix {
     pub fn is_none(&self) -> bool {
         *self == Self::None
     }
+
+    /// The letters written after the index.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MoroSuffix::None => "",
+            MoroSuffix::P => "P",
+            MoroSuffix::X => "X",
+            MoroSuffix::PX => "PX",
+        }
+    }
+}
+
+impl Display for MoroSuffix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
@@ -4,13 +4,24 @@ use nom::{
     combinator::{map, map_res},
     sequence::tuple,
 };
-use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
 use thiserror::Error;
 
-/// An index into the Japanese Names reference book
+/// An index into the Japanese Names reference book.
+/// [`Display`] gives the index as written in KANJIDIC2, such as `1234A`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Oneill {
     /// The reference number
     pub number: u16,
@@ -19,13 +30,22 @@ pub struct Oneill {
     pub suffix: OneillSuffix,
 }
 
-/// The suffix for a Japanese Names reference
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+/// The suffix for a Japanese Names reference.
+/// It is serialized as the letter itself, such as `"A"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub enum OneillSuffix {
     /// No suffix
     None,
     /// 'A' suffix
     A,
+    /// Any other uppercase letter suffix, never 'A'
+    Other(char),
 }
 
 impl Default for OneillSuffix {
@@ -38,6 +58,66 @@ impl OneillSuffix {
     pub fn is_none(&self) -> bool {
         *self == Self::None
     }
+
+    /// The letter written after the reference number, if any.
+    pub fn letter(&self) -> Option<char> {
+        match self {
+            OneillSuffix::None => None,
+            OneillSuffix::A => Some('A'),
+            OneillSuffix::Other(c) => Some(*c),
+        }
+    }
+}
+
+impl From<char> for OneillSuffix {
+    fn from(c: char) -> Self {
+        match c {
+            'A' => OneillSuffix::A,
+            c => OneillSuffix::Other(c),
+        }
+    }
+}
+
+impl Serialize for OneillSuffix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.letter().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OneillSuffix {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Option::<char>::deserialize(deserializer)? {
+            None => Ok(OneillSuffix::None),
+            Some(letter) if letter.is_ascii_uppercase() => Ok(letter.into()),
+            Some(_) => Err(de::Error::custom(ParseError::UnknownSuffix)),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for OneillSuffix {
+    fn schema_name() -> String {
+        "OneillSuffix".to_owned()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Option::<char>::json_schema(gen)
+    }
+}
+
+impl Display for OneillSuffix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.letter() {
+            Some(letter) => write!(f, "{}", letter),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for Oneill {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.number, self.suffix)
+    }
 }
 
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
@@ -75,9 +155,12 @@ fn parts(s: &str) -> IResult<(u16, OneillSuffix)> {
 }
 
 fn suffix(s: &str) -> IResult<OneillSuffix> {
-    map_res(take_while(|c: char| c.is_ascii_alphabetic()), |v| match v {
-        "A" => Ok(OneillSuffix::A),
-        "" => Ok(OneillSuffix::None),
-        _ => Err(ParseError::UnknownSuffix),
+    map_res(take_while(|c: char| c.is_ascii_alphabetic()), |v: &str| {
+        let mut letters = v.chars();
+        match (letters.next(), letters.next()) {
+            (None, _) => Ok(OneillSuffix::None),
+            (Some(letter), None) if letter.is_ascii_uppercase() => Ok(letter.into()),
+            _ => Err(ParseError::UnknownSuffix),
+        }
     })(s)
 }
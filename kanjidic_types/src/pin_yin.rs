@@ -9,12 +9,19 @@ use nom::{
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::convert::TryFrom;
+use std::{convert::TryFrom, str::FromStr};
 use thiserror::Error;
 
 // A modern PinYin romanization of the Chinese reading.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PinYin {
     /// The romanized reading.
     pub romanization: String,
@@ -38,6 +45,13 @@ pub struct PinYin {
     Deserialize_repr,
 )]
 #[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 pub enum Tone {
     /// A steady high sound
     High = 1,
@@ -57,6 +71,8 @@ pub enum ParseError {
     InvalidTone(#[from] TryFromPrimitiveError<Tone>),
     #[error("(Pin yin) Format: {0}")]
     Format(NomErrorReason),
+    #[error("(Pin yin) Expected letters with at most one tone mark")]
+    Marked,
 }
 
 // Todo: move this one back to parsing and check for others
@@ -77,6 +93,100 @@ impl TryFrom<&str> for PinYin {
     }
 }
 
+/// Reads a syllable written with either a tone number, as in `ya4`, or a tone mark, as in `yà`.
+/// A syllable with neither has the neutral tone.
+impl FromStr for PinYin {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.ends_with(|c: char| c.is_ascii_digit()) {
+            Self::try_from(s)
+        } else {
+            from_marked(s)
+        }
+    }
+}
+
+impl PinYin {
+    /// The romanization with the tone written as a mark over the vowel, such as `yà`.
+    /// The mark goes on an a or e if there is one, on the o of ou,
+    /// and otherwise on the last vowel. Neutral tones have no mark.
+    pub fn to_marked(&self) -> String {
+        let tone = match self.tone {
+            Tone::Neutral => return self.romanization.clone(),
+            tone => tone as usize - 1,
+        };
+        let letters: Vec<char> = self.romanization.chars().collect();
+        let position = letters
+            .iter()
+            .position(|c| matches!(c, 'a' | 'e' | 'ê'))
+            .or_else(|| letters.windows(2).position(|pair| pair == ['o', 'u']))
+            .or_else(|| {
+                letters
+                    .iter()
+                    .rposition(|c| matches!(c, 'i' | 'o' | 'u' | 'ü'))
+            });
+        let mut out = String::new();
+        for (i, &letter) in letters.iter().enumerate() {
+            if Some(i) != position {
+                out.push(letter);
+            } else if let Some((_, marked)) = MARKED.iter().find(|(plain, _)| *plain == letter) {
+                out.push(marked[tone]);
+            } else {
+                out.push(letter);
+                out.push(COMBINING_MARKS[tone]);
+            }
+        }
+        out
+    }
+}
+
+/// Vowels with the marks for the high, rising, low, and falling tones.
+const MARKED: [(char, [char; 4]); 6] = [
+    ('a', ['ā', 'á', 'ǎ', 'à']),
+    ('e', ['ē', 'é', 'ě', 'è']),
+    ('i', ['ī', 'í', 'ǐ', 'ì']),
+    ('o', ['ō', 'ó', 'ǒ', 'ò']),
+    ('u', ['ū', 'ú', 'ǔ', 'ù']),
+    ('ü', ['ǖ', 'ǘ', 'ǚ', 'ǜ']),
+];
+
+/// Combining marks for the high, rising, low, and falling tones,
+/// for vowels such as ê that have no precomposed forms.
+const COMBINING_MARKS: [char; 4] = ['\u{304}', '\u{301}', '\u{30C}', '\u{300}'];
+
+fn from_marked(s: &str) -> Result<PinYin, ParseError> {
+    let mut romanization = String::new();
+    let mut tone = None;
+    for c in s.chars() {
+        let marked = MARKED.iter().find_map(|(plain, marked)| {
+            let tone = marked.iter().position(|&m| m == c)?;
+            Some((Some(*plain), tone))
+        });
+        let marked = marked.or_else(|| {
+            COMBINING_MARKS
+                .iter()
+                .position(|&m| m == c)
+                .map(|tone| (None, tone))
+        });
+        match marked {
+            Some(_) if tone.is_some() => return Err(ParseError::Marked),
+            Some((plain, marked_tone)) => {
+                romanization.extend(plain);
+                tone = Some(marked_tone as u8 + 1);
+            }
+            None if c.is_alphabetic() => romanization.push(c),
+            None => return Err(ParseError::Marked),
+        }
+    }
+    if romanization.is_empty() {
+        return Err(ParseError::Marked);
+    }
+    let tone = Tone::try_from(tone.unwrap_or(Tone::Neutral as u8))?;
+    Ok(PinYin { romanization, tone })
+}
+
 fn parts(s: &str) -> IResult<(String, u8)> {
     map(pronunciation_parts, |(parts, tone)| (parts.join(""), tone))(s)
 }
@@ -5,6 +5,13 @@ use serde::{Deserialize, Serialize};
 /// used for identification and lookup.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum QueryCode {
     /// The Halpern SKIP code
     Skip(Skip),
@@ -21,6 +28,13 @@ pub enum QueryCode {
 /// A possible misclassification of the kanji
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Misclassification {
     /// The skip code of the misclassification
     pub skip: Skip,
@@ -30,6 +44,13 @@ pub struct Misclassification {
 
 /// A kind of kanji misclassification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum MisclassificationKind {
     /// A mistake in the division of the kanji
     Position,
@@ -40,3 +61,26 @@ pub enum MisclassificationKind {
     /// Ambiguous stroke counts
     Ambiguous,
 }
+
+impl MisclassificationKind {
+    /// The kind named by a `skip_misclass` attribute value in KANJIDIC2.
+    pub fn from_attribute(value: &str) -> Option<Self> {
+        match value {
+            "posn" => Some(MisclassificationKind::Position),
+            "stroke_count" => Some(MisclassificationKind::StrokeCount),
+            "stroke_and_posn" => Some(MisclassificationKind::StrokeAndPosition),
+            "stroke_diff" => Some(MisclassificationKind::Ambiguous),
+            _ => None,
+        }
+    }
+
+    /// The `skip_misclass` attribute value for the kind in KANJIDIC2.
+    pub fn attribute(self) -> &'static str {
+        match self {
+            MisclassificationKind::Position => "posn",
+            MisclassificationKind::StrokeCount => "stroke_count",
+            MisclassificationKind::StrokeAndPosition => "stroke_and_posn",
+            MisclassificationKind::Ambiguous => "stroke_diff",
+        }
+    }
+}
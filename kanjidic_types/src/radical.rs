@@ -4,6 +4,13 @@ use serde::{Deserialize, Serialize};
 /// A kanji classification based on its radical.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Radical {
     /// The kind of radical classification
     pub kind: RadicalKind,
@@ -13,6 +20,13 @@ pub struct Radical {
 
 /// The kind of kanji classification
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum RadicalKind {
     /// Based on the KangXi Zidian system.
     /// Referenced from the Shibano JIS Kanwa Jiten.
@@ -20,3 +34,14 @@ pub enum RadicalKind {
     /// As used in the classic Modern Japanese-English Character Dictionary.
     Nelson,
 }
+
+/// The two radicals of a character whose Nelson radical differs from its classical one.
+/// Dictionaries indexed by Nelson radical list these characters under a different radical.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RadicalDiscrepancy {
+    /// The classical radical.
+    pub classical: KangXi,
+    /// The radical used by Nelson.
+    pub nelson: KangXi,
+}
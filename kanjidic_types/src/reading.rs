@@ -1,9 +1,16 @@
-use crate::{Kunyomi, PinYin};
+use crate::{hangul, kana, kunyomi::KunyomiKind, Kunyomi, PinYin};
 use serde::{Deserialize, Serialize};
 
 /// A particular reading or pronunciation of a kanji.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Reading {
     /// The modern romanization of the Chinese reading.
     PinYin(PinYin),
@@ -18,3 +25,54 @@ pub enum Reading {
     /// The kunyomi reading of the kanji in hiragana or katakana.
     Kunyomi(Kunyomi),
 }
+
+impl Reading {
+    /// The Hepburn romanization of an onyomi or kunyomi, as given by [`kana::to_romaji`].
+    /// Kunyomi keep the dot before the okurigana and the dash marking a prefix or suffix,
+    /// such as `tsu.gu` or `-ka`. Other readings have no romanization.
+    pub fn to_romaji(&self) -> Option<String> {
+        match self {
+            Reading::Onyomi(onyomi) => Some(kana::to_romaji(onyomi)),
+            Reading::Kunyomi(kunyomi) => Some(kunyomi_romaji(kunyomi)),
+            _ => None,
+        }
+    }
+
+    /// The Korean reading in romanized form, converting Hangul with [`hangul::romanize`].
+    /// Readings that are not Korean have no Korean romanization.
+    pub fn to_korean_romanized(&self) -> Option<Reading> {
+        match self {
+            Reading::KoreanRomanized(_) => Some(self.clone()),
+            Reading::KoreanHangul(hangul) => hangul::romanize(hangul).map(Reading::KoreanRomanized),
+            _ => None,
+        }
+    }
+
+    /// The Korean reading in Hangul, converting a romanization with [`hangul::from_romanized`].
+    /// Readings that are not Korean have no Hangul form.
+    pub fn to_korean_hangul(&self) -> Option<Reading> {
+        match self {
+            Reading::KoreanHangul(_) => Some(self.clone()),
+            Reading::KoreanRomanized(romanized) => {
+                hangul::from_romanized(romanized).map(Reading::KoreanHangul)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn kunyomi_romaji(kunyomi: &Kunyomi) -> String {
+    let mut out = String::new();
+    if kunyomi.kind == KunyomiKind::Suffix {
+        out.push('-');
+    }
+    out.push_str(&kana::to_romaji(&kunyomi.reading));
+    if let Some(okurigana) = &kunyomi.okurigana {
+        out.push('.');
+        out.push_str(&kana::to_romaji(okurigana));
+    }
+    if kunyomi.kind == KunyomiKind::Prefix {
+        out.push('-');
+    }
+    out
+}
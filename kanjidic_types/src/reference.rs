@@ -1,9 +1,23 @@
 use crate::{BusyPeople, Moro, Oneill};
 use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+use thiserror::Error;
 
 /// An index number into a particular kanji dictionary or reference book.
+/// [`Display`] gives the book title followed by the entry,
+/// such as `Remembering the Kanji, 6th edition: 1234`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Reference {
     /// Modern Reader's Japanese-English Dictionary by Andrew Nelson
     NelsonClassic(u16),
@@ -54,3 +68,301 @@ pub enum Reference {
     /// Les Kanjis dans la tete by Yves Maniette
     Maniette(u16),
 }
+
+impl Reference {
+    /// Which book the reference points into.
+    pub fn kind(&self) -> ReferenceKind {
+        match self {
+            Reference::NelsonClassic(_) => ReferenceKind::NelsonClassic,
+            Reference::NelsonNew(_) => ReferenceKind::NelsonNew,
+            Reference::Njecd(_) => ReferenceKind::Njecd,
+            Reference::Kkd(_) => ReferenceKind::Kkd,
+            Reference::Kkld(_) => ReferenceKind::Kkld,
+            Reference::Kkld2ed(_) => ReferenceKind::Kkld2ed,
+            Reference::Heisig(_) => ReferenceKind::Heisig,
+            Reference::Heisig6(_) => ReferenceKind::Heisig6,
+            Reference::Gakken(_) => ReferenceKind::Gakken,
+            Reference::OneillNames(_) => ReferenceKind::OneillNames,
+            Reference::OneillKk(_) => ReferenceKind::OneillKk,
+            Reference::Moro(_) => ReferenceKind::Moro,
+            Reference::Henshall(_) => ReferenceKind::Henshall,
+            Reference::ShKk(_) => ReferenceKind::ShKk,
+            Reference::ShKk2(_) => ReferenceKind::ShKk2,
+            Reference::Sakade(_) => ReferenceKind::Sakade,
+            Reference::Jfcards(_) => ReferenceKind::Jfcards,
+            Reference::Henshall3(_) => ReferenceKind::Henshall3,
+            Reference::TuttleCards(_) => ReferenceKind::TuttleCards,
+            Reference::Crowley(_) => ReferenceKind::Crowley,
+            Reference::KanjiInContext(_) => ReferenceKind::KanjiInContext,
+            Reference::BusyPeople(_) => ReferenceKind::BusyPeople,
+            Reference::KodanshaCompact(_) => ReferenceKind::KodanshaCompact,
+            Reference::Maniette(_) => ReferenceKind::Maniette,
+        }
+    }
+
+    /// The full title of the book the reference points into.
+    pub fn book_title(&self) -> &'static str {
+        self.kind().book_title()
+    }
+
+    /// The entry number within the book.
+    /// O'Neill and Morohashi references give their number without the suffix,
+    /// and Japanese for Busy People references, which are chapters, have none.
+    pub fn number(&self) -> Option<u16> {
+        match self {
+            Reference::OneillNames(oneill) => Some(oneill.number),
+            Reference::Moro(moro) => Some(moro.index),
+            Reference::BusyPeople(_) => None,
+            Reference::NelsonClassic(n)
+            | Reference::NelsonNew(n)
+            | Reference::Njecd(n)
+            | Reference::Kkd(n)
+            | Reference::Kkld(n)
+            | Reference::Kkld2ed(n)
+            | Reference::Heisig(n)
+            | Reference::Heisig6(n)
+            | Reference::Gakken(n)
+            | Reference::OneillKk(n)
+            | Reference::Henshall(n)
+            | Reference::ShKk(n)
+            | Reference::ShKk2(n)
+            | Reference::Sakade(n)
+            | Reference::Jfcards(n)
+            | Reference::Henshall3(n)
+            | Reference::TuttleCards(n)
+            | Reference::Crowley(n)
+            | Reference::KanjiInContext(n)
+            | Reference::KodanshaCompact(n)
+            | Reference::Maniette(n) => Some(*n),
+        }
+    }
+}
+
+/// The book a [`Reference`] points into, without the entry itself.
+/// It is written and read as the KANJIDIC2 `dr_type` attribute, such as `heisig6`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ReferenceKind {
+    /// Modern Reader's Japanese-English Dictionary by Andrew Nelson
+    NelsonClassic,
+    /// The New Nelson Japanese-English Dictionary by John Haig
+    NelsonNew,
+    /// New Japanese-English Character Dictionary by Jack Halpern
+    Njecd,
+    /// Kodansha's Japanese-English Dictionary by Jack Halpern
+    Kkd,
+    /// Kanji Learners Dictionary by Jack Halpern
+    Kkld,
+    /// Kanji Learners Dictionary Second Edition by Jack Halpern
+    Kkld2ed,
+    /// Remembering the Kanji by James Heisig
+    Heisig,
+    /// Remembering the Kanji Sixth Edition by James Heisig
+    Heisig6,
+    /// A New Dictionary of Kanji Usage
+    Gakken,
+    /// Japanese Names by P.G. O'Neill
+    OneillNames,
+    /// Essential Kanji by P.G. O'Neill
+    OneillKk,
+    /// Daikanwajiten by Morohashi
+    Moro,
+    /// A Guide to Remembering Japanese Characters by Kenneth G. Henshall
+    Henshall,
+    /// Kanji and Kana by Spahn and Hadamitzky
+    ShKk,
+    /// Kanji and Kana 2011 edition by Spahn and Hadamitzky
+    ShKk2,
+    /// A Guide to Reading and Writing Japanese by Florence Sakade
+    Sakade,
+    /// Japanese Kanji Flashcards by Tomoko Okazaki
+    Jfcards,
+    /// A Guide to Reading and Writing Japanese by Henshall
+    Henshall3,
+    /// Tuttle Kanji Cards by Alexander Kask
+    TuttleCards,
+    /// The Kanji Way to Japanese Language Power by Dale Crowley
+    Crowley,
+    /// Kanji in Context by Nishiguchi and Kono
+    KanjiInContext,
+    /// Japanese for Busy People
+    BusyPeople,
+    /// The Kodansha Compact Study Guide
+    KodanshaCompact,
+    /// Les Kanjis dans la tete by Yves Maniette
+    Maniette,
+}
+
+impl ReferenceKind {
+    /// Every kind of reference, in the order of the KANJIDIC2 DTD.
+    pub const ALL: [ReferenceKind; 24] = [
+        ReferenceKind::NelsonClassic,
+        ReferenceKind::NelsonNew,
+        ReferenceKind::Njecd,
+        ReferenceKind::Kkd,
+        ReferenceKind::Kkld,
+        ReferenceKind::Kkld2ed,
+        ReferenceKind::Heisig,
+        ReferenceKind::Heisig6,
+        ReferenceKind::Gakken,
+        ReferenceKind::OneillNames,
+        ReferenceKind::OneillKk,
+        ReferenceKind::Moro,
+        ReferenceKind::Henshall,
+        ReferenceKind::ShKk,
+        ReferenceKind::ShKk2,
+        ReferenceKind::Sakade,
+        ReferenceKind::Jfcards,
+        ReferenceKind::Henshall3,
+        ReferenceKind::TuttleCards,
+        ReferenceKind::Crowley,
+        ReferenceKind::KanjiInContext,
+        ReferenceKind::BusyPeople,
+        ReferenceKind::KodanshaCompact,
+        ReferenceKind::Maniette,
+    ];
+
+    /// The dr_type attribute identifying the kind of reference.
+    pub fn dr_type(self) -> &'static str {
+        match self {
+            ReferenceKind::NelsonClassic => "nelson_c",
+            ReferenceKind::NelsonNew => "nelson_n",
+            ReferenceKind::Njecd => "halpern_njecd",
+            ReferenceKind::Kkd => "halpern_kkd",
+            ReferenceKind::Kkld => "halpern_kkld",
+            ReferenceKind::Kkld2ed => "halpern_kkld_2ed",
+            ReferenceKind::Heisig => "heisig",
+            ReferenceKind::Heisig6 => "heisig6",
+            ReferenceKind::Gakken => "gakken",
+            ReferenceKind::OneillNames => "oneill_names",
+            ReferenceKind::OneillKk => "oneill_kk",
+            ReferenceKind::Moro => "moro",
+            ReferenceKind::Henshall => "henshall",
+            ReferenceKind::ShKk => "sh_kk",
+            ReferenceKind::ShKk2 => "sh_kk2",
+            ReferenceKind::Sakade => "sakade",
+            ReferenceKind::Jfcards => "jf_cards",
+            ReferenceKind::Henshall3 => "henshall3",
+            ReferenceKind::TuttleCards => "tutt_cards",
+            ReferenceKind::Crowley => "crowley",
+            ReferenceKind::KanjiInContext => "kanji_in_context",
+            ReferenceKind::BusyPeople => "busy_people",
+            ReferenceKind::KodanshaCompact => "kodansha_compact",
+            ReferenceKind::Maniette => "maniette",
+        }
+    }
+
+    /// The full title of the book.
+    pub fn book_title(self) -> &'static str {
+        match self {
+            ReferenceKind::NelsonClassic => "Modern Reader's Japanese-English Character Dictionary",
+            ReferenceKind::NelsonNew => "The New Nelson Japanese-English Character Dictionary",
+            ReferenceKind::Njecd => "New Japanese-English Character Dictionary",
+            ReferenceKind::Kkd => "Kodansha Kanji Dictionary",
+            ReferenceKind::Kkld => "Kanji Learner's Dictionary",
+            ReferenceKind::Kkld2ed => "Kanji Learner's Dictionary, 2nd edition",
+            ReferenceKind::Heisig => "Remembering the Kanji",
+            ReferenceKind::Heisig6 => "Remembering the Kanji, 6th edition",
+            ReferenceKind::Gakken => "A New Dictionary of Kanji Usage",
+            ReferenceKind::OneillNames => "Japanese Names",
+            ReferenceKind::OneillKk => "Essential Kanji",
+            ReferenceKind::Moro => "Dai Kan-Wa Jiten",
+            ReferenceKind::Henshall => "A Guide to Remembering Japanese Characters",
+            ReferenceKind::ShKk => "Kanji and Kana",
+            ReferenceKind::ShKk2 => "Kanji and Kana, 2011 edition",
+            ReferenceKind::Sakade => "A Guide to Reading and Writing Japanese",
+            ReferenceKind::Jfcards => "Japanese Kanji Flashcards",
+            ReferenceKind::Henshall3 => "A Guide to Reading and Writing Japanese, 3rd edition",
+            ReferenceKind::TuttleCards => "Tuttle Kanji Cards",
+            ReferenceKind::Crowley => "The Kanji Way to Japanese Language Power",
+            ReferenceKind::KanjiInContext => "Kanji in Context",
+            ReferenceKind::BusyPeople => "Japanese for Busy People",
+            ReferenceKind::KodanshaCompact => "The Kodansha Compact Kanji Guide",
+            ReferenceKind::Maniette => "Les Kanjis dans la tête",
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    #[error("(Reference) Unknown dr_type string: {0}")]
+    UnknownKind(String),
+}
+
+impl FromStr for ReferenceKind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "nelson_c" => Ok(ReferenceKind::NelsonClassic),
+            "nelson_n" => Ok(ReferenceKind::NelsonNew),
+            "halpern_njecd" => Ok(ReferenceKind::Njecd),
+            "halpern_kkd" => Ok(ReferenceKind::Kkd),
+            "halpern_kkld" => Ok(ReferenceKind::Kkld),
+            "halpern_kkld_2ed" => Ok(ReferenceKind::Kkld2ed),
+            "heisig" => Ok(ReferenceKind::Heisig),
+            "heisig6" => Ok(ReferenceKind::Heisig6),
+            "gakken" => Ok(ReferenceKind::Gakken),
+            "oneill_names" => Ok(ReferenceKind::OneillNames),
+            "oneill_kk" => Ok(ReferenceKind::OneillKk),
+            "moro" => Ok(ReferenceKind::Moro),
+            "henshall" => Ok(ReferenceKind::Henshall),
+            "sh_kk" => Ok(ReferenceKind::ShKk),
+            "sh_kk2" => Ok(ReferenceKind::ShKk2),
+            "sakade" => Ok(ReferenceKind::Sakade),
+            "jf_cards" => Ok(ReferenceKind::Jfcards),
+            "henshall3" => Ok(ReferenceKind::Henshall3),
+            "tutt_cards" => Ok(ReferenceKind::TuttleCards),
+            "crowley" => Ok(ReferenceKind::Crowley),
+            "kanji_in_context" => Ok(ReferenceKind::KanjiInContext),
+            "busy_people" => Ok(ReferenceKind::BusyPeople),
+            "kodansha_compact" => Ok(ReferenceKind::KodanshaCompact),
+            "maniette" => Ok(ReferenceKind::Maniette),
+            _ => Err(ParseError::UnknownKind(s.to_owned())),
+        }
+    }
+}
+
+impl Display for ReferenceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.dr_type())
+    }
+}
+
+impl Display for Reference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.book_title())?;
+        match self {
+            Reference::OneillNames(oneill) => write!(f, "{}", oneill),
+            Reference::Moro(moro) => write!(f, "{}", moro),
+            Reference::BusyPeople(busy_people) => write!(f, "{}", busy_people),
+            Reference::NelsonClassic(n)
+            | Reference::NelsonNew(n)
+            | Reference::Njecd(n)
+            | Reference::Kkd(n)
+            | Reference::Kkld(n)
+            | Reference::Kkld2ed(n)
+            | Reference::Heisig(n)
+            | Reference::Heisig6(n)
+            | Reference::Gakken(n)
+            | Reference::OneillKk(n)
+            | Reference::Henshall(n)
+            | Reference::ShKk(n)
+            | Reference::ShKk2(n)
+            | Reference::Sakade(n)
+            | Reference::Jfcards(n)
+            | Reference::Henshall3(n)
+            | Reference::TuttleCards(n)
+            | Reference::Crowley(n)
+            | Reference::KanjiInContext(n)
+            | Reference::KodanshaCompact(n)
+            | Reference::Maniette(n) => write!(f, "{}", n),
+        }
+    }
+}
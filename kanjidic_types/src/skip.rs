@@ -1,15 +1,27 @@
 use crate::{shared::NomErrorReason, take_uint, IResult, NomErr, TryFromPrimitiveError};
-use nom::{character::complete::char, sequence::tuple};
+use nom::{character::complete::char, combinator::all_consuming, sequence::tuple};
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
 use thiserror::Error;
 
 /// Kanji code from the SKIP system of indexing.
 /// <http://www.edrdg.org/wwwjdic/SKIP.html>
+/// Codes convert to and from the pattern and two stroke counts joined by dashes, as in `1-4-3`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 #[serde(tag = "tag", content = "content")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Skip {
     /// Pattern 1, the kanji can be divided into left and right parts.
     Horizontal(SkipHorizontal),
@@ -24,6 +36,13 @@ pub enum Skip {
 /// Left and right parts of the kanji.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SkipHorizontal {
     /// Number of strokes in the left part.
     pub left: u8,
@@ -34,6 +53,13 @@ pub struct SkipHorizontal {
 /// Top and bottom parts of the kanji.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SkipVertical {
     /// Number of strokes in the top part.
     pub top: u8,
@@ -44,6 +70,13 @@ pub struct SkipVertical {
 /// Interior and exterior parts of the kanji.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SkipEnclosure {
     /// Number of strokes in the exterior part.
     pub exterior: u8,
@@ -54,6 +87,13 @@ pub struct SkipEnclosure {
 /// Classification for kanji that don't fit another pattern.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SkipSolid {
     /// The total number of strokes in the kanji.
     pub total_stroke_count: u8,
@@ -76,6 +116,13 @@ pub struct SkipSolid {
     Deserialize_repr,
 )]
 #[repr(u8)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 pub enum SolidSubpattern {
     /// Contains a top line.
     TopLine = 1,
@@ -133,6 +180,32 @@ impl TryFrom<&str> for Skip {
     }
 }
 
+impl FromStr for Skip {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.trim())
+    }
+}
+
+impl Display for Skip {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (pattern, first, second) = match self {
+            Skip::Horizontal(skip) => (1, skip.left, skip.right),
+            Skip::Vertical(skip) => (2, skip.top, skip.bottom),
+            Skip::Enclosure(skip) => (3, skip.exterior, skip.interior),
+            Skip::Solid(skip) => (4, skip.total_stroke_count, skip.solid_subpattern as u8),
+        };
+        write!(f, "{}-{}-{}", pattern, first, second)
+    }
+}
+
 fn parts(s: &str) -> IResult<(u8, char, u8, char, u8)> {
-    tuple((take_uint, char('-'), take_uint, char('-'), take_uint))(s)
+    all_consuming(tuple((
+        take_uint,
+        char('-'),
+        take_uint,
+        char('-'),
+        take_uint,
+    )))(s)
 }
@@ -0,0 +1,97 @@
+use crate::Character;
+use std::cmp::Ordering;
+
+/// Sorts characters from most to least frequent,
+/// with characters that have no frequency ranking last.
+pub fn by_frequency(a: &Character, b: &Character) -> Ordering {
+    frequency_key(a).cmp(&frequency_key(b))
+}
+
+/// Sorts characters from fewest to most strokes by their accepted stroke count.
+pub fn by_stroke_count(a: &Character, b: &Character) -> Ordering {
+    a.stroke_counts.accepted.cmp(&b.stroke_counts.accepted)
+}
+
+/// Sorts characters by grade in curriculum order,
+/// then from most to least frequent within a grade.
+/// Characters without a grade come last,
+/// as do characters without a frequency ranking within a grade.
+pub fn by_grade_then_frequency(a: &Character, b: &Character) -> Ordering {
+    grade_key(a)
+        .cmp(&grade_key(b))
+        .then_with(|| by_frequency(a, b))
+}
+
+/// A key for `sort_by_key` that sorts the same way as [`by_frequency`].
+pub fn frequency_key(character: &Character) -> impl Ord {
+    none_last(character.frequency)
+}
+
+/// A key for `sort_by_key` that sorts by grade with characters without a grade last.
+pub fn grade_key(character: &Character) -> impl Ord {
+    none_last(character.grade)
+}
+
+/// Orders missing values after present ones,
+/// where `Option` would otherwise put them first.
+fn none_last<T: Ord>(value: Option<T>) -> (bool, Option<T>) {
+    (value.is_none(), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{by_frequency, by_grade_then_frequency, by_stroke_count, frequency_key};
+    use crate::{Character, Grade, StrokeCount};
+
+    fn character(
+        literal: char,
+        strokes: u8,
+        grade: Option<Grade>,
+        frequency: Option<u16>,
+    ) -> Character {
+        let mut builder = Character::builder()
+            .literal(literal)
+            .stroke_counts(StrokeCount {
+                accepted: strokes,
+                miscounts: vec![],
+            });
+        if let Some(grade) = grade {
+            builder = builder.grade(grade);
+        }
+        if let Some(frequency) = frequency {
+            builder = builder.frequency(frequency);
+        }
+        builder.build().unwrap()
+    }
+
+    fn literals(characters: &[Character]) -> String {
+        characters
+            .iter()
+            .map(|character| character.literal)
+            .collect()
+    }
+
+    #[test]
+    fn comparators() {
+        let mut characters = vec![
+            character('亜', 7, Some(Grade::Jouyou), Some(1509)),
+            character('唖', 10, None, None),
+            character('愛', 13, Some(Grade::Kyouiku(4)), Some(640)),
+            character('娃', 9, Some(Grade::Jinmeiyou), None),
+            character('悪', 11, Some(Grade::Kyouiku(3)), Some(530)),
+            character('安', 6, Some(Grade::Kyouiku(3)), Some(144)),
+        ];
+
+        characters.sort_by(by_frequency);
+        assert_eq!(literals(&characters), "安悪愛亜唖娃");
+
+        characters.sort_by(by_stroke_count);
+        assert_eq!(literals(&characters), "安亜娃唖悪愛");
+
+        characters.sort_by_key(frequency_key);
+        assert_eq!(literals(&characters), "安悪愛亜娃唖");
+
+        characters.sort_by(by_grade_then_frequency);
+        assert_eq!(literals(&characters), "安悪愛亜娃唖");
+    }
+}
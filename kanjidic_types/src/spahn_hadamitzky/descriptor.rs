@@ -1,14 +1,31 @@
 use super::radical::Radical;
 use crate::{shared::NomErrorReason, take_uint, IResult, NomErr};
-use nom::{bytes::complete::take, character::complete::char, combinator::map_res, sequence::tuple};
+use nom::{
+    bytes::complete::take,
+    character::complete::char,
+    combinator::{all_consuming, map_res},
+    sequence::tuple,
+};
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
 use thiserror::Error;
 
 /// Descriptor code for The Kanji Dictionary.
 /// The code reference can be found here: <http://www.edrdg.org/wiki/index.php/KANJIDIC_Project>
+/// Printed and parsed as radical strokes, radical letter, other strokes, and sequence, as in `0a7.14`.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Descriptor {
     /// Number of strokes in the identifying radical.
     pub radical_strokes: u8,
@@ -47,8 +64,26 @@ impl TryFrom<&str> for Descriptor {
     }
 }
 
+impl FromStr for Descriptor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.trim())
+    }
+}
+
+impl Display for Descriptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}.{}",
+            self.radical_strokes, self.radical, self.other_strokes, self.sequence
+        )
+    }
+}
+
 fn parts(s: &str) -> IResult<(u8, Radical, u8, char, u8)> {
-    tuple((take_uint, radical, take_uint, char('.'), take_uint))(s)
+    all_consuming(tuple((take_uint, radical, take_uint, char('.'), take_uint)))(s)
 }
 
 fn radical(s: &str) -> IResult<Radical> {
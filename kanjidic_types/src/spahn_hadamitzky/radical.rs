@@ -1,10 +1,20 @@
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
 use thiserror::Error;
 
 /// An identifying radical in the Spahn and Hadamitzky classification system.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Radical {
     A,
     B,
@@ -75,3 +85,16 @@ impl TryFrom<char> for Radical {
         }
     }
 }
+
+impl Radical {
+    /// The lowercase letter identifying the radical in a descriptor.
+    pub fn letter(self) -> char {
+        (b'a' + self as u8) as char
+    }
+}
+
+impl Display for Radical {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
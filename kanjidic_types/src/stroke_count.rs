@@ -3,6 +3,13 @@ use serde::{Deserialize, Serialize};
 /// The number of strokes in a kanji.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StrokeCount {
     /// The accepted number of strokes.
     pub accepted: u8,
@@ -10,3 +17,20 @@ pub struct StrokeCount {
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub miscounts: Vec<u8>,
 }
+
+impl StrokeCount {
+    /// Whether any miscounts are recorded.
+    pub fn is_disputed(&self) -> bool {
+        !self.miscounts.is_empty()
+    }
+
+    /// The accepted count followed by the miscounts.
+    pub fn all_counts(&self) -> impl Iterator<Item = u8> + '_ {
+        std::iter::once(self.accepted).chain(self.miscounts.iter().copied())
+    }
+
+    /// Whether the accepted count or any miscount is the given number of strokes.
+    pub fn matches(&self, strokes: u8) -> bool {
+        self.all_counts().any(|count| count == strokes)
+    }
+}